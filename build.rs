@@ -0,0 +1,10 @@
+use std::env;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=LAPACK_RS_CBLAS_LIB");
+    if env::var_os("CARGO_FEATURE_CBLAS").is_some() {
+        let lib = env::var("LAPACK_RS_CBLAS_LIB").unwrap_or_else(|_| "openblas".to_string());
+        println!("cargo:rustc-link-lib={lib}");
+    }
+}
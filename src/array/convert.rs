@@ -1,112 +1,296 @@
 use crate::array::FortranArray;
+use crate::array::view::{flatten_columns, FortranView, FortranViewMut};
+use crate::scalar::Scalar;
 
-impl From<&[f64]> for FortranArray {
-    fn from(data: &[f64]) -> Self {
+impl<T: Scalar> From<&[T]> for FortranArray<T> {
+    fn from(data: &[T]) -> Self {
         let len = data.len();
         FortranArray {
             data: data.to_vec(),
             rows: len as i32,
             cols: 0,
-            default_value: f64::NAN,
+            default_value: T::nan(),
         }
     }
 }
 
-impl From<Vec<f64>> for FortranArray {
-    fn from(data: Vec<f64>) -> Self {
+impl<T: Scalar> From<Vec<T>> for FortranArray<T> {
+    fn from(data: Vec<T>) -> Self {
         let len = data.len();
         FortranArray {
             data,
             rows: len as i32,
             cols: 0,
-            default_value: f64::NAN,
+            default_value: T::nan(),
         }
     }
 }
 
-impl From<Vec<Vec<f64>>> for FortranArray {
-    fn from(data: Vec<Vec<f64>>) -> Self {
-        let rows = data.len();
-        let cols = if rows > 0 { data[0].len() } else { 0 };
-        let data = data.into_iter().flatten().collect();
+/// Each inner vector is one column, matching the column-major storage.
+/// Shorter columns are padded with zeros.
+impl<T: Scalar> From<Vec<Vec<T>>> for FortranArray<T> {
+    fn from(data: Vec<Vec<T>>) -> Self {
+        let (data, rows, cols) = flatten_columns(&data);
         FortranArray {
             data,
-            rows: rows as i32,
-            cols: cols as i32,
-            default_value: f64::NAN,
+            rows,
+            cols,
+            default_value: T::nan(),
         }
     }
 }
 
-impl From<FortranArray> for Vec<f64> {
-    fn from(array: FortranArray) -> Self {
+impl<T: Scalar> From<FortranArray<T>> for Vec<T> {
+    fn from(array: FortranArray<T>) -> Self {
         array.data
     }
 }
 
-impl From<FortranArray> for Vec<Vec<f64>> {
-    fn from(array: FortranArray) -> Self {
-        let mut data = vec![vec![0.0; array.rows as usize]; array.cols as usize];
-        for (i, value) in array.data.iter().enumerate() {
-            let row = i % array.rows as usize;
-            let col = i / array.rows as usize;
-            data[col][row] = *value;
-        }
-        data
+/// Splits the array into its columns; a vector becomes a single column.
+impl<T: Scalar> From<FortranArray<T>> for Vec<Vec<T>> {
+    fn from(array: FortranArray<T>) -> Self {
+        let (rows, _) = array.shape();
+        array.data.chunks(rows.max(1) as usize).map(|col| col.to_vec()).collect()
     }
 }
 
-pub trait ToFortranArray {
-    fn to_fa(&self) -> FortranArray;
-    fn to_fa_2d(&self, ld: i32) -> FortranArray;
+/// Conversion into the column-major representation used by the routines.
+///
+/// `to_fa`/`to_fa_2d` produce an owned copy, while `fa_view`/`fa_view_2d` borrow the
+/// data in place. Flat sources (`&[T]`, `Vec<T>`, 1D arrays) are laid out with the
+/// leading dimension `ld`; sources that already carry a 2D shape (2D arrays, views and
+/// `Vec<Vec<T>>`) keep their own.
+pub trait ToFortranArray<T: Scalar = f64> {
+    fn to_fa(&self) -> FortranArray<T>;
+    fn to_fa_2d(&self, ld: i32) -> FortranArray<T>;
+    fn fa_view(&self) -> FortranView<'_, T>;
+    fn fa_view_2d(&self, ld: i32) -> FortranView<'_, T>;
+}
+
+/// Mutable counterpart of [`ToFortranArray`], used by routines that update their
+/// arguments in place.
+pub trait ToFortranArrayMut<T: Scalar = f64>: ToFortranArray<T> {
+    fn fa_view_mut(&mut self) -> FortranViewMut<'_, T>;
+    fn fa_view_2d_mut(&mut self, ld: i32) -> FortranViewMut<'_, T>;
+}
+
+// A flat source may end in a partial column, as Fortran's `A(LDA,*)` with `LDA*(N-1)+M`
+// elements; that column still counts, and the flat offset bounds what can be reached in it.
+fn flat_view<T: Scalar>(data: &[T], ld: i32) -> FortranView<'_, T> {
+    let ld = ld.max(1);
+    let cols = data.len().div_ceil(ld as usize) as i32;
+    FortranView::new(data, ld, cols, ld)
+}
+
+fn flat_view_mut<T: Scalar>(data: &mut [T], ld: i32) -> FortranViewMut<'_, T> {
+    let ld = ld.max(1);
+    let cols = data.len().div_ceil(ld as usize) as i32;
+    FortranViewMut::new(data, ld, cols, ld)
+}
+
+impl<T: Scalar> ToFortranArray<T> for &[T] {
+
+    fn to_fa(&self) -> FortranArray<T> {
+        FortranArray::from(self.to_vec())
+    }
+
+    fn to_fa_2d(&self, ld: i32) -> FortranArray<T> {
+        let array = &mut FortranArray::from(self.to_vec());
+        array.as_2d(ld);
+        array.clone()
+    }
+
+    fn fa_view(&self) -> FortranView<'_, T> {
+        FortranView::vector(self)
+    }
+
+    fn fa_view_2d(&self, ld: i32) -> FortranView<'_, T> {
+        flat_view(self, ld)
+    }
 }
 
-impl ToFortranArray for &[f64] {
+impl<T: Scalar> ToFortranArray<T> for &mut [T] {
 
-    fn to_fa(&self) -> FortranArray {
+    fn to_fa(&self) -> FortranArray<T> {
         FortranArray::from(self.to_vec())
     }
 
-    fn to_fa_2d(&self, ld: i32) -> FortranArray {
+    fn to_fa_2d(&self, ld: i32) -> FortranArray<T> {
         let array = &mut FortranArray::from(self.to_vec());
         array.as_2d(ld);
         array.clone()
     }
+
+    fn fa_view(&self) -> FortranView<'_, T> {
+        FortranView::vector(self)
+    }
+
+    fn fa_view_2d(&self, ld: i32) -> FortranView<'_, T> {
+        flat_view(self, ld)
+    }
 }
 
-impl ToFortranArray for Vec<f64> {
+impl<T: Scalar> ToFortranArrayMut<T> for &mut [T] {
 
-    fn to_fa(&self) -> FortranArray {
+    fn fa_view_mut(&mut self) -> FortranViewMut<'_, T> {
+        FortranViewMut::vector(self)
+    }
+
+    fn fa_view_2d_mut(&mut self, ld: i32) -> FortranViewMut<'_, T> {
+        flat_view_mut(self, ld)
+    }
+}
+
+impl<T: Scalar> ToFortranArray<T> for Vec<T> {
+
+    fn to_fa(&self) -> FortranArray<T> {
         FortranArray::from(self.clone())
     }
 
-    fn to_fa_2d(&self, ld: i32) -> FortranArray {
+    fn to_fa_2d(&self, ld: i32) -> FortranArray<T> {
         let array = &mut FortranArray::from(self.clone());
         array.as_2d(ld);
         array.clone()
     }
+
+    fn fa_view(&self) -> FortranView<'_, T> {
+        FortranView::vector(self)
+    }
+
+    fn fa_view_2d(&self, ld: i32) -> FortranView<'_, T> {
+        flat_view(self, ld)
+    }
 }
 
-impl ToFortranArray for Vec<Vec<f64>> {
+impl<T: Scalar> ToFortranArrayMut<T> for Vec<T> {
+
+    fn fa_view_mut(&mut self) -> FortranViewMut<'_, T> {
+        FortranViewMut::vector(self)
+    }
 
-    fn to_fa(&self) -> FortranArray {
+    fn fa_view_2d_mut(&mut self, ld: i32) -> FortranViewMut<'_, T> {
+        flat_view_mut(self, ld)
+    }
+}
+
+/// `Vec<Vec<T>>` is not contiguous, so its views are staged through a single
+/// column-major buffer (written back when a mutable view is dropped).
+impl<T: Scalar> ToFortranArray<T> for Vec<Vec<T>> {
+
+    fn to_fa(&self) -> FortranArray<T> {
         FortranArray::from(self.clone())
     }
 
-    fn to_fa_2d(&self, _ld: i32) -> FortranArray {
+    fn to_fa_2d(&self, _ld: i32) -> FortranArray<T> {
         FortranArray::from(self.clone())
     }
+
+    fn fa_view(&self) -> FortranView<'_, T> {
+        let (data, rows, cols) = flatten_columns(self);
+        FortranView::staged(data, rows, cols)
+    }
+
+    fn fa_view_2d(&self, _ld: i32) -> FortranView<'_, T> {
+        self.fa_view()
+    }
+}
+
+impl<T: Scalar> ToFortranArrayMut<T> for Vec<Vec<T>> {
+
+    fn fa_view_mut(&mut self) -> FortranViewMut<'_, T> {
+        FortranViewMut::staged(self)
+    }
+
+    fn fa_view_2d_mut(&mut self, _ld: i32) -> FortranViewMut<'_, T> {
+        self.fa_view_mut()
+    }
 }
 
-impl ToFortranArray for FortranArray {
+impl<T: Scalar> ToFortranArray<T> for FortranArray<T> {
 
-    fn to_fa(&self) -> FortranArray {
+    fn to_fa(&self) -> FortranArray<T> {
         self.clone()
     }
 
-    fn to_fa_2d(&self, _ld: i32) -> FortranArray {
+    fn to_fa_2d(&self, _ld: i32) -> FortranArray<T> {
         self.clone()
     }
+
+    fn fa_view(&self) -> FortranView<'_, T> {
+        self.view()
+    }
+
+    fn fa_view_2d(&self, ld: i32) -> FortranView<'_, T> {
+        if self.is_2d() { self.view() }
+        else { flat_view(&self.data, ld) }
+    }
+}
+
+impl<T: Scalar> ToFortranArrayMut<T> for FortranArray<T> {
+
+    fn fa_view_mut(&mut self) -> FortranViewMut<'_, T> {
+        self.view_mut()
+    }
+
+    fn fa_view_2d_mut(&mut self, ld: i32) -> FortranViewMut<'_, T> {
+        if self.is_2d() { self.view_mut() }
+        else { flat_view_mut(&mut self.data, ld) }
+    }
+}
+
+impl<T: Scalar> ToFortranArray<T> for FortranView<'_, T> {
+
+    fn to_fa(&self) -> FortranArray<T> {
+        let mut array = FortranArray::zeros(self.rows, self.cols);
+        for j in 1..=self.cols {
+            for i in 1..=self.rows {
+                array[(i, j)] = self[(i, j)];
+            }
+        }
+        array
+    }
+
+    fn to_fa_2d(&self, _ld: i32) -> FortranArray<T> {
+        self.to_fa()
+    }
+
+    fn fa_view(&self) -> FortranView<'_, T> {
+        self.reborrow()
+    }
+
+    fn fa_view_2d(&self, _ld: i32) -> FortranView<'_, T> {
+        self.reborrow()
+    }
+}
+
+impl<T: Scalar> ToFortranArray<T> for FortranViewMut<'_, T> {
+
+    fn to_fa(&self) -> FortranArray<T> {
+        self.as_view().to_fa()
+    }
+
+    fn to_fa_2d(&self, _ld: i32) -> FortranArray<T> {
+        self.to_fa()
+    }
+
+    fn fa_view(&self) -> FortranView<'_, T> {
+        self.as_view()
+    }
+
+    fn fa_view_2d(&self, _ld: i32) -> FortranView<'_, T> {
+        self.as_view()
+    }
+}
+
+impl<T: Scalar> ToFortranArrayMut<T> for FortranViewMut<'_, T> {
+
+    fn fa_view_mut(&mut self) -> FortranViewMut<'_, T> {
+        self.reborrow()
+    }
+
+    fn fa_view_2d_mut(&mut self, _ld: i32) -> FortranViewMut<'_, T> {
+        self.reborrow()
+    }
 }
 
 #[cfg(test)]
@@ -121,7 +305,7 @@ mod tests {
         #[case] expected: FortranArray,
     ) {
         let input: FortranArray = input.into();
-        assert_eq!(expected, input.into());
+        assert_eq!(expected, input);
     }
 
     #[rstest]
@@ -136,16 +320,19 @@ mod tests {
 
     #[rstest]
     #[case(vec![vec![1.0, 2.0], vec![3.0, 4.0]], FortranArray::matrix(&[1.0, 2.0, 3.0, 4.0], 2, 2))]
+    #[case(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]], FortranArray::matrix(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 3, 2))]
     fn test_from_vec_2d(
         #[case] input: Vec<Vec<f64>>,
         #[case] expected: FortranArray,
     ) {
         let input: FortranArray = input.into();
-        assert_eq!(expected, input.into());
+        assert_eq!(expected, input);
     }
 
     #[rstest]
     #[case(FortranArray::matrix(&[1.0, 2.0, 3.0, 4.0], 2, 2), vec![vec![1.0, 2.0], vec![3.0, 4.0]])]
+    #[case(FortranArray::matrix(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 3, 2), vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]])]
+    #[case(FortranArray::vector(&[1.0, 2.0, 3.0]), vec![vec![1.0, 2.0, 3.0]])]
     fn test_into_vec_2d(
         #[case] input: FortranArray,
         #[case] expected: Vec<Vec<f64>>,
@@ -153,4 +340,49 @@ mod tests {
         let input: Vec<Vec<f64>> = input.into();
         assert_eq!(expected, input);
     }
+
+    #[test]
+    fn test_vec_2d_round_trip() {
+        let columns = vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]];
+        let array = FortranArray::from(columns.clone());
+        assert_eq!(6.0, array[(3, 2)]);
+        assert_eq!(columns, Vec::<Vec<f64>>::from(array));
+    }
+
+    #[test]
+    fn test_view_mut_writes_in_place() {
+        let mut data = vec![1.0, 2.0, 3.0, 4.0];
+        {
+            let mut view = data.fa_view_2d_mut(2);
+            view[(2, 2)] = 10.0;
+        }
+        assert_eq!(vec![1.0, 2.0, 3.0, 10.0], data);
+    }
+
+    #[test]
+    fn test_flat_view_partial_last_column() {
+        // lda*(n-1)+m elements, as the reference BLAS allows.
+        let a = vec![1., 2., 0., 3., 4.];
+        let view = a.fa_view_2d(3);
+        assert_eq!((3, 2), (view.rows(), view.cols()));
+        assert_eq!(Some(4.), view.get(2, 2));
+        assert_eq!(None, view.get(3, 2));
+    }
+
+    #[test]
+    #[cfg_attr(feature = "debug-bounds", should_panic(expected = "out of bounds"))]
+    fn test_flat_view_past_partial_column() {
+        let a = vec![1., 2., 0., 3., 4.];
+        let _ = a.fa_view_2d(3)[(3, 2)];
+    }
+
+    #[test]
+    fn test_view_mut_stages_vec_2d() {
+        let mut data = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+        {
+            let mut view = data.fa_view_2d_mut(2);
+            view[(1, 2)] = 10.0;
+        }
+        assert_eq!(vec![vec![1.0, 2.0], vec![10.0, 4.0]], data);
+    }
 }
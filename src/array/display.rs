@@ -1,34 +1,157 @@
 use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::array::view::{FortranView, FortranViewMut};
 use crate::array::FortranArray;
+use crate::scalar::Scalar;
+
+static THRESHOLD: AtomicUsize = AtomicUsize::new(1000);
+static EDGE_ITEMS: AtomicUsize = AtomicUsize::new(3);
+
+/// How arrays are printed, in the spirit of NumPy's `set_printoptions`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrintOptions {
+    /// Arrays with more elements than this are summarized with `...`.
+    pub threshold: usize,
+    /// Rows and columns printed at each end of a summarized axis.
+    pub edge_items: usize,
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        PrintOptions { threshold: 1000, edge_items: 3 }
+    }
+}
 
-impl fmt::Display for FortranArray {
+/// Current print options, shared by every thread.
+pub fn print_options() -> PrintOptions {
+    PrintOptions {
+        threshold: THRESHOLD.load(Ordering::Relaxed),
+        edge_items: EDGE_ITEMS.load(Ordering::Relaxed),
+    }
+}
+
+/// Replaces the print options used by `Display`, `LowerExp` and `Debug`.
+pub fn set_print_options(options: PrintOptions) {
+    THRESHOLD.store(options.threshold, Ordering::Relaxed);
+    EDGE_ITEMS.store(options.edge_items, Ordering::Relaxed);
+}
+
+// Indices printed along an axis of length `len`; `None` marks the elided middle.
+fn axis(len: i32, summarize: bool, edge_items: usize) -> Vec<Option<i32>> {
+    let edge = edge_items as i32;
+    if summarize && len > 2 * edge {
+        (1..=edge).map(Some)
+            .chain(std::iter::once(None))
+            .chain((len - edge + 1..=len).map(Some))
+            .collect()
+    } else {
+        (1..=len).map(Some).collect()
+    }
+}
+
+/// Writes a `rows` by `cols` array read through `get`, with every element right-aligned
+/// to the widest one (or to the formatter's width, if larger).
+///
+/// Vectors go on one line; matrix rows are joined with `row_separator`.
+pub(super) fn write_array<T: Scalar>(
+    f: &mut fmt::Formatter,
+    (rows, cols): (i32, i32),
+    vector: bool,
+    get: impl Fn(i32, i32) -> T,
+    element: impl Fn(&T) -> String,
+    row_separator: &str,
+) -> fmt::Result {
+    let options = print_options();
+    let summarize = (rows.max(0) as usize) * (cols.max(0) as usize) > options.threshold;
+    let row_axis = axis(rows, summarize, options.edge_items);
+    let col_axis = if vector { vec![Some(1)] } else { axis(cols, summarize, options.edge_items) };
+
+    let cells: Vec<Option<Vec<Option<String>>>> = row_axis.iter()
+        .map(|i| i.map(|i| col_axis.iter().map(|j| j.map(|j| element(&get(i, j)))).collect()))
+        .collect();
+    let width = cells.iter().flatten().flatten().flatten()
+        .map(|cell| cell.chars().count())
+        .fold(f.width().unwrap_or(0), usize::max);
+    let pad = |cell: &Option<String>| match cell {
+        Some(cell) => format!("{cell:>width$}"),
+        None => "...".to_string(),
+    };
+
+    if vector {
+        let line: Vec<String> = cells.iter()
+            .map(|row| row.as_ref().map_or("...".to_string(), |row| pad(&row[0])))
+            .collect();
+        return write!(f, "[{}]", line.join(", "));
+    }
+
+    write!(f, "[")?;
+    if cells.is_empty() || cols <= 0 { return write!(f, "]"); }
+    for (k, row) in cells.iter().enumerate() {
+        if k != 0 { write!(f, "{row_separator}")?; }
+        match row {
+            Some(row) => write!(f, "[{}]", row.iter().map(pad).collect::<Vec<_>>().join(", "))?,
+            None => write!(f, "...")?,
+        }
+    }
+    write!(f, "]")
+}
+
+impl<T: Scalar> FortranArray<T> {
+    fn write(&self, f: &mut fmt::Formatter, element: impl Fn(&T) -> String) -> fmt::Result {
+        let (rows, cols) = self.shape();
+        let get = |i, j| self.data[((j - 1) * rows + (i - 1)) as usize];
+        write_array(f, (rows, cols), self.is_1d(), get, element, ",\n ")
+    }
+}
+
+/// Column-aligned, one row per line; `{:.p}` sets the precision (one digit by default).
+impl<T: Scalar> fmt::Display for FortranArray<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let p = f.precision().unwrap_or(1);
-        if self.is_1d() {
-            // 1D Vector
-            write!(f, "[")?;
-            for (i, value) in self.data.iter().enumerate() {
-                if i != 0 { write!(f, ", ")?; }
-                write!(f, "{:.p$}", value)?;
-            }
-            write!(f, "]")
-        } else {
-            // 2D Matrix
-            write!(f, "[")?;
-            for row in 0..self.rows {
-                write!(f, "[")?;
-                for col in 0..self.cols {
-                    if col != 0 { write!(f, ", ")?; }
-                    let index = (row * self.cols + col) as usize;
-                    write!(f, "{:.p$}", self.data[index])?;
-                }
-                write!(f, "]")?;
-            }
-            write!(f, "]")
+        self.write(f, |value| format!("{value:.p$}"))
+    }
+}
+
+/// Like `Display`, with the elements in scientific notation.
+impl<T: Scalar> fmt::LowerExp for FortranArray<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match f.precision() {
+            Some(p) => self.write(f, |value| format!("{value:.p$e}")),
+            None => self.write(f, |value| format!("{value:e}")),
         }
     }
 }
 
+/// Shape, leading dimension and the elements on a single line.
+impl<T: Scalar> fmt::Debug for FortranArray<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (rows, cols) = self.shape();
+        write!(f, "FortranArray {{ shape: ({rows}, {cols}), ld: {}, data: ", rows.max(1))?;
+        let get = |i, j| self.data[((j - 1) * rows + (i - 1)) as usize];
+        write_array(f, (rows, cols), self.is_1d(), get, |value| format!("{value:?}"), ", ")?;
+        write!(f, " }}")
+    }
+}
+
+impl<T: Scalar> fmt::Debug for FortranView<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FortranView {{ shape: ({}, {}), ld: {}, data: ", self.rows(), self.cols(), self.ld())?;
+        let get = |i, j| *self.element((i, j));
+        write_array(f, (self.rows(), self.cols()), false, get, |value| format!("{value:?}"), ", ")?;
+        write!(f, " }}")
+    }
+}
+
+impl<T: Scalar> fmt::Debug for FortranViewMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FortranViewMut {{ shape: ({}, {}), ld: {}, data: ", self.rows(), self.cols(), self.ld())?;
+        let get = |i, j| *self.element((i, j));
+        write_array(f, (self.rows(), self.cols()), false, get, |value| format!("{value:?}"), ", ")?;
+        write!(f, " }}")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -37,12 +160,47 @@ mod tests {
     #[rstest]
     #[case(FortranArray::vector(& [1.0, 2.0, 3.0, 4.0]), "[1.0, 2.0, 3.0, 4.0]")]
     #[case(FortranArray::matrix(& [1.0, 2.0, 3.0, 4.0], 1, 4), "[[1.0, 2.0, 3.0, 4.0]]")]
-    #[case(FortranArray::matrix(& [1.0, 2.0, 3.0, 4.0], 4, 1), "[[1.0][2.0][3.0][4.0]]")]
-    #[case(FortranArray::matrix(& [1.0, 2.0, 3.0, 4.0], 2, 2), "[[1.0, 2.0][3.0, 4.0]]")]
+    #[case(FortranArray::matrix(& [1.0, 2.0, 3.0, 4.0], 4, 1), "[[1.0],\n [2.0],\n [3.0],\n [4.0]]")]
+    #[case(FortranArray::matrix(& [1.0, 2.0, 3.0, 4.0], 2, 2), "[[1.0, 3.0],\n [2.0, 4.0]]")]
+    #[case(FortranArray::from_row_major(& [1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3), "[[1.0, 2.0, 3.0],\n [4.0, 5.0, 6.0]]")]
+    #[case(FortranArray::from_row_major(& [1.0, -20.0, 300.0, 4.0], 2, 2), "[[  1.0, -20.0],\n [300.0,   4.0]]")]
+    #[case(FortranArray::vector(& [1.0, 10.0]), "[ 1.0, 10.0]")]
+    #[case(FortranArray::empty(), "[]")]
     fn test_display(
         #[case] input: FortranArray,
         #[case] expected: &str,
     ) {
         assert_eq!(expected, format!("{input}"))
     }
+
+    #[test]
+    fn test_format_flags() {
+        let array = FortranArray::from_row_major(&[1.0, 0.25, 1500.0, -2.0], 2, 2);
+        assert_eq!("[[   1.000,    0.250],\n [1500.000,   -2.000]]", format!("{array:.3}"));
+        assert_eq!("[[    1.0,     0.2],\n [ 1500.0,    -2.0]]", format!("{array:7}"));
+        assert_eq!("[[   1e0, 2.5e-1],\n [ 1.5e3,   -2e0]]", format!("{array:e}"));
+        assert_eq!("[[ 1.0e0, 2.5e-1],\n [ 1.5e3, -2.0e0]]", format!("{array:.1e}"));
+    }
+
+    #[test]
+    fn test_summarized() {
+        let array = FortranArray::from_row_major(&(1..=2000).map(f64::from).collect::<Vec<_>>(), 40, 50);
+        let text = format!("{array:.0}");
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(7, lines.len());
+        assert_eq!("[[   1,    2,    3, ...,   48,   49,   50],", lines[0]);
+        assert_eq!(" ...,", lines[3]);
+        assert_eq!(" [1951, 1952, 1953, ..., 1998, 1999, 2000]]", lines[6]);
+
+        let vector = FortranArray::vector(&(1..=1001).map(f64::from).collect::<Vec<_>>());
+        assert_eq!("[   1,    2,    3, ...,  999, 1000, 1001]", format!("{vector:.0}"));
+    }
+
+    #[test]
+    fn test_debug() {
+        let array = FortranArray::from_row_major(&[1.0, 2.5, 3.0, 4.0, 5.0, 6.0], 2, 3);
+        assert_eq!("FortranArray { shape: (2, 3), ld: 2, data: [[1.0, 2.5, 3.0], [4.0, 5.0, 6.0]] }", format!("{array:?}"));
+        assert_eq!("FortranView { shape: (2, 2), ld: 2, data: [[2.5, 3.0], [5.0, 6.0]] }", format!("{:?}", array.sub(1, 2, 2, 2)));
+        assert_eq!("FortranArray { shape: (2, 1), ld: 2, data: [7.0, 8.0] }", format!("{:?}", FortranArray::vector(&[7.0, 8.0])));
+    }
 }
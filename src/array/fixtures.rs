@@ -0,0 +1,6 @@
+use crate::array::FortranArray;
+
+// A = [1 2 3; 4 5 6], whose rows, columns and diagonals all differ.
+pub(super) fn a() -> FortranArray {
+    FortranArray::from_row_major(&[1., 2., 3., 4., 5., 6.], 2, 3)
+}
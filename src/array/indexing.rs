@@ -1,26 +1,55 @@
+use std::fmt::Debug;
 use std::ops::{Index, IndexMut, RangeFrom};
+use std::panic::Location;
+use std::path::Path;
 
 use crate::array::FortranArray;
+use crate::array::view::{FortranView, FortranViewMut};
+use crate::scalar::Scalar;
 
-impl Index<i32> for FortranArray {
-    type Output = f64;
+/// Reports an out-of-range access when the `debug-bounds` feature is enabled.
+///
+/// The panic names the routine (taken from the caller's source file), the index and
+/// the shape. Without the feature nothing happens and the caller falls back to
+/// `MIN_POSITIVE` on reads and to a scratch value on writes.
+#[track_caller]
+#[inline]
+fn check_bounds<I: Debug>(in_bounds: bool, index: I, rows: i32, cols: i32) {
+    if cfg!(feature = "debug-bounds") && !in_bounds {
+        let file = Location::caller().file();
+        let routine = Path::new(file).file_stem().and_then(|s| s.to_str()).unwrap_or(file);
+        panic!("{routine}: index {index:?} is out of bounds for a {rows}x{cols} array");
+    }
+}
+
+pub(super) fn in_bounds(index: (i32, i32), rows: i32, cols: i32) -> bool {
+    let (i, j) = index;
+    i >= 1 && i <= rows && j >= 1 && j <= cols
+}
 
+impl<T: Scalar> Index<i32> for FortranArray<T> {
+    type Output = T;
+
+    #[track_caller]
     fn index(&self, index: i32) -> &Self::Output {
+        check_bounds(index >= 1 && index as usize <= self.data.len(), index, self.rows, self.cols);
         if index == 0 {
-            return &f64::MIN_POSITIVE;
+            return T::fallback();
         }
 
         if let Some(value) = self.data.get(index as usize - 1) {
-            &value
+            value
         } else {
-            &f64::MIN_POSITIVE
+            T::fallback()
         }
     }
 }
 
-impl IndexMut<i32> for FortranArray {
+impl<T: Scalar> IndexMut<i32> for FortranArray<T> {
 
+    #[track_caller]
     fn index_mut(&mut self, index: i32) -> &mut Self::Output {
+        check_bounds(index >= 1 && index as usize <= self.data.len(), index, self.rows, self.cols);
         if index == 0 {
             return &mut self.default_value;
         }
@@ -33,23 +62,29 @@ impl IndexMut<i32> for FortranArray {
     }
 }
 
-impl Index<(i32, i32)> for FortranArray {
-    type Output = f64;
+impl<T: Scalar> Index<(i32, i32)> for FortranArray<T> {
+    type Output = T;
 
+    #[track_caller]
     fn index(&self, index: (i32, i32)) -> &Self::Output {
+        let (rows, cols) = self.shape();
+        check_bounds(in_bounds(index, rows, cols), index, rows, cols);
         let index = ((index.1 - 1) * self.rows + (index.0 - 1)) as usize;
 
         if let Some(value) = self.data.get(index) {
             value
         } else {
-            &f64::MIN_POSITIVE
+            T::fallback()
         }
     }
 }
 
-impl IndexMut<(i32, i32)> for FortranArray {
+impl<T: Scalar> IndexMut<(i32, i32)> for FortranArray<T> {
 
+    #[track_caller]
     fn index_mut(&mut self, index: (i32, i32)) -> &mut Self::Output {
+        let (rows, cols) = self.shape();
+        check_bounds(in_bounds(index, rows, cols), index, rows, cols);
         let (col, row) = index;
         let index = ((row - 1) * self.rows + (col - 1)) as usize;
 
@@ -61,8 +96,8 @@ impl IndexMut<(i32, i32)> for FortranArray {
     }
 }
 
-impl Index<RangeFrom<i32>> for FortranArray {
-    type Output = [f64];
+impl<T: Scalar> Index<RangeFrom<i32>> for FortranArray<T> {
+    type Output = [T];
 
     fn index(&self, range: RangeFrom<i32>) -> &Self::Output {
         if range.start >= 1 && range.start <= self.len() {
@@ -73,7 +108,7 @@ impl Index<RangeFrom<i32>> for FortranArray {
     }
 }
 
-impl IndexMut<RangeFrom<i32>> for FortranArray {
+impl<T: Scalar> IndexMut<RangeFrom<i32>> for FortranArray<T> {
 
     fn index_mut(&mut self, range: RangeFrom<i32>) -> &mut Self::Output {
         if range.start >= 1 && range.start <= self.len()  {
@@ -84,8 +119,8 @@ impl IndexMut<RangeFrom<i32>> for FortranArray {
     }
 }
 
-impl Index<RangeFrom<(i32, i32)>> for FortranArray {
-    type Output = [f64];
+impl<T: Scalar> Index<RangeFrom<(i32, i32)>> for FortranArray<T> {
+    type Output = [T];
 
     fn index(&self, index: RangeFrom<(i32, i32)>) -> &Self::Output {
         let (col, row) = index.start;
@@ -94,7 +129,7 @@ impl Index<RangeFrom<(i32, i32)>> for FortranArray {
     }
 }
 
-impl IndexMut<RangeFrom<(i32, i32)>> for FortranArray {
+impl<T: Scalar> IndexMut<RangeFrom<(i32, i32)>> for FortranArray<T> {
 
     fn index_mut(&mut self, index: RangeFrom<(i32, i32)>) -> &mut Self::Output {
         let (col, row) = index.start;
@@ -103,6 +138,64 @@ impl IndexMut<RangeFrom<(i32, i32)>> for FortranArray {
     }
 }
 
+impl<T: Scalar> Index<i32> for FortranView<'_, T> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, index: i32) -> &Self::Output {
+        check_bounds(self.position((index, 1)).is_some(), index, self.rows, self.cols);
+        self.element((index, 1))
+    }
+}
+
+impl<T: Scalar> Index<(i32, i32)> for FortranView<'_, T> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, index: (i32, i32)) -> &Self::Output {
+        check_bounds(in_bounds(index, self.rows, self.cols) && self.position(index).is_some(), index, self.rows, self.cols);
+        self.element(index)
+    }
+}
+
+impl<T: Scalar> Index<i32> for FortranViewMut<'_, T> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, index: i32) -> &Self::Output {
+        check_bounds(self.position((index, 1)).is_some(), index, self.rows, self.cols);
+        self.element((index, 1))
+    }
+}
+
+impl<T: Scalar> IndexMut<i32> for FortranViewMut<'_, T> {
+
+    #[track_caller]
+    fn index_mut(&mut self, index: i32) -> &mut Self::Output {
+        check_bounds(self.position((index, 1)).is_some(), index, self.rows, self.cols);
+        self.element_mut((index, 1))
+    }
+}
+
+impl<T: Scalar> Index<(i32, i32)> for FortranViewMut<'_, T> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, index: (i32, i32)) -> &Self::Output {
+        check_bounds(in_bounds(index, self.rows, self.cols) && self.position(index).is_some(), index, self.rows, self.cols);
+        self.element(index)
+    }
+}
+
+impl<T: Scalar> IndexMut<(i32, i32)> for FortranViewMut<'_, T> {
+
+    #[track_caller]
+    fn index_mut(&mut self, index: (i32, i32)) -> &mut Self::Output {
+        check_bounds(in_bounds(index, self.rows, self.cols) && self.position(index).is_some(), index, self.rows, self.cols);
+        self.element_mut(index)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,6 +205,7 @@ mod tests {
     #[case(10, 100.0, 100.0)]
     #[case(43, 200.0, 200.0)]
     #[case(66, 0.0, 0.0)]
+    #[cfg_attr(feature = "debug-bounds", should_panic(expected = "out of bounds"))]
     #[case(-1, f64::MIN_POSITIVE, f64::MIN_POSITIVE)]
     fn test_set_get(
         #[case] idx: i32,
@@ -127,6 +221,7 @@ mod tests {
     #[case(5, 5, 100.0, 100.0)]
     #[case(9, 9, 200.0, 200.0)]
     #[case(1, 1, 0.0, 0.0)]
+    #[cfg_attr(feature = "debug-bounds", should_panic(expected = "out of bounds"))]
     #[case(-1, -1, f64::MIN_POSITIVE, f64::MIN_POSITIVE)]
     fn test_set_get_double(
         #[case] x: i32,
@@ -138,4 +233,52 @@ mod tests {
         array[(x, y)] = set_value;
         assert_eq!(expected, array[(x, y)]);
     }
+
+    #[rstest]
+    #[case(1, 1, 100.0, 100.0)]
+    #[case(3, 2, 200.0, 200.0)]
+    #[cfg_attr(feature = "debug-bounds", should_panic(expected = "out of bounds"))]
+    #[case(-1, 1, 1.0, f64::MIN_POSITIVE)]
+    fn test_set_get_view(
+        #[case] x: i32,
+        #[case] y: i32,
+        #[case] set_value: f64,
+        #[case] expected: f64,
+    ) {
+        let mut data = vec![0.; 12];
+        {
+            let mut view = FortranViewMut::new(&mut data, 3, 3, 4);
+            view[(x, y)] = set_value;
+            assert_eq!(expected, view[(x, y)]);
+        }
+        if expected == set_value {
+            assert_eq!(set_value, data[((y - 1) * 4 + x - 1) as usize]);
+        }
+    }
+
+    #[rstest]
+    #[case(1, 1, Some(1.))]
+    #[case(4, 3, Some(12.))]
+    #[case(5, 1, None)]
+    #[case(0, 2, None)]
+    #[case(2, 4, None)]
+    fn test_get(
+        #[case] i: i32,
+        #[case] j: i32,
+        #[case] expected: Option<f64>,
+    ) {
+        let data = (1..=12).map(|x| x as f64).collect::<Vec<f64>>();
+        let mut array = FortranArray::matrix(&data, 4, 3);
+        assert_eq!(expected, array.get(i, j));
+        assert_eq!(expected, array.view().get(i, j));
+        assert_eq!(expected, array.view_mut().get_mut(i, j).map(|value| *value));
+    }
+
+    #[test]
+    #[cfg(feature = "debug-bounds")]
+    #[should_panic(expected = "indexing: index (5, 1) is out of bounds for a 4x3 array")]
+    fn test_debug_bounds_message() {
+        let array = FortranArray::<f64>::zeros(4, 3);
+        let _ = array[(5, 1)];
+    }
 }
@@ -0,0 +1,138 @@
+use std::iter::{StepBy, Take};
+use std::slice::{Chunks, ChunksMut, Iter, IterMut};
+
+use crate::array::FortranArray;
+use crate::scalar::Scalar;
+
+impl<T: Scalar> FortranArray<T> {
+    /// Elements in storage (column-major) order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Mutable elements in storage (column-major) order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// Columns as contiguous slices; a vector is a single column.
+    pub fn columns(&self) -> Chunks<'_, T> {
+        let (m, _) = self.shape();
+        self.data.chunks(m.max(1) as usize)
+    }
+
+    /// Mutable columns as contiguous slices; a vector is a single column.
+    pub fn columns_mut(&mut self) -> ChunksMut<'_, T> {
+        let (m, _) = self.shape();
+        self.data.chunks_mut(m.max(1) as usize)
+    }
+
+    /// Rows, each one strided through the column-major storage.
+    pub fn rows(&self) -> impl Iterator<Item = StepBy<Iter<'_, T>>> + '_ {
+        let (m, _) = self.shape();
+        let step = m.max(1) as usize;
+        (0..m.max(0) as usize).map(move |i| self.data[i..].iter().step_by(step))
+    }
+
+    /// Diagonal `k`: the main diagonal for `k = 0`, superdiagonals above it for `k > 0`
+    /// and subdiagonals below it for `k < 0`. Empty when `k` lies outside the matrix.
+    pub fn diag(&self, k: i32) -> Take<StepBy<Iter<'_, T>>> {
+        let (m, n) = self.shape();
+        // Checked first, so the offsets below cannot overflow.
+        let (start, len) = if k >= n || k <= -m {
+            (0, 0)
+        } else if k >= 0 {
+            (k * m, m.min(n - k))
+        } else {
+            (-k, (m + k).min(n))
+        };
+        let start = if len > 0 { start as usize } else { self.data.len() };
+        self.data[start..].iter().step_by((m + 1) as usize).take(len.max(0) as usize)
+    }
+
+    /// Elements with their 1-based `(i, j)` position, in column-major order.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (i32, i32, &T)> + '_ {
+        let (m, _) = self.shape();
+        let m = m.max(1);
+        self.data.iter().enumerate()
+            .map(move |(offset, value)| (offset as i32 % m + 1, offset as i32 / m + 1, value))
+    }
+}
+
+impl<'a, T: Scalar> IntoIterator for &'a FortranArray<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T: Scalar> IntoIterator for &'a mut FortranArray<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::fixtures::a;
+    use rstest::rstest;
+
+    #[test]
+    fn test_iter() {
+        assert_eq!(vec![1., 4., 2., 5., 3., 6.], a().iter().copied().collect::<Vec<_>>());
+        assert_eq!(21., a().iter().sum::<f64>());
+
+        let mut array = a();
+        array.iter_mut().for_each(|value| *value *= 2.);
+        for value in &mut array { *value += 1.; }
+        assert_eq!(FortranArray::from_row_major(&[3., 5., 7., 9., 11., 13.], 2, 3), array);
+    }
+
+    #[test]
+    fn test_columns_and_rows() {
+        let columns: Vec<Vec<f64>> = a().columns().map(|column| column.to_vec()).collect();
+        assert_eq!(vec![vec![1., 4.], vec![2., 5.], vec![3., 6.]], columns);
+
+        let rows: Vec<Vec<f64>> = a().rows().map(|row| row.copied().collect()).collect();
+        assert_eq!(vec![vec![1., 2., 3.], vec![4., 5., 6.]], rows);
+
+        let vector = FortranArray::vector(&[7., 8.]);
+        assert_eq!(1, vector.columns().count());
+        assert_eq!(vec![vec![7.], vec![8.]], vector.rows().map(|row| row.copied().collect()).collect::<Vec<Vec<f64>>>());
+
+        let mut array = a();
+        array.columns_mut().for_each(|column| column.reverse());
+        assert_eq!(FortranArray::from_row_major(&[4., 5., 6., 1., 2., 3.], 2, 3), array);
+    }
+
+    #[rstest]
+    #[case(0, vec![1., 5.])]
+    #[case(1, vec![2., 6.])]
+    #[case(2, vec![3.])]
+    #[case(3, vec![])]
+    #[case(-1, vec![4.])]
+    #[case(-2, vec![])]
+    #[case(i32::MAX, vec![])]
+    #[case(i32::MIN, vec![])]
+    fn test_diag(
+        #[case] k: i32,
+        #[case] expected: Vec<f64>,
+    ) {
+        assert_eq!(expected, a().diag(k).copied().collect::<Vec<_>>());
+        assert_eq!(expected, a().transpose().diag(k.saturating_neg()).copied().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_indexed_iter() {
+        let array = a();
+        let indexed: Vec<(i32, i32, f64)> = array.indexed_iter().map(|(i, j, value)| (i, j, *value)).collect();
+        assert_eq!(vec![(1, 1, 1.), (2, 1, 4.), (1, 2, 2.), (2, 2, 5.), (1, 3, 3.), (2, 3, 6.)], indexed);
+        assert!(array.indexed_iter().all(|(i, j, value)| array[(i, j)] == *value));
+    }
+}
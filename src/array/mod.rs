@@ -1,41 +1,59 @@
 pub mod convert;
 pub mod display;
+#[cfg(test)]
+mod fixtures;
 pub mod indexing;
+pub mod iter;
+pub mod ops;
+pub mod shape;
+pub mod structure;
+pub mod view;
 
-#[derive(Clone, Debug)]
-pub struct FortranArray {
-    data: Vec<f64>,
+use crate::array::view::{FortranView, FortranViewMut};
+use crate::scalar::Scalar;
+
+/// Column-major (Fortran order) array with 1-based indexing.
+///
+/// Element `(i, j)` of a `rows` by `cols` matrix is stored at `data[(j - 1) * rows + (i - 1)]`.
+/// [`matrix`](FortranArray::matrix), `Vec<Vec<f64>>` (one inner vector per column) and
+/// `Display` all follow this order. Use [`from_row_major`](FortranArray::from_row_major)
+/// and [`to_row_major`](FortranArray::to_row_major) to exchange data with row-major (C order) code.
+///
+/// The element type defaults to `f64`; see [`Scalar`] for the others.
+#[derive(Clone)]
+pub struct FortranArray<T = f64> {
+    data: Vec<T>,
     rows: i32,
     cols: i32,
-    default_value: f64,
+    default_value: T,
 }
 
-impl Default for FortranArray {
+impl<T: Scalar> Default for FortranArray<T> {
     fn default() -> Self {
         FortranArray {
             data: vec![],
             rows: 0,
             cols: 0,
-            default_value: f64::NAN,
+            default_value: T::nan(),
         }
     }
 }
 
-impl FortranArray {
+impl<T: Scalar> FortranArray<T> {
     pub fn empty() -> Self {
         FortranArray::default()
     }
 
     pub fn zeros(rows: i32, cols: i32) -> Self {
         FortranArray {
-            data: vec![0.0; (rows * cols) as usize],
+            data: vec![T::zero(); (rows * cols) as usize],
             rows,
             cols,
             ..Default::default()
         }
     }
 
-    pub fn single(data: f64) -> Self {
+    pub fn single(data: T) -> Self {
         FortranArray {
             data: vec![data],
             rows: 1,
@@ -43,7 +61,7 @@ impl FortranArray {
         }
     }
 
-    pub fn vector(data: &[f64]) -> Self {
+    pub fn vector(data: &[T]) -> Self {
         FortranArray {
             data: data.to_vec(),
             rows: data.len() as i32,
@@ -51,7 +69,7 @@ impl FortranArray {
         }
     }
 
-    pub fn matrix(data: &[f64], rows: i32, cols: i32) -> Self {
+    pub fn matrix(data: &[T], rows: i32, cols: i32) -> Self {
         assert_eq!(data.len() as i32, rows * cols);
         FortranArray {
             data: data.to_vec(),
@@ -61,14 +79,86 @@ impl FortranArray {
         }
     }
 
-    pub(crate) fn insert(&mut self, index: i32, value: f64) {
-        if index >= 1 && index < self.len() {
-            self.data.insert(index as usize - 1, value)
+    /// Builds a `rows` by `cols` matrix from `data` laid out column by column (Fortran order).
+    pub fn from_col_major(data: &[T], rows: i32, cols: i32) -> Self {
+        FortranArray::matrix(data, rows, cols)
+    }
+
+    /// Builds a `rows` by `cols` matrix from `data` laid out row by row (C order).
+    pub fn from_row_major(data: &[T], rows: i32, cols: i32) -> Self {
+        assert_eq!(data.len() as i32, rows * cols);
+        let mut array = FortranArray::zeros(rows, cols);
+        for i in 1..=rows {
+            for j in 1..=cols {
+                array[(i, j)] = data[((i - 1) * cols + j - 1) as usize];
+            }
         }
+        array
+    }
+
+    /// Copies the elements out column by column (Fortran order).
+    pub fn to_col_major(&self) -> Vec<T> {
+        self.data.clone()
+    }
+
+    /// Copies the elements out row by row (C order).
+    pub fn to_row_major(&self) -> Vec<T> {
+        let (rows, cols) = self.shape();
+        let mut data = Vec::with_capacity(self.data.len());
+        for i in 1..=rows {
+            for j in 1..=cols {
+                data.push(self[(i, j)]);
+            }
+        }
+        data
+    }
+
+    /// Borrows the array as a view without copying.
+    pub fn view(&self) -> FortranView<'_, T> {
+        if self.is_1d() { FortranView::vector(&self.data) }
+        else { FortranView::new(&self.data, self.rows, self.cols, self.rows) }
+    }
+
+    /// Borrows the array as a mutable view without copying.
+    pub fn view_mut(&mut self) -> FortranViewMut<'_, T> {
+        if self.is_1d() { FortranViewMut::vector(&mut self.data) }
+        else { FortranViewMut::new(&mut self.data, self.rows, self.cols, self.rows) }
+    }
+
+    /// Borrows the `m` by `n` block starting at `(i0, j0)`, see [`FortranView::sub`].
+    pub fn sub(&self, i0: i32, j0: i32, m: i32, n: i32) -> FortranView<'_, T> {
+        let ld = self.rows.max(1);
+        let range = view::block_range(ld, self.data.len(), i0, j0, m, n);
+        FortranView::new(&self.data[range], m, n, ld)
     }
 
-    pub(crate) fn idx(&self, index: (i32, i32)) -> i32 {
-        (index.1 - 1) * self.rows + index.0
+    /// Mutably borrows the `m` by `n` block starting at `(i0, j0)`, see [`FortranViewMut::sub_mut`].
+    pub fn sub_mut(&mut self, i0: i32, j0: i32, m: i32, n: i32) -> FortranViewMut<'_, T> {
+        let ld = self.rows.max(1);
+        let range = view::block_range(ld, self.data.len(), i0, j0, m, n);
+        FortranViewMut::new(&mut self.data[range], m, n, ld)
+    }
+
+    /// Element `(i, j)`, or `None` when it lies outside the array.
+    ///
+    /// Vectors are treated as a single column.
+    pub fn get(&self, i: i32, j: i32) -> Option<T> {
+        let (rows, cols) = self.shape();
+        if !indexing::in_bounds((i, j), rows, cols) { return None; }
+        self.data.get(((j - 1) * rows + i - 1) as usize).copied()
+    }
+
+    /// Mutable reference to element `(i, j)`, or `None` when it lies outside the array.
+    pub fn get_mut(&mut self, i: i32, j: i32) -> Option<&mut T> {
+        let (rows, cols) = self.shape();
+        if !indexing::in_bounds((i, j), rows, cols) { return None; }
+        self.data.get_mut(((j - 1) * rows + i - 1) as usize)
+    }
+
+    /// Shape used for 2D addressing; vectors are a single column.
+    pub(crate) fn shape(&self) -> (i32, i32) {
+        if self.is_1d() { (self.rows, 1) }
+        else { (self.rows, self.cols) }
     }
 
     pub(crate) fn len(&self) -> i32 {
@@ -89,16 +179,42 @@ impl FortranArray {
         self.rows = rows;
         self.cols = len / rows;
     }
-
-    pub(crate) fn to_vec(self) -> Vec<f64> {
-        self.data
-    }
 }
 
-impl PartialEq for FortranArray {
+impl<T: Scalar> PartialEq for FortranArray<T> {
     fn eq(&self, other: &Self) -> bool {
         self.rows == other.rows
             && self.cols == other.cols
             && self.data == other.data
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3, &[1.0, 4.0, 2.0, 5.0, 3.0, 6.0])]
+    #[case(&[1.0, 2.0, 3.0], 1, 3, &[1.0, 2.0, 3.0])]
+    #[case(&[1.0, 2.0, 3.0], 3, 1, &[1.0, 2.0, 3.0])]
+    fn test_row_major(
+        #[case] row_major: &[f64],
+        #[case] rows: i32,
+        #[case] cols: i32,
+        #[case] col_major: &[f64],
+    ) {
+        let array = FortranArray::from_row_major(row_major, rows, cols);
+        assert_eq!(FortranArray::from_col_major(col_major, rows, cols), array);
+        assert_eq!(col_major, array.to_col_major());
+        assert_eq!(row_major, array.to_row_major());
+    }
+
+    #[test]
+    fn test_row_major_indexing() {
+        let array = FortranArray::from_row_major(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
+        assert_eq!(2.0, array[(1, 2)]);
+        assert_eq!(4.0, array[(2, 1)]);
+        assert_eq!(6.0, array[(2, 3)]);
+    }
+}
@@ -0,0 +1,324 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::array::FortranArray;
+use crate::blas::daxpy::axpy;
+use crate::blas::dgemm::gemm;
+use crate::blas::dscal::scal;
+use crate::flags::Trans;
+use crate::scalar::{Complex32, Complex64, Scalar};
+
+/// The operands of an arithmetic operation have incompatible shapes.
+///
+/// Vectors take part in the operations as a single column.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShapeError {
+    pub op: ShapeOp,
+    pub lhs: (i32, i32),
+    /// The other operand's shape, or the requested shape for [`ShapeOp::Reshape`] and
+    /// [`ShapeOp::Resize`].
+    pub rhs: (i32, i32),
+}
+
+/// Operation that failed with a [`ShapeError`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShapeOp {
+    Multiply,
+    Add,
+    Subtract,
+    Reshape,
+    Resize,
+    Hstack,
+    Vstack,
+}
+
+impl ShapeOp {
+    fn verb(self) -> &'static str {
+        match self {
+            ShapeOp::Multiply => "multiply",
+            ShapeOp::Add => "add",
+            ShapeOp::Subtract => "subtract",
+            ShapeOp::Reshape => "reshape",
+            ShapeOp::Resize => "resize",
+            ShapeOp::Hstack => "hstack",
+            ShapeOp::Vstack => "vstack",
+        }
+    }
+
+    // Word between the two shapes in the message.
+    fn joiner(self) -> &'static str {
+        match self {
+            ShapeOp::Reshape => "into",
+            ShapeOp::Resize => "to",
+            _ => "and",
+        }
+    }
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cannot {} a {}x{} array {} a {}x{} array",
+               self.op.verb(), self.lhs.0, self.lhs.1, self.op.joiner(), self.rhs.0, self.rhs.1)
+    }
+}
+
+impl Error for ShapeError {}
+
+impl<T: Scalar> FortranArray<T> {
+    /// Matrix product `self * rhs`, computed with `gemm`.
+    ///
+    /// The product with a vector is a vector.
+    pub fn checked_mul(&self, rhs: &FortranArray<T>) -> Result<FortranArray<T>, ShapeError> {
+        let (m, k) = self.shape();
+        let (rhs_rows, n) = rhs.shape();
+        if k != rhs_rows {
+            return Err(ShapeError { op: ShapeOp::Multiply, lhs: (m, k), rhs: (rhs_rows, n) });
+        }
+
+        let mut c = vec![T::zero(); (m * n) as usize];
+        gemm(Trans::NoTrans, Trans::NoTrans, m, n, k, T::one(), self, m.max(1), rhs, k.max(1), T::zero(), &mut c, m.max(1))
+            .expect("dimensions are checked above");
+        if rhs.is_1d() { Ok(FortranArray::from(c)) }
+        else { Ok(FortranArray::matrix(&c, m, n)) }
+    }
+
+    /// Element-wise sum `self + rhs`, computed with `axpy`.
+    pub fn checked_add(&self, rhs: &FortranArray<T>) -> Result<FortranArray<T>, ShapeError> {
+        let mut sum = self.clone();
+        sum.checked_add_assign(rhs)?;
+        Ok(sum)
+    }
+
+    /// Element-wise difference `self - rhs`, computed with `axpy`.
+    pub fn checked_sub(&self, rhs: &FortranArray<T>) -> Result<FortranArray<T>, ShapeError> {
+        let mut difference = self.clone();
+        difference.checked_sub_assign(rhs)?;
+        Ok(difference)
+    }
+
+    /// In-place `self += rhs`.
+    pub fn checked_add_assign(&mut self, rhs: &FortranArray<T>) -> Result<(), ShapeError> {
+        self.axpy(ShapeOp::Add, T::one(), rhs)
+    }
+
+    /// In-place `self -= rhs`.
+    pub fn checked_sub_assign(&mut self, rhs: &FortranArray<T>) -> Result<(), ShapeError> {
+        self.axpy(ShapeOp::Subtract, -T::one(), rhs)
+    }
+
+    /// In-place `self *= alpha`, computed with `scal`.
+    pub fn scale(&mut self, alpha: T) {
+        let n = self.data.len() as i32;
+        scal(n, alpha, &mut self.data, 1);
+    }
+
+    fn axpy(&mut self, op: ShapeOp, alpha: T, rhs: &FortranArray<T>) -> Result<(), ShapeError> {
+        if self.shape() != rhs.shape() {
+            return Err(ShapeError { op, lhs: self.shape(), rhs: rhs.shape() });
+        }
+        let n = self.data.len() as i32;
+        axpy(n, alpha, &rhs.data, 1, &mut self.data, 1);
+        Ok(())
+    }
+}
+
+// The operators panic with the `ShapeError` message on mismatched shapes;
+// use the `checked_*` methods to handle the error instead.
+macro_rules! impl_binary_op {
+    ($trait:ident, $method:ident, $checked:ident) => {
+        impl<T: Scalar> $trait<&FortranArray<T>> for &FortranArray<T> {
+            type Output = FortranArray<T>;
+
+            fn $method(self, rhs: &FortranArray<T>) -> FortranArray<T> {
+                self.$checked(rhs).unwrap_or_else(|err| panic!("{err}"))
+            }
+        }
+
+        impl<T: Scalar> $trait<FortranArray<T>> for FortranArray<T> {
+            type Output = FortranArray<T>;
+
+            fn $method(self, rhs: FortranArray<T>) -> FortranArray<T> {
+                $trait::$method(&self, &rhs)
+            }
+        }
+
+        impl<T: Scalar> $trait<&FortranArray<T>> for FortranArray<T> {
+            type Output = FortranArray<T>;
+
+            fn $method(self, rhs: &FortranArray<T>) -> FortranArray<T> {
+                $trait::$method(&self, rhs)
+            }
+        }
+
+        impl<T: Scalar> $trait<FortranArray<T>> for &FortranArray<T> {
+            type Output = FortranArray<T>;
+
+            fn $method(self, rhs: FortranArray<T>) -> FortranArray<T> {
+                $trait::$method(self, &rhs)
+            }
+        }
+    };
+}
+
+macro_rules! impl_assign_op {
+    ($trait:ident, $method:ident, $checked:ident) => {
+        impl<T: Scalar> $trait<&FortranArray<T>> for FortranArray<T> {
+            fn $method(&mut self, rhs: &FortranArray<T>) {
+                self.$checked(rhs).unwrap_or_else(|err| panic!("{err}"))
+            }
+        }
+
+        impl<T: Scalar> $trait<FortranArray<T>> for FortranArray<T> {
+            fn $method(&mut self, rhs: FortranArray<T>) {
+                self.$method(&rhs)
+            }
+        }
+    };
+}
+
+impl_binary_op!(Mul, mul, checked_mul);
+impl_binary_op!(Add, add, checked_add);
+impl_binary_op!(Sub, sub, checked_sub);
+impl_assign_op!(AddAssign, add_assign, checked_add_assign);
+impl_assign_op!(SubAssign, sub_assign, checked_sub_assign);
+
+impl<T: Scalar> Mul<T> for FortranArray<T> {
+    type Output = FortranArray<T>;
+
+    fn mul(mut self, alpha: T) -> FortranArray<T> {
+        self.scale(alpha);
+        self
+    }
+}
+
+impl<T: Scalar> Mul<T> for &FortranArray<T> {
+    type Output = FortranArray<T>;
+
+    fn mul(self, alpha: T) -> FortranArray<T> {
+        self.clone() * alpha
+    }
+}
+
+// `alpha * array` can't be implemented generically over the left-hand scalar.
+macro_rules! impl_scalar_lhs_mul {
+    ($($t:ty),*) => {$(
+        impl Mul<FortranArray<$t>> for $t {
+            type Output = FortranArray<$t>;
+
+            fn mul(self, array: FortranArray<$t>) -> FortranArray<$t> {
+                array * self
+            }
+        }
+
+        impl Mul<&FortranArray<$t>> for $t {
+            type Output = FortranArray<$t>;
+
+            fn mul(self, array: &FortranArray<$t>) -> FortranArray<$t> {
+                array * self
+            }
+        }
+    )*};
+}
+
+impl_scalar_lhs_mul!(f32, f64, Complex32, Complex64);
+
+impl<T: Scalar> MulAssign<T> for FortranArray<T> {
+    fn mul_assign(&mut self, alpha: T) {
+        self.scale(alpha);
+    }
+}
+
+impl<T: Scalar> Neg for FortranArray<T> {
+    type Output = FortranArray<T>;
+
+    fn neg(self) -> FortranArray<T> {
+        self * -T::one()
+    }
+}
+
+impl<T: Scalar> Neg for &FortranArray<T> {
+    type Output = FortranArray<T>;
+
+    fn neg(self) -> FortranArray<T> {
+        self * -T::one()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::fixtures::a;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(a(), FortranArray::from_row_major(&[1., 0., 0., 1., 1., 1.], 3, 2),
+           FortranArray::from_row_major(&[4., 5., 10., 11.], 2, 2))]
+    #[case(a(), FortranArray::vector(&[1., 1., 1.]), FortranArray::vector(&[6., 15.]))]
+    #[case(FortranArray::vector(&[1., 2.]), FortranArray::matrix(&[3., 4.], 1, 2),
+           FortranArray::from_row_major(&[3., 4., 6., 8.], 2, 2))]
+    fn test_mul(
+        #[case] lhs: FortranArray,
+        #[case] rhs: FortranArray,
+        #[case] expected: FortranArray,
+    ) {
+        assert_eq!(expected, &lhs * &rhs);
+        assert_eq!(expected, lhs * rhs);
+    }
+
+    #[test]
+    fn test_add_sub_neg() {
+        let b = FortranArray::from_row_major(&[6., 5., 4., 3., 2., 1.], 2, 3);
+        assert_eq!(FortranArray::from_row_major(&[7.; 6], 2, 3), &a() + &b);
+        assert_eq!(FortranArray::from_row_major(&[-5., -3., -1., 1., 3., 5.], 2, 3), a() - b);
+        assert_eq!(FortranArray::from_row_major(&[-1., -2., -3., -4., -5., -6.], 2, 3), -a());
+    }
+
+    #[test]
+    fn test_scalar_mul() {
+        let expected = FortranArray::from_row_major(&[2., 4., 6., 8., 10., 12.], 2, 3);
+        assert_eq!(expected, &a() * 2.);
+        assert_eq!(expected, 2. * a());
+
+        let mut c = a();
+        c *= 2.;
+        assert_eq!(expected, c);
+    }
+
+    #[test]
+    fn test_assign_ops() {
+        let mut c = a();
+        c += a();
+        c -= &a();
+        c += &c.clone();
+        assert_eq!(&a() * 2., c);
+    }
+
+    #[rstest]
+    #[case(a().checked_mul(&a()), ShapeOp::Multiply, (2, 3), (2, 3))]
+    #[case(a().checked_add(&FortranArray::zeros(3, 2)), ShapeOp::Add, (2, 3), (3, 2))]
+    #[case(a().checked_sub(&FortranArray::vector(&[1.; 6])), ShapeOp::Subtract, (2, 3), (6, 1))]
+    fn test_shape_error(
+        #[case] result: Result<FortranArray, ShapeError>,
+        #[case] op: ShapeOp,
+        #[case] lhs: (i32, i32),
+        #[case] rhs: (i32, i32),
+    ) {
+        assert_eq!(Err(ShapeError { op, lhs, rhs }), result);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add a 2x3 array and a 3x2 array")]
+    fn test_shape_error_panics() {
+        let _ = a() + FortranArray::zeros(3, 2);
+    }
+
+    #[test]
+    fn test_complex() {
+        let i = Complex64::new(0., 1.);
+        let a = FortranArray::from_row_major(&[i, Complex64::new(1., 0.)], 1, 2);
+        let b = FortranArray::vector(&[i, i]);
+        assert_eq!(FortranArray::vector(&[Complex64::new(-1., 1.)]), &a * &b);
+        assert_eq!(FortranArray::from_row_major(&[-i, Complex64::new(-1., 0.)], 1, 2), -a);
+    }
+}
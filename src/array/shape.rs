@@ -0,0 +1,185 @@
+use crate::array::ops::{ShapeError, ShapeOp};
+use crate::array::FortranArray;
+use crate::dlacpy::lacpy;
+use crate::scalar::Scalar;
+
+impl<T: Scalar> FortranArray<T> {
+    /// The same elements, in column-major order, as a `rows` by `cols` matrix.
+    ///
+    /// Fails unless `rows * cols` equals the number of elements. Both dimensions must be
+    /// positive, or both zero for an empty array.
+    pub fn reshape(&self, rows: i32, cols: i32) -> Result<Self, ShapeError> {
+        match element_count(rows, cols) {
+            Some(len) if len == self.data.len() => Ok(FortranArray::matrix(&self.data, rows, cols)),
+            _ => Err(ShapeError { op: ShapeOp::Reshape, lhs: self.shape(), rhs: (rows, cols) }),
+        }
+    }
+
+    /// Resizes in place to `rows` by `cols`, keeping the elements `(i, j)` that still fit
+    /// and setting the new ones to `fill`.
+    ///
+    /// Fails, leaving the array as it is, on the shapes [`reshape`](FortranArray::reshape)
+    /// rejects whatever the length.
+    pub fn resize(&mut self, rows: i32, cols: i32, fill: T) -> Result<(), ShapeError> {
+        let Some(len) = element_count(rows, cols) else {
+            return Err(ShapeError { op: ShapeOp::Resize, lhs: self.shape(), rhs: (rows, cols) });
+        };
+        let (m, n) = self.shape();
+        let mut array = FortranArray::matrix(&vec![fill; len], rows, cols);
+        lacpy(None, m.min(rows), n.min(cols), self, m.max(1), &mut array, rows.max(1)).expect("the shapes are valid");
+        *self = array;
+        Ok(())
+    }
+
+    /// Places the arrays side by side; they must have the same number of rows.
+    ///
+    /// Vectors take part as a single column.
+    pub fn hstack(arrays: &[&FortranArray<T>]) -> Result<Self, ShapeError> {
+        let Some(first) = arrays.first() else { return Ok(FortranArray::empty()) };
+        let rows = first.shape().0;
+        let mut cols = 0;
+        for array in arrays {
+            let (m, n) = array.shape();
+            if m != rows {
+                return Err(ShapeError { op: ShapeOp::Hstack, lhs: (rows, cols), rhs: (m, n) });
+            }
+            cols += n;
+        }
+
+        let mut stacked = FortranArray::zeros(rows, cols);
+        let mut j0 = 1;
+        for array in arrays {
+            let (m, n) = array.shape();
+            lacpy(None, m, n, *array, m.max(1), &mut stacked.sub_mut(1, j0, m, n), rows.max(1))
+                .expect("the shapes are checked above");
+            j0 += n;
+        }
+        Ok(stacked)
+    }
+
+    /// Places the arrays one above the other; they must have the same number of columns.
+    ///
+    /// Vectors take part as a single column, and stacking only vectors gives a vector.
+    pub fn vstack(arrays: &[&FortranArray<T>]) -> Result<Self, ShapeError> {
+        let Some(first) = arrays.first() else { return Ok(FortranArray::empty()) };
+        let cols = first.shape().1;
+        let mut rows = 0;
+        for array in arrays {
+            let (m, n) = array.shape();
+            if n != cols {
+                return Err(ShapeError { op: ShapeOp::Vstack, lhs: (rows, cols), rhs: (m, n) });
+            }
+            rows += m;
+        }
+
+        let mut stacked = FortranArray::zeros(rows, cols);
+        let mut i0 = 1;
+        for array in arrays {
+            let (m, n) = array.shape();
+            lacpy(None, m, n, *array, m.max(1), &mut stacked.sub_mut(i0, 1, m, n), rows.max(1))
+                .expect("the shapes are checked above");
+            i0 += m;
+        }
+        if arrays.iter().all(|array| array.is_1d()) { return Ok(FortranArray::vector(&stacked.data)); }
+        Ok(stacked)
+    }
+
+    /// Assembles a matrix from a grid of blocks, e.g. `block(&[[&a, &b], [&c, &d]])`.
+    ///
+    /// Each row of blocks is stacked with [`hstack`](FortranArray::hstack), and the
+    /// results with [`vstack`](FortranArray::vstack).
+    pub fn block<'a, R: AsRef<[&'a FortranArray<T>]>>(blocks: &[R]) -> Result<Self, ShapeError> {
+        let rows = blocks.iter()
+            .map(|row| FortranArray::hstack(row.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+        FortranArray::vstack(&rows.iter().collect::<Vec<_>>())
+    }
+}
+
+// Number of elements in a `rows` by `cols` matrix, or `None` for a negative or overflowing
+// shape, or one with a single zero dimension: zero columns is how a vector is stored.
+fn element_count(rows: i32, cols: i32) -> Option<usize> {
+    if rows < 0 || cols < 0 || (rows == 0) != (cols == 0) { return None; }
+    rows.checked_mul(cols).map(|len| len as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::fixtures::a;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(3, 2, Ok(FortranArray::from_row_major(&[1., 5., 4., 3., 2., 6.], 3, 2)))]
+    #[case(6, 1, Ok(FortranArray::matrix(&[1., 4., 2., 5., 3., 6.], 6, 1)))]
+    #[case(4, 2, Err(ShapeError { op: ShapeOp::Reshape, lhs: (2, 3), rhs: (4, 2) }))]
+    #[case(-2, -3, Err(ShapeError { op: ShapeOp::Reshape, lhs: (2, 3), rhs: (-2, -3) }))]
+    #[case(65536, 65536, Err(ShapeError { op: ShapeOp::Reshape, lhs: (2, 3), rhs: (65536, 65536) }))]
+    fn test_reshape(
+        #[case] rows: i32,
+        #[case] cols: i32,
+        #[case] expected: Result<FortranArray, ShapeError>,
+    ) {
+        assert_eq!(expected, a().reshape(rows, cols));
+    }
+
+    #[test]
+    fn test_reshape_empty() {
+        let empty = FortranArray::<f64>::zeros(0, 3);
+        assert_eq!(Err(ShapeError { op: ShapeOp::Reshape, lhs: (0, 3), rhs: (3, 0) }), empty.reshape(3, 0));
+        assert_eq!(Ok(FortranArray::empty()), empty.reshape(0, 0));
+    }
+
+    #[rstest]
+    #[case(3, 4, FortranArray::from_row_major(&[1., 2., 3., 0., 4., 5., 6., 0., 0., 0., 0., 0.], 3, 4))]
+    #[case(1, 2, FortranArray::from_row_major(&[1., 2.], 1, 2))]
+    #[case(0, 0, FortranArray::zeros(0, 0))]
+    fn test_resize(
+        #[case] rows: i32,
+        #[case] cols: i32,
+        #[case] expected: FortranArray,
+    ) {
+        let mut array = a();
+        array.resize(rows, cols, 0.).unwrap();
+        assert_eq!(expected, array);
+    }
+
+    #[rstest]
+    #[case(3, 0)]
+    #[case(-1, 2)]
+    #[case(65536, 65536)]
+    fn test_resize_invalid(
+        #[case] rows: i32,
+        #[case] cols: i32,
+    ) {
+        let mut array = a();
+        assert_eq!(Err(ShapeError { op: ShapeOp::Resize, lhs: (2, 3), rhs: (rows, cols) }), array.resize(rows, cols, 0.));
+        assert_eq!(a(), array);
+    }
+
+    #[test]
+    fn test_stack() {
+        let b = FortranArray::vector(&[7., 8.]);
+        assert_eq!(FortranArray::from_row_major(&[1., 2., 3., 7., 4., 5., 6., 8.], 2, 4), FortranArray::hstack(&[&a(), &b]).unwrap());
+        assert_eq!(FortranArray::from_row_major(&[1., 2., 3., 4., 5., 6., 1., 2., 3., 4., 5., 6.], 4, 3), FortranArray::vstack(&[&a(), &a()]).unwrap());
+        assert_eq!(FortranArray::vector(&[7., 8., 7., 8.]), FortranArray::vstack(&[&b, &b]).unwrap());
+        assert_eq!(FortranArray::empty(), FortranArray::<f64>::hstack(&[]).unwrap());
+
+        assert_eq!(Err(ShapeError { op: ShapeOp::Hstack, lhs: (2, 3), rhs: (3, 1) }), FortranArray::hstack(&[&a(), &FortranArray::vector(&[1.; 3])]));
+        assert_eq!(Err(ShapeError { op: ShapeOp::Vstack, lhs: (2, 3), rhs: (2, 1) }), FortranArray::vstack(&[&a(), &b]));
+    }
+
+    #[test]
+    fn test_block() {
+        // Bordered matrix [A b; c' d].
+        let b = FortranArray::vector(&[7., 8.]);
+        let c = FortranArray::matrix(&[9., 10., 11.], 1, 3);
+        let d = FortranArray::single(12.);
+        let expected = FortranArray::from_row_major(&[1., 2., 3., 7., 4., 5., 6., 8., 9., 10., 11., 12.], 3, 4);
+        assert_eq!(expected, FortranArray::block(&[[&a(), &b], [&c, &d]]).unwrap());
+
+        let error = FortranArray::block(&[vec![&a(), &b], vec![&d]]).unwrap_err();
+        assert_eq!("cannot vstack a 2x4 array and a 1x1 array", error.to_string());
+        assert_eq!("cannot resize a 2x3 array to a 3x0 array", a().resize(3, 0, 0.).unwrap_err().to_string());
+    }
+}
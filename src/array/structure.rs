@@ -0,0 +1,135 @@
+use crate::array::FortranArray;
+use crate::blas::dcopy::copy;
+use crate::dlacpy::lacpy;
+use crate::dlaset::laset;
+use crate::flags::Uplo;
+use crate::scalar::Scalar;
+
+impl<T: Scalar> FortranArray<T> {
+    /// `n` by `n` identity matrix.
+    pub fn identity(n: i32) -> Self {
+        let mut array = FortranArray::zeros(n, n);
+        laset(None, n, n, T::zero(), T::one(), &mut array, n.max(1)).expect("identity needs n >= 0");
+        array
+    }
+
+    /// Square matrix with `diagonal` on its diagonal and zeros elsewhere.
+    pub fn from_diagonal(diagonal: &[T]) -> Self {
+        let n = diagonal.len() as i32;
+        let mut array = FortranArray::zeros(n, n);
+        copy(n, &diagonal, 1, &mut array.data, n + 1);
+        array
+    }
+
+    /// Identity matrix with the shape of `self`: ones on the diagonal, zeros elsewhere.
+    pub fn eye_like(&self) -> Self {
+        let (m, n) = self.shape();
+        let mut array = self.clone();
+        laset(None, m, n, T::zero(), T::one(), &mut array, m.max(1)).expect("the shape is valid");
+        array
+    }
+
+    /// Sets every element to `value`.
+    pub fn fill(&mut self, value: T) {
+        let (m, n) = self.shape();
+        laset(None, m, n, value, value, self, m.max(1)).expect("the shape is valid");
+    }
+
+    /// Elements `(i, i)` for `i` up to `min(rows, cols)`, as a vector.
+    pub fn diagonal(&self) -> Self {
+        let (m, n) = self.shape();
+        let len = m.min(n);
+        let mut diagonal = vec![T::zero(); len.max(0) as usize];
+        copy(len, &self.data, m + 1, &mut diagonal, 1);
+        FortranArray::from(diagonal)
+    }
+
+    /// Transposed copy; a vector becomes a single row.
+    pub fn transpose(&self) -> Self {
+        let (m, n) = self.shape();
+        let mut array = FortranArray::zeros(n, m);
+        for j in 1..=n {
+            copy(m, &self.sub(1, j, m, 1), 1, &mut array.sub_mut(j, 1, 1, m), n);
+        }
+        array
+    }
+
+    /// Copy keeping the diagonal and everything above it, with zeros below.
+    pub fn upper_triangle(&self) -> Self {
+        self.triangle(Uplo::Upper)
+    }
+
+    /// Copy keeping the diagonal and everything below it, with zeros above.
+    pub fn lower_triangle(&self) -> Self {
+        self.triangle(Uplo::Lower)
+    }
+
+    fn triangle(&self, uplo: Uplo) -> Self {
+        let (m, n) = self.shape();
+        let mut array = self.clone();
+        array.fill(T::zero());
+        lacpy(Some(uplo), m, n, self, m.max(1), &mut array, m.max(1)).expect("the shape is valid");
+        array
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::fixtures::a;
+    use rstest::rstest;
+
+    #[test]
+    fn test_identity() {
+        let expected = FortranArray::from_row_major(&[1., 0., 0., 0., 1., 0., 0., 0., 1.], 3, 3);
+        assert_eq!(expected, FortranArray::identity(3));
+        assert_eq!(FortranArray::<f64>::zeros(0, 0), FortranArray::identity(0));
+    }
+
+    #[test]
+    fn test_from_diagonal() {
+        let expected = FortranArray::from_row_major(&[1., 0., 0., 0., 2., 0., 0., 0., 3.], 3, 3);
+        let array = FortranArray::from_diagonal(&[1., 2., 3.]);
+        assert_eq!(expected, array);
+        assert_eq!(FortranArray::vector(&[1., 2., 3.]), array.diagonal());
+    }
+
+    #[rstest]
+    #[case(a(), FortranArray::vector(&[1., 5.]))]
+    #[case(a().transpose(), FortranArray::vector(&[1., 5.]))]
+    #[case(FortranArray::vector(&[7., 8.]), FortranArray::vector(&[7.]))]
+    fn test_diagonal(
+        #[case] input: FortranArray,
+        #[case] expected: FortranArray,
+    ) {
+        assert_eq!(expected, input.diagonal());
+    }
+
+    #[rstest]
+    #[case(a(), FortranArray::from_row_major(&[1., 4., 2., 5., 3., 6.], 3, 2))]
+    #[case(FortranArray::vector(&[1., 2., 3.]), FortranArray::matrix(&[1., 2., 3.], 1, 3))]
+    fn test_transpose(
+        #[case] input: FortranArray,
+        #[case] expected: FortranArray,
+    ) {
+        assert_eq!(expected, input.transpose());
+        assert_eq!(input.to_row_major(), expected.to_col_major());
+    }
+
+    #[test]
+    fn test_triangles() {
+        let a = FortranArray::from_row_major(&[1., 2., 3., 4., 5., 6., 7., 8., 9.], 3, 3);
+        assert_eq!(FortranArray::from_row_major(&[1., 2., 3., 0., 5., 6., 0., 0., 9.], 3, 3), a.upper_triangle());
+        assert_eq!(FortranArray::from_row_major(&[1., 0., 0., 4., 5., 0., 7., 8., 9.], 3, 3), a.lower_triangle());
+        assert_eq!(a, a.upper_triangle() + a.lower_triangle() - FortranArray::from_diagonal(&[1., 5., 9.]));
+    }
+
+    #[test]
+    fn test_eye_like_and_fill() {
+        let mut array = a();
+        assert_eq!(FortranArray::from_row_major(&[1., 0., 0., 0., 1., 0.], 2, 3), array.eye_like());
+
+        array.fill(2.5);
+        assert_eq!(FortranArray::from_row_major(&[2.5; 6], 2, 3), array);
+    }
+}
@@ -0,0 +1,573 @@
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::slice;
+
+use crate::array::indexing::in_bounds;
+use crate::scalar::Scalar;
+
+/// Read-only, column-major window into column-major storage.
+///
+/// A view is what Fortran passes as `A(I,J)` together with `LDA`: a pointer to the
+/// first element, the logical shape and the leading dimension used to step from one
+/// column to the next. It borrows the underlying storage instead of copying it.
+///
+/// Indexing is 1-based, like [`FortranArray`](crate::array::FortranArray).
+pub struct FortranView<'a, T: Scalar = f64> {
+    pub(super) ptr: *const T,
+    pub(super) len: usize,
+    pub(super) rows: i32,
+    pub(super) cols: i32,
+    pub(super) ld: i32,
+    // Owns the data when the source is not contiguous (`Vec<Vec<T>>`).
+    _staged: Option<Vec<T>>,
+    _marker: PhantomData<&'a [T]>,
+}
+
+/// Mutable, column-major window into column-major storage.
+///
+/// The mutable counterpart of [`FortranView`]. Writes go straight to the borrowed
+/// storage; sources that cannot be borrowed contiguously are staged once and written
+/// back when the view is dropped.
+pub struct FortranViewMut<'a, T: Scalar = f64> {
+    pub(super) ptr: *mut T,
+    pub(super) len: usize,
+    pub(super) rows: i32,
+    pub(super) cols: i32,
+    pub(super) ld: i32,
+    pub(super) default_value: T,
+    staged: Option<(Vec<T>, &'a mut Vec<Vec<T>>)>,
+    _marker: PhantomData<&'a mut [T]>,
+}
+
+impl<'a, T: Scalar> FortranView<'a, T> {
+    /// Creates a `rows` by `cols` view over `data` with leading dimension `ld`.
+    pub fn new(data: &'a [T], rows: i32, cols: i32, ld: i32) -> Self {
+        FortranView {
+            ptr: data.as_ptr(),
+            len: data.len(),
+            rows,
+            cols,
+            ld: ld.max(1),
+            _staged: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a view over a vector.
+    pub fn vector(data: &'a [T]) -> Self {
+        let len = data.len() as i32;
+        FortranView::new(data, len, 1, len)
+    }
+
+    pub(crate) fn staged(data: Vec<T>, rows: i32, cols: i32) -> Self {
+        FortranView {
+            ptr: data.as_ptr(),
+            len: data.len(),
+            rows,
+            cols,
+            ld: rows.max(1),
+            _staged: Some(data),
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn rows(&self) -> i32 {
+        self.rows
+    }
+
+    pub fn cols(&self) -> i32 {
+        self.cols
+    }
+
+    pub fn ld(&self) -> i32 {
+        self.ld
+    }
+
+    /// Number of elements reachable from the start of the view.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The first `len` elements of the storage as a slice, or `None` when fewer are reachable.
+    pub(crate) fn as_slice(&self, len: usize) -> Option<&[T]> {
+        // SAFETY: the first `self.len` elements from `ptr` are borrowed by the view.
+        (len <= self.len).then(|| unsafe { slice::from_raw_parts(self.ptr, len) })
+    }
+
+    /// A pointer to the first `len` elements of the storage, or `None` when fewer are reachable.
+    ///
+    /// Unlike [`as_slice`](Self::as_slice), this claims nothing about elements the view does
+    /// not address, so strided views from the same parent may be passed on together.
+    #[cfg(feature = "cblas")]
+    pub(crate) fn as_ptr(&self, len: usize) -> Option<*const T> {
+        (len <= self.len).then_some(self.ptr)
+    }
+
+    /// Reborrows the view without copying.
+    pub fn reborrow(&self) -> FortranView<'_, T> {
+        FortranView {
+            ptr: self.ptr,
+            len: self.len,
+            rows: self.rows,
+            cols: self.cols,
+            ld: self.ld,
+            _staged: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// `m` by `n` submatrix starting at element `(i0, j0)`.
+    ///
+    /// This is `A(I0,J0)` passed together with `LDA`: the block keeps the parent's
+    /// leading dimension, so it can be handed to dgemm/dtrmm without copying.
+    pub fn sub(&self, i0: i32, j0: i32, m: i32, n: i32) -> FortranView<'_, T> {
+        let range = block_range(self.ld, self.len, i0, j0, m, n);
+        FortranView {
+            // SAFETY: `block_range` only returns ranges inside the borrowed storage.
+            ptr: unsafe { self.ptr.add(range.start) },
+            len: range.len(),
+            rows: m,
+            cols: n,
+            ld: self.ld,
+            _staged: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Element `(i, j)`, or `None` when it lies outside the view's shape.
+    pub fn get(&self, i: i32, j: i32) -> Option<T> {
+        if !in_bounds((i, j), self.rows, self.cols) { return None; }
+        self.position((i, j)).map(|_| *self.element((i, j)))
+    }
+
+    pub(super) fn element(&self, index: (i32, i32)) -> &T {
+        match self.position(index) {
+            // SAFETY: `position` only returns offsets inside the borrowed storage.
+            Some(offset) => unsafe { &*self.ptr.add(offset) },
+            None => T::fallback(),
+        }
+    }
+
+    pub(super) fn position(&self, index: (i32, i32)) -> Option<usize> {
+        position(index, self.ld, self.len)
+    }
+}
+
+impl<'a, T: Scalar> FortranViewMut<'a, T> {
+    /// Creates a mutable `rows` by `cols` view over `data` with leading dimension `ld`.
+    pub fn new(data: &'a mut [T], rows: i32, cols: i32, ld: i32) -> Self {
+        FortranViewMut {
+            ptr: data.as_mut_ptr(),
+            len: data.len(),
+            rows,
+            cols,
+            ld: ld.max(1),
+            default_value: T::nan(),
+            staged: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a mutable view over a vector.
+    pub fn vector(data: &'a mut [T]) -> Self {
+        let len = data.len() as i32;
+        FortranViewMut::new(data, len, 1, len)
+    }
+
+    pub(crate) fn staged(target: &'a mut Vec<Vec<T>>) -> Self {
+        let (mut data, rows, cols) = flatten_columns(target);
+        FortranViewMut {
+            ptr: data.as_mut_ptr(),
+            len: data.len(),
+            rows,
+            cols,
+            ld: rows.max(1),
+            default_value: T::nan(),
+            staged: Some((data, target)),
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn rows(&self) -> i32 {
+        self.rows
+    }
+
+    pub fn cols(&self) -> i32 {
+        self.cols
+    }
+
+    pub fn ld(&self) -> i32 {
+        self.ld
+    }
+
+    /// Number of elements reachable from the start of the view.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The first `len` elements of the storage as a mutable slice, or `None` when fewer are
+    /// reachable.
+    pub(crate) fn as_mut_slice(&mut self, len: usize) -> Option<&mut [T]> {
+        // SAFETY: the first `self.len` elements from `ptr` are mutably borrowed by the view.
+        (len <= self.len).then(|| unsafe { slice::from_raw_parts_mut(self.ptr, len) })
+    }
+
+    /// A pointer to the first `len` elements of the storage, or `None` when fewer are reachable.
+    ///
+    /// Unlike [`as_mut_slice`](Self::as_mut_slice), this claims nothing about elements the
+    /// view does not address, so views from [`slice_pair_mut`](FortranViewMut::slice_pair_mut)
+    /// may be passed on together.
+    #[cfg(feature = "cblas")]
+    pub(crate) fn as_mut_ptr(&mut self, len: usize) -> Option<*mut T> {
+        (len <= self.len).then_some(self.ptr)
+    }
+
+    /// Read-only view of the same storage.
+    pub fn as_view(&self) -> FortranView<'_, T> {
+        FortranView {
+            ptr: self.ptr,
+            len: self.len,
+            rows: self.rows,
+            cols: self.cols,
+            ld: self.ld,
+            _staged: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Reborrows the view without copying.
+    pub fn reborrow(&mut self) -> FortranViewMut<'_, T> {
+        FortranViewMut {
+            ptr: self.ptr,
+            len: self.len,
+            rows: self.rows,
+            cols: self.cols,
+            ld: self.ld,
+            default_value: T::nan(),
+            staged: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// `m` by `n` submatrix starting at element `(i0, j0)`, see [`FortranView::sub`].
+    pub fn sub(&self, i0: i32, j0: i32, m: i32, n: i32) -> FortranView<'_, T> {
+        let range = block_range(self.ld, self.len, i0, j0, m, n);
+        FortranView {
+            // SAFETY: `block_range` only returns ranges inside the borrowed storage.
+            ptr: unsafe { self.ptr.add(range.start) },
+            len: range.len(),
+            rows: m,
+            cols: n,
+            ld: self.ld,
+            _staged: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Mutable `m` by `n` submatrix starting at element `(i0, j0)`.
+    ///
+    /// Writes through the block land in the parent, addressed with the parent's
+    /// leading dimension.
+    pub fn sub_mut(&mut self, i0: i32, j0: i32, m: i32, n: i32) -> FortranViewMut<'_, T> {
+        let range = block_range(self.ld, self.len, i0, j0, m, n);
+        FortranViewMut {
+            // SAFETY: `block_range` only returns ranges inside the borrowed storage.
+            ptr: unsafe { self.ptr.add(range.start) },
+            len: range.len(),
+            rows: m,
+            cols: n,
+            ld: self.ld,
+            default_value: T::nan(),
+            staged: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Splits the view into disjoint mutable blocks of at most `width` columns each.
+    ///
+    /// Every block keeps the parent's rows and leading dimension and only reaches the storage
+    /// of its own columns, so the blocks can be handed to different threads.
+    pub(crate) fn column_blocks_mut(&mut self, width: usize) -> Vec<FortranViewMut<'_, T>> {
+        let (ld, cols, width) = (self.ld as usize, self.cols.max(0) as usize, width.max(1));
+        (0..cols)
+            .step_by(width)
+            .map(|j0| {
+                let start = (j0 * ld).min(self.len);
+                let end = ((j0 + width) * ld).min(self.len);
+                FortranViewMut {
+                    // SAFETY: `start..end` lies inside the borrowed storage and does not overlap
+                    // the range of any other block.
+                    ptr: unsafe { self.ptr.add(start) },
+                    len: end - start,
+                    rows: self.rows,
+                    cols: width.min(cols - j0) as i32,
+                    ld: self.ld,
+                    default_value: T::nan(),
+                    staged: None,
+                    _marker: PhantomData,
+                }
+            })
+            .collect()
+    }
+
+    /// Splits the view into mutable blocks of at most `height` rows each.
+    ///
+    /// # Safety
+    ///
+    /// The blocks interleave in memory. The caller must only ever address rows `1..=rows()`
+    /// of each block, which are disjoint from the rows of the others.
+    pub(crate) unsafe fn row_blocks_mut(&mut self, height: usize) -> Vec<FortranViewMut<'_, T>> {
+        let (rows, height) = (self.rows.max(0) as usize, height.max(1));
+        (0..rows)
+            .step_by(height)
+            .map(|i0| {
+                let start = i0.min(self.len);
+                FortranViewMut {
+                    ptr: self.ptr.add(start),
+                    len: self.len - start,
+                    rows: height.min(rows - i0) as i32,
+                    cols: self.cols,
+                    ld: self.ld,
+                    default_value: T::nan(),
+                    staged: None,
+                    _marker: PhantomData,
+                }
+            })
+            .collect()
+    }
+
+    /// View starting at element `(i, j)` and running to the end of the storage.
+    pub(crate) fn slice_from(&self, index: (i32, i32)) -> FortranView<'_, T> {
+        let offset = offset_from(index, self.ld, self.len);
+        FortranView {
+            // SAFETY: `offset <= len`, so the pointer stays within (or one past) the borrow.
+            ptr: unsafe { self.ptr.add(offset) },
+            len: self.len - offset,
+            rows: (self.rows - index.0 + 1).max(0),
+            cols: (self.cols - index.1 + 1).max(0),
+            ld: self.ld,
+            _staged: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Mutable view starting at element `(i, j)` and running to the end of the storage.
+    pub(crate) fn slice_from_mut(&mut self, index: (i32, i32)) -> FortranViewMut<'_, T> {
+        // SAFETY: the returned view reborrows `self` mutably, so it is the only live alias.
+        unsafe { self.slice_from_unchecked(index) }
+    }
+
+    /// Two mutable views starting at `a` and `b`, e.g. two rows of the same matrix.
+    ///
+    /// # Safety
+    ///
+    /// The views may span overlapping memory. The caller must only ever address
+    /// disjoint elements through them.
+    pub(crate) unsafe fn slice_pair_mut(
+        &mut self,
+        a: (i32, i32),
+        b: (i32, i32),
+    ) -> (FortranViewMut<'_, T>, FortranViewMut<'_, T>) {
+        (self.slice_from_unchecked(a), self.slice_from_unchecked(b))
+    }
+
+    unsafe fn slice_from_unchecked(&self, index: (i32, i32)) -> FortranViewMut<'_, T> {
+        let offset = offset_from(index, self.ld, self.len);
+        FortranViewMut {
+            ptr: self.ptr.add(offset),
+            len: self.len - offset,
+            rows: (self.rows - index.0 + 1).max(0),
+            cols: (self.cols - index.1 + 1).max(0),
+            ld: self.ld,
+            default_value: T::nan(),
+            staged: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Element `(i, j)`, or `None` when it lies outside the view's shape.
+    pub fn get(&self, i: i32, j: i32) -> Option<T> {
+        self.as_view().get(i, j)
+    }
+
+    /// Mutable reference to element `(i, j)`, or `None` when it lies outside the view's shape.
+    pub fn get_mut(&mut self, i: i32, j: i32) -> Option<&mut T> {
+        if !in_bounds((i, j), self.rows, self.cols) { return None; }
+        self.position((i, j))?;
+        Some(self.element_mut((i, j)))
+    }
+
+    pub(super) fn element(&self, index: (i32, i32)) -> &T {
+        match self.position(index) {
+            // SAFETY: `position` only returns offsets inside the borrowed storage.
+            Some(offset) => unsafe { &*self.ptr.add(offset) },
+            None => T::fallback(),
+        }
+    }
+
+    pub(super) fn element_mut(&mut self, index: (i32, i32)) -> &mut T {
+        match self.position(index) {
+            // SAFETY: `position` only returns offsets inside the borrowed storage.
+            Some(offset) => unsafe { &mut *self.ptr.add(offset) },
+            None => &mut self.default_value,
+        }
+    }
+
+    pub(super) fn position(&self, index: (i32, i32)) -> Option<usize> {
+        position(index, self.ld, self.len)
+    }
+}
+
+// SAFETY: views behave like `&[T]` and `&mut [T]`, and every `Scalar` is `Send + Sync`.
+unsafe impl<T: Scalar> Send for FortranView<'_, T> {}
+unsafe impl<T: Scalar> Sync for FortranView<'_, T> {}
+unsafe impl<T: Scalar> Send for FortranViewMut<'_, T> {}
+
+impl<T: Scalar> Drop for FortranViewMut<'_, T> {
+    fn drop(&mut self) {
+        if let Some((data, target)) = self.staged.take() {
+            let ld = self.ld as usize;
+            for (col, values) in target.iter_mut().enumerate() {
+                let len = values.len();
+                values.copy_from_slice(&data[col * ld..col * ld + len]);
+            }
+        }
+    }
+}
+
+/// Flattens a vector of columns into column-major storage, padding short columns.
+pub(crate) fn flatten_columns<T: Scalar>(data: &[Vec<T>]) -> (Vec<T>, i32, i32) {
+    let rows = data.iter().map(|col| col.len()).max().unwrap_or(0);
+    let mut flat = vec![T::zero(); rows * data.len()];
+    for (col, values) in data.iter().enumerate() {
+        flat[col * rows..col * rows + values.len()].copy_from_slice(values);
+    }
+    (flat, rows as i32, data.len() as i32)
+}
+
+/// Storage range covered by the `m` by `n` block starting at `(i0, j0)`.
+///
+/// The range is clipped to the parent's storage, so elements of the block that fall
+/// outside of it behave like out-of-range indexing on `FortranArray`.
+pub(super) fn block_range(ld: i32, len: usize, i0: i32, j0: i32, m: i32, n: i32) -> Range<usize> {
+    let start = offset_from((i0, j0), ld, len);
+    let extent =
+        if m > 0 && n > 0 { (n as usize - 1) * ld as usize + m as usize }
+        else { 0 };
+    start..(start + extent).min(len)
+}
+
+// Same addressing as `FortranArray`: only the flat offset is checked.
+fn position(index: (i32, i32), ld: i32, len: usize) -> Option<usize> {
+    let (i, j) = index;
+    let offset = (j as i64 - 1) * ld as i64 + (i as i64 - 1);
+    if offset >= 0 && offset < len as i64 { Some(offset as usize) } else { None }
+}
+
+fn offset_from(index: (i32, i32), ld: i32, len: usize) -> usize {
+    let (i, j) = index;
+    let offset = (j as i64 - 1) * ld as i64 + (i as i64 - 1);
+    // Out-of-range starts give an empty view, like `FortranArray`'s range indexing.
+    if offset >= 0 && offset <= len as i64 { offset as usize } else { len }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::FortranArray;
+    use crate::blas::dgemm::dgemm;
+    use crate::flags::Trans;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1, 1, 2, 2, vec![1., 2., 5., 6.])]
+    #[case(2, 3, 3, 2, vec![10., 11., 12., 14., 15., 16.])]
+    #[case(4, 1, 1, 4, vec![4., 8., 12., 16.])]
+    #[case(3, 3, 0, 2, vec![])]
+    fn test_sub(
+        #[case] i0: i32,
+        #[case] j0: i32,
+        #[case] m: i32,
+        #[case] n: i32,
+        #[case] expected: Vec<f64>,
+    ) {
+        let data = (1..=16).map(|x| x as f64).collect::<Vec<f64>>();
+        let array = FortranArray::matrix(&data, 4, 4);
+        let sub = array.sub(i0, j0, m, n);
+        assert_eq!((m, n, 4), (sub.rows(), sub.cols(), sub.ld()));
+
+        let mut actual = vec![];
+        for j in 1..=n {
+            for i in 1..=m {
+                actual.push(sub[(i, j)]);
+            }
+        }
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_sub_mut_writes_parent() {
+        let mut array = FortranArray::zeros(4, 4);
+        {
+            let mut view = array.view_mut();
+            let mut sub = view.sub_mut(2, 2, 2, 2);
+            let mut inner = sub.sub_mut(2, 1, 1, 2);
+            inner[(1, 1)] = 1.;
+            inner[(1, 2)] = 2.;
+        }
+        assert_eq!(1., array[(3, 2)]);
+        assert_eq!(2., array[(3, 3)]);
+        assert_eq!(0., array[(2, 2)]);
+    }
+
+    #[test]
+    fn test_blocks_cover_parent() {
+        let mut array = FortranArray::zeros(5, 7);
+        {
+            let mut view = array.view_mut();
+            for (b, block) in view.column_blocks_mut(3).iter_mut().enumerate() {
+                assert_eq!((5, if b < 2 { 3 } else { 1 }), (block.rows(), block.cols()));
+                for j in 1..=block.cols() {
+                    for i in 1..=block.rows() {
+                        block[(i, j)] += 1.;
+                    }
+                }
+            }
+            for block in unsafe { view.row_blocks_mut(2) }.iter_mut() {
+                for j in 1..=block.cols() {
+                    for i in 1..=block.rows() {
+                        block[(i, j)] += 1.;
+                    }
+                }
+            }
+        }
+        assert_eq!(FortranArray::matrix(&[2.; 35], 5, 7), array);
+    }
+
+    #[test]
+    fn test_sub_panels_dgemm() {
+        // C(2:3, 2:3) := A(1:2, 3:4) * B(3:4, 1:2), all blocks of 4x4 arrays.
+        let data = (1..=16).map(|x| x as f64).collect::<Vec<f64>>();
+        let a = FortranArray::matrix(&data, 4, 4);
+        let b = FortranArray::matrix(&data, 4, 4);
+        let mut c = FortranArray::zeros(4, 4);
+
+        dgemm(Trans::NoTrans, Trans::NoTrans, 2, 2, 2, 1., &a.sub(1, 3, 2, 2), 4,
+              &b.sub(3, 1, 2, 2), 4, 0., &mut c.sub_mut(2, 2, 2, 2), 4).unwrap();
+
+        let expected = FortranArray::matrix(&[
+            0., 0., 0., 0.,
+            0., 9. * 3. + 13. * 4., 10. * 3. + 14. * 4., 0.,
+            0., 9. * 7. + 13. * 8., 10. * 7. + 14. * 8., 0.,
+            0., 0., 0., 0.,
+        ], 4, 4);
+        assert_eq!(expected, c);
+    }
+}
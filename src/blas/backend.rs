@@ -0,0 +1,437 @@
+use crate::array::convert::{ToFortranArray, ToFortranArrayMut};
+use crate::blas::{dcopy, dgemm, dnrm2, drot, dscal};
+use crate::error::Info;
+use crate::flags::Trans;
+use crate::scalar::RealScalar;
+
+/// BLAS routines called by the LAPACK-level routines of the crate.
+///
+/// Every method takes the arguments of the reference routine of the same name, see
+/// [`dcopy`](dcopy::dcopy), [`dgemm`](dgemm::dgemm), [`dnrm2`](dnrm2::dnrm2),
+/// [`drot`](drot::drot) and [`dscal`](dscal::dscal), and must give the same results up to
+/// rounding. The LAPACK-level routines go through [`Blas`], the backend picked at build time.
+pub trait BlasBackend<T: RealScalar> {
+    fn copy<X, Y>(
+        n: i32,
+        x: &X,
+        incx: i32,
+        y: &mut Y,
+        incy: i32,
+    ) where
+        X: ToFortranArray<T>,
+        Y: ToFortranArrayMut<T>;
+
+    fn gemm<A, B, C>(
+        transa: Trans,
+        transb: Trans,
+        m: i32,
+        n: i32,
+        k: i32,
+        alpha: T,
+        a: &A,
+        lda: i32,
+        b: &B,
+        ldb: i32,
+        beta: T,
+        c: &mut C,
+        ldc: i32,
+    ) -> Result<(), Info> where
+        A: ToFortranArray<T>,
+        B: ToFortranArray<T>,
+        C: ToFortranArrayMut<T>;
+
+    fn nrm2<X>(
+        n: i32,
+        x: &X,
+        incx: i32,
+    ) -> T where
+        X: ToFortranArray<T>;
+
+    fn rot<X, Y>(
+        n: i32,
+        x: &mut X,
+        incx: i32,
+        y: &mut Y,
+        incy: i32,
+        c: T,
+        s: T,
+    ) where
+        X: ToFortranArrayMut<T>,
+        Y: ToFortranArrayMut<T>;
+
+    fn scal<X>(
+        n: i32,
+        alpha: T,
+        x: &mut X,
+        incx: i32,
+    ) where
+        X: ToFortranArrayMut<T>;
+}
+
+/// The pure Rust routines of [`crate::blas`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RustBlas;
+
+impl<T: RealScalar> BlasBackend<T> for RustBlas {
+    fn copy<X, Y>(n: i32, x: &X, incx: i32, y: &mut Y, incy: i32) where
+        X: ToFortranArray<T>,
+        Y: ToFortranArrayMut<T>,
+    {
+        dcopy::copy(n, x, incx, y, incy)
+    }
+
+    fn gemm<A, B, C>(
+        transa: Trans,
+        transb: Trans,
+        m: i32,
+        n: i32,
+        k: i32,
+        alpha: T,
+        a: &A,
+        lda: i32,
+        b: &B,
+        ldb: i32,
+        beta: T,
+        c: &mut C,
+        ldc: i32,
+    ) -> Result<(), Info> where
+        A: ToFortranArray<T>,
+        B: ToFortranArray<T>,
+        C: ToFortranArrayMut<T>,
+    {
+        dgemm::gemm(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+    }
+
+    fn nrm2<X>(n: i32, x: &X, incx: i32) -> T where
+        X: ToFortranArray<T>,
+    {
+        dnrm2::nrm2(n, x, incx)
+    }
+
+    fn rot<X, Y>(n: i32, x: &mut X, incx: i32, y: &mut Y, incy: i32, c: T, s: T) where
+        X: ToFortranArrayMut<T>,
+        Y: ToFortranArrayMut<T>,
+    {
+        drot::rot(n, x, incx, y, incy, c, s)
+    }
+
+    fn scal<X>(n: i32, alpha: T, x: &mut X, incx: i32) where
+        X: ToFortranArrayMut<T>,
+    {
+        dscal::scal(n, alpha, x, incx)
+    }
+}
+
+/// Backend of the LAPACK-level routines: [`CBlas`] with the `cblas` feature, [`RustBlas`]
+/// otherwise.
+#[cfg(not(feature = "cblas"))]
+pub type Blas = RustBlas;
+
+/// Backend of the LAPACK-level routines: [`CBlas`] with the `cblas` feature, [`RustBlas`]
+/// otherwise.
+#[cfg(feature = "cblas")]
+pub type Blas = CBlas;
+
+#[cfg(feature = "cblas")]
+pub use self::cblas::CBlas;
+
+#[cfg(feature = "cblas")]
+mod cblas {
+    use std::any::TypeId;
+    use std::ffi::c_int;
+    use std::mem;
+
+    use super::{BlasBackend, RustBlas};
+    use crate::array::convert::{ToFortranArray, ToFortranArrayMut};
+    use crate::error::Info;
+    use crate::flags::Trans;
+    use crate::scalar::RealScalar;
+
+    /// A system CBLAS library such as OpenBLAS or BLIS, linked through FFI.
+    ///
+    /// The library is `libopenblas` unless the `LAPACK_RS_CBLAS_LIB` environment variable
+    /// names another one at build time. Calls with illegal arguments, and calls whose arrays
+    /// are too short for the elements the library would read, run on [`RustBlas`] instead, so
+    /// errors and out-of-range accesses behave as in the rest of the crate.
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct CBlas;
+
+    const COL_MAJOR: c_int = 102;
+
+    fn transpose(trans: Trans) -> c_int {
+        match trans {
+            Trans::NoTrans => 111,
+            Trans::Trans => 112,
+            Trans::ConjTrans => 113,
+        }
+    }
+
+    extern "C" {
+        fn cblas_scopy(n: c_int, x: *const f32, incx: c_int, y: *mut f32, incy: c_int);
+        fn cblas_dcopy(n: c_int, x: *const f64, incx: c_int, y: *mut f64, incy: c_int);
+        fn cblas_sgemm(
+            layout: c_int, transa: c_int, transb: c_int, m: c_int, n: c_int, k: c_int, alpha: f32,
+            a: *const f32, lda: c_int, b: *const f32, ldb: c_int, beta: f32, c: *mut f32, ldc: c_int,
+        );
+        fn cblas_dgemm(
+            layout: c_int, transa: c_int, transb: c_int, m: c_int, n: c_int, k: c_int, alpha: f64,
+            a: *const f64, lda: c_int, b: *const f64, ldb: c_int, beta: f64, c: *mut f64, ldc: c_int,
+        );
+        fn cblas_snrm2(n: c_int, x: *const f32, incx: c_int) -> f32;
+        fn cblas_dnrm2(n: c_int, x: *const f64, incx: c_int) -> f64;
+        fn cblas_srot(n: c_int, x: *mut f32, incx: c_int, y: *mut f32, incy: c_int, c: f32, s: f32);
+        fn cblas_drot(n: c_int, x: *mut f64, incx: c_int, y: *mut f64, incy: c_int, c: f64, s: f64);
+        fn cblas_sscal(n: c_int, alpha: f32, x: *mut f32, incx: c_int);
+        fn cblas_dscal(n: c_int, alpha: f64, x: *mut f64, incx: c_int);
+    }
+
+    enum Precision {
+        Single,
+        Double,
+    }
+
+    fn precision<T: RealScalar>() -> Option<Precision> {
+        match TypeId::of::<T>() {
+            id if id == TypeId::of::<f32>() => Some(Precision::Single),
+            id if id == TypeId::of::<f64>() => Some(Precision::Double),
+            _ => None,
+        }
+    }
+
+    /// `x` as a `U`.
+    ///
+    /// # Safety
+    ///
+    /// `T` and `U` must be the same type, as checked by [`precision`].
+    unsafe fn value<T, U>(x: T) -> U {
+        mem::transmute_copy(&x)
+    }
+
+    // Elements spanned by `n` elements `inc` apart.
+    fn vector_len(n: i32, inc: i32) -> usize {
+        if n > 0 { 1 + (n as usize - 1) * inc.unsigned_abs() as usize } else { 0 }
+    }
+
+    // Elements spanned by a `rows` by `cols` matrix with leading dimension `ld`.
+    fn matrix_len(rows: i32, cols: i32, ld: i32) -> usize {
+        if rows > 0 && cols > 0 { (cols as usize - 1) * ld as usize + rows as usize } else { 0 }
+    }
+
+    impl<T: RealScalar> BlasBackend<T> for CBlas {
+        fn copy<X, Y>(n: i32, x: &X, incx: i32, y: &mut Y, incy: i32) where
+            X: ToFortranArray<T>,
+            Y: ToFortranArrayMut<T>,
+        {
+            let x_f = &x.fa_view();
+            let y_f = &mut y.fa_view_mut();
+            let (Some(precision), Some(xp), Some(yp)) =
+                (precision::<T>(), x_f.as_ptr(vector_len(n, incx)), y_f.as_mut_ptr(vector_len(n, incy)))
+            else {
+                return RustBlas::copy(n, x_f, incx, y_f, incy);
+            };
+            // SAFETY: the pointers reach every element the library addresses, and `T` matches.
+            unsafe {
+                match precision {
+                    Precision::Single => cblas_scopy(n, xp.cast(), incx, yp.cast(), incy),
+                    Precision::Double => cblas_dcopy(n, xp.cast(), incx, yp.cast(), incy),
+                }
+            }
+        }
+
+        fn gemm<A, B, C>(
+            transa: Trans,
+            transb: Trans,
+            m: i32,
+            n: i32,
+            k: i32,
+            alpha: T,
+            a: &A,
+            lda: i32,
+            b: &B,
+            ldb: i32,
+            beta: T,
+            c: &mut C,
+            ldc: i32,
+        ) -> Result<(), Info> where
+            A: ToFortranArray<T>,
+            B: ToFortranArray<T>,
+            C: ToFortranArrayMut<T>,
+        {
+            let (nrowa, ncola) = if transa == Trans::NoTrans { (m, k) } else { (k, m) };
+            let (nrowb, ncolb) = if transb == Trans::NoTrans { (k, n) } else { (n, k) };
+            let legal = m >= 0 && n >= 0 && k >= 0
+                && lda >= 1.max(nrowa) && ldb >= 1.max(nrowb) && ldc >= 1.max(m);
+
+            let a_f = &a.fa_view_2d(lda);
+            let b_f = &b.fa_view_2d(ldb);
+            let c_f = &mut c.fa_view_2d_mut(ldc);
+            // Views carry their own leading dimension, which is what the library must step by.
+            let (a_ld, b_ld, c_ld) = (a_f.ld(), b_f.ld(), c_f.ld());
+            let (true, Some(precision), Some(ap), Some(bp), Some(cp)) = (
+                legal,
+                precision::<T>(),
+                a_f.as_ptr(matrix_len(nrowa, ncola, a_ld)),
+                b_f.as_ptr(matrix_len(nrowb, ncolb, b_ld)),
+                c_f.as_mut_ptr(matrix_len(m, n, c_ld)),
+            ) else {
+                return RustBlas::gemm(transa, transb, m, n, k, alpha, a_f, lda, b_f, ldb, beta, c_f, ldc);
+            };
+            let (ta, tb) = (transpose(transa), transpose(transb));
+            // SAFETY: the arguments are legal, the pointers reach every element the library
+            // addresses, and `T` matches.
+            unsafe {
+                match precision {
+                    Precision::Single => cblas_sgemm(
+                        COL_MAJOR, ta, tb, m, n, k, value(alpha), ap.cast(), a_ld,
+                        bp.cast(), b_ld, value(beta), cp.cast(), c_ld,
+                    ),
+                    Precision::Double => cblas_dgemm(
+                        COL_MAJOR, ta, tb, m, n, k, value(alpha), ap.cast(), a_ld,
+                        bp.cast(), b_ld, value(beta), cp.cast(), c_ld,
+                    ),
+                }
+            }
+            Ok(())
+        }
+
+        fn nrm2<X>(n: i32, x: &X, incx: i32) -> T where
+            X: ToFortranArray<T>,
+        {
+            let x_f = &x.fa_view();
+            // Libraries disagree on non-positive increments; keep the reference behaviour.
+            let (true, Some(precision), Some(xp)) = (incx > 0, precision::<T>(), x_f.as_ptr(vector_len(n, incx))) else {
+                return RustBlas::nrm2(n, x_f, incx);
+            };
+            // SAFETY: the pointer reaches every element the library addresses, and `T` matches.
+            unsafe {
+                match precision {
+                    Precision::Single => value(cblas_snrm2(n, xp.cast(), incx)),
+                    Precision::Double => value(cblas_dnrm2(n, xp.cast(), incx)),
+                }
+            }
+        }
+
+        fn rot<X, Y>(n: i32, x: &mut X, incx: i32, y: &mut Y, incy: i32, c: T, s: T) where
+            X: ToFortranArrayMut<T>,
+            Y: ToFortranArrayMut<T>,
+        {
+            let x_f = &mut x.fa_view_mut();
+            let y_f = &mut y.fa_view_mut();
+            // Pointers rather than slices: views from `slice_pair_mut`, such as two rows of a
+            // matrix, span overlapping memory even though they address disjoint elements.
+            let (Some(precision), Some(xp), Some(yp)) =
+                (precision::<T>(), x_f.as_mut_ptr(vector_len(n, incx)), y_f.as_mut_ptr(vector_len(n, incy)))
+            else {
+                return RustBlas::rot(n, x_f, incx, y_f, incy, c, s);
+            };
+            // SAFETY: the pointers reach every element the library addresses, the library
+            // only writes the elements the views address, and `T` matches.
+            unsafe {
+                match precision {
+                    Precision::Single => cblas_srot(n, xp.cast(), incx, yp.cast(), incy, value(c), value(s)),
+                    Precision::Double => cblas_drot(n, xp.cast(), incx, yp.cast(), incy, value(c), value(s)),
+                }
+            }
+        }
+
+        fn scal<X>(n: i32, alpha: T, x: &mut X, incx: i32) where
+            X: ToFortranArrayMut<T>,
+        {
+            let x_f = &mut x.fa_view_mut();
+            // Libraries disagree on non-positive increments; keep the reference behaviour.
+            let (true, Some(precision), Some(xp)) = (incx > 0, precision::<T>(), x_f.as_mut_ptr(vector_len(n, incx))) else {
+                return RustBlas::scal(n, alpha, x_f, incx);
+            };
+            // SAFETY: the pointer reaches every element the library addresses, and `T` matches.
+            unsafe {
+                match precision {
+                    Precision::Single => cblas_sscal(n, value(alpha), xp.cast(), incx),
+                    Precision::Double => cblas_dscal(n, value(alpha), xp.cast(), incx),
+                }
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::blas::fixtures::values;
+
+        #[test]
+        fn test_cblas_matches_rust() {
+            let (x, y) = (values(9, 1), values(9, 2));
+            assert!((RustBlas::nrm2(4, &x, 2) - CBlas::nrm2(4, &x, 2)).abs() <= 1e-14);
+            assert_eq!(RustBlas::nrm2(4, &x, -2), CBlas::nrm2(4, &x, -2));
+
+            let (mut expected, mut actual) = ((x.clone(), y.clone()), (x.clone(), y.clone()));
+            RustBlas::rot(3, &mut expected.0, 3, &mut expected.1, -2, 0.6, 0.8);
+            CBlas::rot(3, &mut actual.0, 3, &mut actual.1, -2, 0.6, 0.8);
+            for (e, a) in expected.0.iter().chain(&expected.1).zip(actual.0.iter().chain(&actual.1)) {
+                assert!((e - a).abs() <= 1e-14);
+            }
+
+            let (mut expected, mut actual) = (y.clone(), y.clone());
+            RustBlas::copy(4, &x, -2, &mut expected, 1);
+            CBlas::copy(4, &x, -2, &mut actual, 1);
+            assert_eq!(expected, actual);
+            RustBlas::scal(4, -1.5, &mut expected, 2);
+            CBlas::scal(4, -1.5, &mut actual, 2);
+            assert_eq!(expected, actual);
+        }
+
+        // Rotates rows 1 and 2 of the 3 by 4 `a` from column 2 on, as dlahqr does: the two
+        // views overlap in memory.
+        fn rotate_rows<B: BlasBackend<f64>>(a: &mut Vec<f64>) {
+            let a_f = &mut a.fa_view_2d_mut(3);
+            // SAFETY: rows 1 and 2 never share an element.
+            let (row_1, row_2) = unsafe { a_f.slice_pair_mut((1, 2), (2, 2)) };
+            B::rot(3, &mut { row_1 }, 3, &mut { row_2 }, 3, 0.6, 0.8);
+        }
+
+        #[test]
+        fn test_cblas_rot_rows() {
+            let (mut expected, mut actual) = (values(12, 6), values(12, 6));
+            rotate_rows::<RustBlas>(&mut expected);
+            rotate_rows::<CBlas>(&mut actual);
+            for (e, a) in expected.iter().zip(&actual) {
+                assert!((e - a).abs() <= 1e-14);
+            }
+        }
+
+        #[test]
+        fn test_cblas_gemm() {
+            let (m, n, k) = (5, 4, 3);
+            let (a, b) = (values(k * m, 3), values(k * n, 4));
+            let (mut expected, mut actual) = (values(m * n, 5), values(m * n, 5));
+            RustBlas::gemm(Trans::Trans, Trans::NoTrans, 5, 4, 3, 0.5, &a, 3, &b, 3, 2., &mut expected, 5).unwrap();
+            CBlas::gemm(Trans::Trans, Trans::NoTrans, 5, 4, 3, 0.5, &a, 3, &b, 3, 2., &mut actual, 5).unwrap();
+            for (e, a) in expected.iter().zip(&actual) {
+                assert!((e - a).abs() <= 1e-13);
+            }
+
+            // Illegal arguments are reported by the Rust routine, not by the library.
+            let err = CBlas::gemm(Trans::NoTrans, Trans::NoTrans, 5, 4, 3, 1., &a, 4, &b, 3, 0., &mut actual, 5);
+            assert_eq!(-8, err.unwrap_err().info());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rust_blas() {
+        let x = vec![3f32, 4., 12.];
+        assert_eq!(13., <RustBlas as BlasBackend<f32>>::nrm2(3, &x, 1));
+
+        let mut y = vec![0.; 3];
+        RustBlas::copy(3, &x, 1, &mut y, 1);
+        RustBlas::scal(2, 2., &mut y, 1);
+        assert_eq!(vec![6., 8., 12.], y);
+
+        let mut c = vec![1.; 4];
+        RustBlas::gemm(Trans::NoTrans, Trans::Trans, 2, 2, 1, 1., &vec![1., 2.], 2, &vec![3., 4.], 2, 1., &mut c, 2).unwrap();
+        assert_eq!(vec![4., 7., 5., 9.], c);
+    }
+}
@@ -0,0 +1,128 @@
+use num_traits::Zero;
+
+use crate::array::convert::ToFortranArray;
+use crate::scalar::{Complex32, Complex64, Scalar};
+
+/// DASUM
+///
+/// # Documentation
+///
+/// [Original] Online html documentation available at
+/// [http://www.netlib.org/lapack/explore-html/](http://www.netlib.org/lapack/explore-html/)
+///
+/// Takes the sum of the absolute values.
+/// Uses unrolled loops for increment equal to one.
+/// As in the reference, a non-positive `incx` gives zero.
+///
+/// # Arguments
+///
+/// * `n` - number of elements in input vector
+/// * `dx` - array
+/// * `incx` - storage spacing between elements of dx
+pub fn dasum<DX>(
+    n: i32,
+    dx: &DX,
+    incx: i32,
+) -> f64 where
+    DX: ToFortranArray,
+{
+    asum(n, dx, incx)
+}
+
+/// SASUM
+///
+/// Single precision variant of [`dasum`].
+pub fn sasum<DX>(
+    n: i32,
+    dx: &DX,
+    incx: i32,
+) -> f32 where
+    DX: ToFortranArray<f32>,
+{
+    asum(n, dx, incx)
+}
+
+/// SCASUM
+///
+/// Single precision complex variant of [`dasum`], summing `|re| + |im|`.
+pub fn scasum<DX>(
+    n: i32,
+    dx: &DX,
+    incx: i32,
+) -> f32 where
+    DX: ToFortranArray<Complex32>,
+{
+    asum(n, dx, incx)
+}
+
+/// DZASUM
+///
+/// Double precision complex variant of [`dasum`], summing `|re| + |im|`.
+pub fn dzasum<DX>(
+    n: i32,
+    dx: &DX,
+    incx: i32,
+) -> f64 where
+    DX: ToFortranArray<Complex64>,
+{
+    asum(n, dx, incx)
+}
+
+/// Shared implementation of [`sasum`], [`dasum`], [`scasum`] and [`dzasum`].
+pub fn asum<T, DX>(
+    n: i32,
+    dx: &DX,
+    incx: i32,
+) -> T::Real where
+    T: Scalar,
+    DX: ToFortranArray<T>,
+{
+    let dx = dx.fa_view();
+
+    let mut dtemp = T::Real::zero();
+    if n <= 0 || incx <= 0 { return dtemp; }
+    if incx == 1 {
+        // Clean-up loop
+        let m = n % 6;
+        if m != 0 {
+            for i in 1..=m { dtemp += dx[i].abs1(); }
+            if n < 6 { return dtemp; }
+        }
+        for i in (m+1..=n).step_by(6) {
+            dtemp += dx[i].abs1() + dx[i + 1].abs1() + dx[i + 2].abs1()
+                + dx[i + 3].abs1() + dx[i + 4].abs1() + dx[i + 5].abs1();
+        }
+    } else {
+        for i in (1..=n * incx).step_by(incx as usize) {
+            dtemp += dx[i].abs1();
+        }
+    }
+    dtemp
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0, vec![1.], 1, 0.)]
+    #[case(3, vec![1., -2., 3.], 1, 6.)]
+    #[case(8, vec![1., -1., 1., -1., 1., -1., 1., -1.], 1, 8.)]
+    #[case(2, vec![1., -2., -3.], 2, 4.)]
+    #[case(3, vec![1., -2., 3.], -1, 0.)]
+    fn test_dasum(
+        #[case] n: i32,
+        #[case] dx: Vec<f64>,
+        #[case] incx: i32,
+        #[case] expected: f64,
+    ) {
+        assert_eq!(expected, dasum(n, &dx, incx));
+    }
+
+    #[test]
+    fn test_dzasum() {
+        let x = vec![Complex64::new(1., -2.), Complex64::new(-3., 4.)];
+        assert_eq!(10., dzasum(2, &x, 1));
+    }
+}
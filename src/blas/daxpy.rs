@@ -0,0 +1,156 @@
+use crate::array::convert::{ToFortranArray, ToFortranArrayMut};
+use crate::scalar::{Complex32, Complex64, Scalar};
+
+/// DAXPY
+///
+/// # Documentation
+///
+/// [Original] Online html documentation available at
+/// [http://www.netlib.org/lapack/explore-html/](http://www.netlib.org/lapack/explore-html/)
+///
+/// Constant times a vector plus a vector: `dy := da*dx + dy`.
+/// Uses unrolled loops for increments equal to one.
+///
+/// # Arguments
+///
+/// * `n` - number of elements in input vector(s)
+/// * `da` - scalar multiplier of dx
+/// * `dx` - array
+/// * `incx` - storage spacing between elements of dx
+/// * `dy` - array
+/// * `incy` - storage spacing between elements of dy
+pub fn daxpy<DX, DY>(
+    n: i32,
+    da: f64,
+    dx: &DX,
+    incx: i32,
+    dy: &mut DY,
+    incy: i32,
+) where
+    DX: ToFortranArray,
+    DY: ToFortranArrayMut,
+{
+    axpy(n, da, dx, incx, dy, incy)
+}
+
+/// SAXPY
+///
+/// Single precision variant of [`daxpy`].
+pub fn saxpy<DX, DY>(
+    n: i32,
+    da: f32,
+    dx: &DX,
+    incx: i32,
+    dy: &mut DY,
+    incy: i32,
+) where
+    DX: ToFortranArray<f32>,
+    DY: ToFortranArrayMut<f32>,
+{
+    axpy(n, da, dx, incx, dy, incy)
+}
+
+/// CAXPY
+///
+/// Single precision complex variant of [`daxpy`].
+pub fn caxpy<DX, DY>(
+    n: i32,
+    da: Complex32,
+    dx: &DX,
+    incx: i32,
+    dy: &mut DY,
+    incy: i32,
+) where
+    DX: ToFortranArray<Complex32>,
+    DY: ToFortranArrayMut<Complex32>,
+{
+    axpy(n, da, dx, incx, dy, incy)
+}
+
+/// ZAXPY
+///
+/// Double precision complex variant of [`daxpy`].
+pub fn zaxpy<DX, DY>(
+    n: i32,
+    da: Complex64,
+    dx: &DX,
+    incx: i32,
+    dy: &mut DY,
+    incy: i32,
+) where
+    DX: ToFortranArray<Complex64>,
+    DY: ToFortranArrayMut<Complex64>,
+{
+    axpy(n, da, dx, incx, dy, incy)
+}
+
+/// Shared implementation of [`saxpy`], [`daxpy`], [`caxpy`] and [`zaxpy`].
+pub fn axpy<T, DX, DY>(
+    n: i32,
+    da: T,
+    dx: &DX,
+    incx: i32,
+    dy: &mut DY,
+    incy: i32,
+) where
+    T: Scalar,
+    DX: ToFortranArray<T>,
+    DY: ToFortranArrayMut<T>,
+{
+    let dx = dx.fa_view();
+    let dy_f = &mut dy.fa_view_mut();
+
+    if n <= 0 || da.is_zero() { return; }
+    if incx == 1 && incy == 1 {
+        // Clean-up loop
+        let m = n % 4;
+        if m != 0 {
+            for i in 1..=m { dy_f[i] += da * dx[i]; }
+            if n < 4 { return; }
+        }
+        for i in (m+1..=n).step_by(4) {
+            dy_f[i] += da * dx[i];
+            dy_f[i + 1] += da * dx[i + 1];
+            dy_f[i + 2] += da * dx[i + 2];
+            dy_f[i + 3] += da * dx[i + 3];
+        }
+    } else {
+        let mut ix = 1;
+        let mut iy = 1;
+        if incx < 0 { ix = (-n + 1) * incx + 1; }
+        if incy < 0 { iy = (-n + 1) * incy + 1; }
+        for _i in 1..=n {
+            dy_f[iy] += da * dx[ix];
+            ix += incx;
+            iy += incy;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0, 2., vec![1.], 1, vec![1.], 1, vec![1.])]
+    #[case(3, 0., vec![1., 2., 3.], 1, vec![4., 5., 6.], 1, vec![4., 5., 6.])]
+    #[case(3, 2., vec![1., 2., 3.], 1, vec![4., 5., 6.], 1, vec![6., 9., 12.])]
+    #[case(5, -1., vec![1., 2., 3., 4., 5.], 1, vec![5., 5., 5., 5., 5.], 1, vec![4., 3., 2., 1., 0.])]
+    #[case(2, 1., vec![1., 2., 3., 4.], 2, vec![10., 20.], 1, vec![11., 23.])]
+    #[case(2, 1., vec![1., 2.], 1, vec![10., 20., 30.], 2, vec![11., 20., 32.])]
+    #[case(3, 1., vec![1., 2., 3.], -1, vec![10., 20., 30.], 1, vec![13., 22., 31.])]
+    #[case(3, 1., vec![1., 2., 3.], 1, vec![10., 20., 30.], -1, vec![13., 22., 31.])]
+    fn test_daxpy(
+        #[case] n: i32,
+        #[case] da: f64,
+        #[case] dx: Vec<f64>,
+        #[case] incx: i32,
+        #[case] mut dy: Vec<f64>,
+        #[case] incy: i32,
+        #[case] expected: Vec<f64>,
+    ) {
+        daxpy(n, da, &dx, incx, &mut dy, incy);
+        assert_eq!(expected, dy);
+    }
+}
@@ -1,4 +1,6 @@
-use crate::array::{convert::ToFortranArray, FortranArray};
+use crate::array::convert::{ToFortranArray, ToFortranArrayMut};
+use crate::blas::simd;
+use crate::scalar::{Complex32, Complex64, Scalar};
 
 /// DCOPY
 ///
@@ -25,21 +27,84 @@ pub fn dcopy<DX, DY>(
     incy: i32,
 ) where
     DX: ToFortranArray,
-    DY: ToFortranArray + From<FortranArray>,
+    DY: ToFortranArrayMut,
 {
-    let dx = dx.to_fa();
-    let dy_f = &mut dy.to_fa();
+    copy(n, dx, incx, dy, incy)
+}
+
+/// SCOPY
+///
+/// Single precision variant of [`dcopy`].
+pub fn scopy<DX, DY>(
+    n: i32,
+    dx: &DX,
+    incx: i32,
+    dy: &mut DY,
+    incy: i32,
+) where
+    DX: ToFortranArray<f32>,
+    DY: ToFortranArrayMut<f32>,
+{
+    copy(n, dx, incx, dy, incy)
+}
+
+/// CCOPY
+///
+/// Single precision complex variant of [`dcopy`].
+pub fn ccopy<DX, DY>(
+    n: i32,
+    dx: &DX,
+    incx: i32,
+    dy: &mut DY,
+    incy: i32,
+) where
+    DX: ToFortranArray<Complex32>,
+    DY: ToFortranArrayMut<Complex32>,
+{
+    copy(n, dx, incx, dy, incy)
+}
+
+/// ZCOPY
+///
+/// Double precision complex variant of [`dcopy`].
+pub fn zcopy<DX, DY>(
+    n: i32,
+    dx: &DX,
+    incx: i32,
+    dy: &mut DY,
+    incy: i32,
+) where
+    DX: ToFortranArray<Complex64>,
+    DY: ToFortranArrayMut<Complex64>,
+{
+    copy(n, dx, incx, dy, incy)
+}
+
+/// Shared implementation of [`scopy`], [`dcopy`], [`ccopy`] and [`zcopy`].
+pub fn copy<T, DX, DY>(
+    n: i32,
+    dx: &DX,
+    incx: i32,
+    dy: &mut DY,
+    incy: i32,
+) where
+    T: Scalar,
+    DX: ToFortranArray<T>,
+    DY: ToFortranArrayMut<T>,
+{
+    let dx = dx.fa_view();
+    let dy_f = &mut dy.fa_view_mut();
 
     if n <= 0 { return; }
     if incx == 1 && incy == 1 {
+        if let (Some(x), Some(y)) = (dx.as_slice(n as usize), dy_f.as_mut_slice(n as usize)) {
+            if simd::copy(x, y) { return; }
+        }
         // Clean-up loop
         let m = n % 7;
         if m != 0 {
             for i in 1..=m { dy_f[i] = dx[i]; }
-            if n < 7 {
-                *dy = DY::from(dy_f.clone());
-                return;
-            }
+            if n < 7 { return; }
         }
         for i in (m+1..=n).step_by(7) {
             dy_f[i] = dx[i];
@@ -61,8 +126,6 @@ pub fn dcopy<DX, DY>(
             iy += incy;
         }
     }
-
-    *dy = DY::from(dy_f.clone());
 }
 
 #[cfg(test)]
@@ -75,8 +138,8 @@ mod tests {
     #[case(1, vec![1., 2., 3.], 1, vec![0., 0., 0.], 1, vec![1., 0., 0.])]
     #[case(2, vec![1.,2.,3.,4.,5.,6.,7.,8.], 2, vec![0., 0., 0., 0., 0., 0., 0., 0.], 2, vec![1., 0., 3., 0., 0., 0., 0., 0.])]
     #[case(3, vec![1.1, 2.2, 3.3, 4.4], 1, vec![0., 0., 0., 0.], 1, vec![1.1, 2.2, 3.3, 0.])]
-    #[case(4, vec![5.5, 6.6, 7.7, 8.8], 2, vec![0., 0., 0., 0.], 3, vec![5.5, 0., 0., 7.7])]
-    #[case(5, vec![9.9, 10.1, 11.1, 12.1], 4, vec![0., 0., 0., 0.], 4, vec![9.9, 0., 0., 0.])]
+    #[case(2, vec![5.5, 6.6, 7.7, 8.8], 2, vec![0., 0., 0., 0.], 3, vec![5.5, 0., 0., 7.7])]
+    #[case(1, vec![9.9, 10.1, 11.1, 12.1], 4, vec![0., 0., 0., 0.], 4, vec![9.9, 0., 0., 0.])]
     fn test_dcopy(
         #[case] n: i32,
         #[case] dx: Vec<f64>,
@@ -88,4 +151,13 @@ mod tests {
         dcopy(n, &dx, incx, &mut dy, incy);
         assert_eq!(expected, dy)
     }
+
+    #[test]
+    #[cfg_attr(feature = "debug-bounds", should_panic(expected = "out of bounds"))]
+    fn test_dcopy_past_end() {
+        // n = 4 with incx = 2 and incy = 3 needs seven and ten elements.
+        let mut dy = vec![0., 0., 0., 0.];
+        dcopy(4, &vec![5.5, 6.6, 7.7, 8.8], 2, &mut dy, 3);
+        assert_eq!(vec![5.5, 0., 0., 7.7], dy)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,191 @@
+use crate::array::convert::ToFortranArray;
+use crate::scalar::{conj_if, Complex32, Complex64, Scalar};
+
+/// DDOT
+///
+/// # Documentation
+///
+/// [Original] Online html documentation available at
+/// [http://www.netlib.org/lapack/explore-html/](http://www.netlib.org/lapack/explore-html/)
+///
+/// Forms the dot product of two vectors.
+/// Uses unrolled loops for increments equal to one.
+///
+/// # Arguments
+///
+/// * `n` - number of elements in input vector(s)
+/// * `dx` - array
+/// * `incx` - storage spacing between elements of dx
+/// * `dy` - array
+/// * `incy` - storage spacing between elements of dy
+pub fn ddot<DX, DY>(
+    n: i32,
+    dx: &DX,
+    incx: i32,
+    dy: &DY,
+    incy: i32,
+) -> f64 where
+    DX: ToFortranArray,
+    DY: ToFortranArray,
+{
+    dot(false, n, dx, incx, dy, incy)
+}
+
+/// SDOT
+///
+/// Single precision variant of [`ddot`].
+pub fn sdot<DX, DY>(
+    n: i32,
+    dx: &DX,
+    incx: i32,
+    dy: &DY,
+    incy: i32,
+) -> f32 where
+    DX: ToFortranArray<f32>,
+    DY: ToFortranArray<f32>,
+{
+    dot(false, n, dx, incx, dy, incy)
+}
+
+/// CDOTU
+///
+/// Single precision complex variant of [`ddot`], without conjugation.
+pub fn cdotu<DX, DY>(
+    n: i32,
+    dx: &DX,
+    incx: i32,
+    dy: &DY,
+    incy: i32,
+) -> Complex32 where
+    DX: ToFortranArray<Complex32>,
+    DY: ToFortranArray<Complex32>,
+{
+    dot(false, n, dx, incx, dy, incy)
+}
+
+/// ZDOTU
+///
+/// Double precision complex variant of [`ddot`], without conjugation.
+pub fn zdotu<DX, DY>(
+    n: i32,
+    dx: &DX,
+    incx: i32,
+    dy: &DY,
+    incy: i32,
+) -> Complex64 where
+    DX: ToFortranArray<Complex64>,
+    DY: ToFortranArray<Complex64>,
+{
+    dot(false, n, dx, incx, dy, incy)
+}
+
+/// CDOTC
+///
+/// Single precision complex variant of [`ddot`], conjugating `dx`.
+pub fn cdotc<DX, DY>(
+    n: i32,
+    dx: &DX,
+    incx: i32,
+    dy: &DY,
+    incy: i32,
+) -> Complex32 where
+    DX: ToFortranArray<Complex32>,
+    DY: ToFortranArray<Complex32>,
+{
+    dot(true, n, dx, incx, dy, incy)
+}
+
+/// ZDOTC
+///
+/// Double precision complex variant of [`ddot`], conjugating `dx`.
+pub fn zdotc<DX, DY>(
+    n: i32,
+    dx: &DX,
+    incx: i32,
+    dy: &DY,
+    incy: i32,
+) -> Complex64 where
+    DX: ToFortranArray<Complex64>,
+    DY: ToFortranArray<Complex64>,
+{
+    dot(true, n, dx, incx, dy, incy)
+}
+
+/// Shared implementation of [`sdot`], [`ddot`], [`cdotu`], [`zdotu`], [`cdotc`] and
+/// [`zdotc`]; `conj` conjugates the elements of `dx`.
+pub fn dot<T, DX, DY>(
+    conj: bool,
+    n: i32,
+    dx: &DX,
+    incx: i32,
+    dy: &DY,
+    incy: i32,
+) -> T where
+    T: Scalar,
+    DX: ToFortranArray<T>,
+    DY: ToFortranArray<T>,
+{
+    let dx = dx.fa_view();
+    let dy = dy.fa_view();
+
+    let mut dtemp = T::zero();
+    if n <= 0 { return dtemp; }
+    if incx == 1 && incy == 1 {
+        // Clean-up loop
+        let m = n % 5;
+        if m != 0 {
+            for i in 1..=m { dtemp += conj_if(conj, dx[i]) * dy[i]; }
+            if n < 5 { return dtemp; }
+        }
+        for i in (m+1..=n).step_by(5) {
+            dtemp += conj_if(conj, dx[i]) * dy[i] + conj_if(conj, dx[i + 1]) * dy[i + 1]
+                + conj_if(conj, dx[i + 2]) * dy[i + 2] + conj_if(conj, dx[i + 3]) * dy[i + 3]
+                + conj_if(conj, dx[i + 4]) * dy[i + 4];
+        }
+    } else {
+        let mut ix = 1;
+        let mut iy = 1;
+        if incx < 0 { ix = (-n + 1) * incx + 1; }
+        if incy < 0 { iy = (-n + 1) * incy + 1; }
+        for _i in 1..=n {
+            dtemp += conj_if(conj, dx[ix]) * dy[iy];
+            ix += incx;
+            iy += incy;
+        }
+    }
+    dtemp
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0, vec![1.], 1, vec![1.], 1, 0.)]
+    #[case(3, vec![1., 2., 3.], 1, vec![4., 5., 6.], 1, 32.)]
+    #[case(7, vec![1., 1., 1., 1., 1., 1., 1.], 1, vec![1., 2., 3., 4., 5., 6., 7.], 1, 28.)]
+    #[case(2, vec![1., 2., 3., 4.], 2, vec![10., 20.], 1, 70.)]
+    #[case(3, vec![1., 2., 3.], -1, vec![10., 20., 30.], 1, 100.)]
+    #[case(3, vec![1., 2., 3.], 1, vec![10., 20., 30.], -1, 100.)]
+    #[case(3, vec![1., 2., 3.], -1, vec![10., 20., 30.], -1, 140.)]
+    fn test_ddot(
+        #[case] n: i32,
+        #[case] dx: Vec<f64>,
+        #[case] incx: i32,
+        #[case] dy: Vec<f64>,
+        #[case] incy: i32,
+        #[case] expected: f64,
+    ) {
+        assert_eq!(expected, ddot(n, &dx, incx, &dy, incy));
+    }
+
+    #[test]
+    fn test_zdot() {
+        let x = vec![Complex64::new(1., 1.), Complex64::new(0., 2.)];
+        let y = vec![Complex64::new(2., 0.), Complex64::new(1., 1.)];
+        assert_eq!(Complex64::new(0., 4.), zdotu(2, &x, 1, &y, 1));
+        assert_eq!(Complex64::new(4., -4.), zdotc(2, &x, 1, &y, 1));
+        assert_eq!(6f32, sdot(2, &vec![1f32, 2.], 1, &vec![2f32, 2.], 1));
+    }
+}
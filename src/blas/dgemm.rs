@@ -1,7 +1,11 @@
-use crate::array::convert::ToFortranArray;
-use crate::array::FortranArray;
-use crate::blas::lsame::lsame;
-use crate::xerbla::xerbla;
+use std::fmt::Debug;
+
+use crate::array::convert::{ToFortranArray, ToFortranArrayMut};
+use crate::blas::parallel::{gemm_parallel, Config};
+use crate::error::Info;
+use crate::flags::Trans;
+use crate::scalar::{Complex32, Complex64, Scalar};
+use crate::xerbla::illegal_argument;
 
 /// DGEMM
 ///
@@ -25,8 +29,8 @@ use crate::xerbla::xerbla;
 ///
 /// For arguments definitions, please refer to the original documentation.
 pub fn dgemm<A, B, C>(
-    transa: char,
-    transb: char,
+    transa: Trans,
+    transb: Trans,
     m: i32,
     n: i32,
     k: i32,
@@ -38,178 +42,200 @@ pub fn dgemm<A, B, C>(
     beta: f64,
     c: &mut C,
     ldc: i32,
-) where
+) -> Result<(), Info> where
     A: ToFortranArray,
     B: ToFortranArray,
-    C: ToFortranArray + From<FortranArray>,
+    C: ToFortranArrayMut,
+{
+    gemm(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+/// SGEMM
+///
+/// Single precision variant of [`dgemm`].
+pub fn sgemm<A, B, C>(
+    transa: Trans,
+    transb: Trans,
+    m: i32,
+    n: i32,
+    k: i32,
+    alpha: f32,
+    a: &A,
+    lda: i32,
+    b: &B,
+    ldb: i32,
+    beta: f32,
+    c: &mut C,
+    ldc: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<f32>,
+    B: ToFortranArray<f32>,
+    C: ToFortranArrayMut<f32>,
 {
-    let a_f = &mut a.to_fa_2d(lda);
-    let b_f = &mut b.to_fa_2d(ldb);
-    let c_f = &mut c.to_fa_2d(ldc);
+    gemm(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
 
-    let nota = lsame(transa, 'N');
-    let notb = lsame(transb, 'N');
+/// CGEMM
+///
+/// Single precision complex variant of [`dgemm`].
+pub fn cgemm<A, B, C>(
+    transa: Trans,
+    transb: Trans,
+    m: i32,
+    n: i32,
+    k: i32,
+    alpha: Complex32,
+    a: &A,
+    lda: i32,
+    b: &B,
+    ldb: i32,
+    beta: Complex32,
+    c: &mut C,
+    ldc: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<Complex32>,
+    B: ToFortranArray<Complex32>,
+    C: ToFortranArrayMut<Complex32>,
+{
+    gemm(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+/// ZGEMM
+///
+/// Double precision complex variant of [`dgemm`].
+pub fn zgemm<A, B, C>(
+    transa: Trans,
+    transb: Trans,
+    m: i32,
+    n: i32,
+    k: i32,
+    alpha: Complex64,
+    a: &A,
+    lda: i32,
+    b: &B,
+    ldb: i32,
+    beta: Complex64,
+    c: &mut C,
+    ldc: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<Complex64>,
+    B: ToFortranArray<Complex64>,
+    C: ToFortranArrayMut<Complex64>,
+{
+    gemm(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+/// Shared implementation of [`sgemm`], [`dgemm`], [`cgemm`] and [`zgemm`].
+///
+/// For complex types [`Trans::ConjTrans`] applies the conjugate transpose.
+pub fn gemm<T, A, B, C>(
+    transa: Trans,
+    transb: Trans,
+    m: i32,
+    n: i32,
+    k: i32,
+    alpha: T,
+    a: &A,
+    lda: i32,
+    b: &B,
+    ldb: i32,
+    beta: T,
+    c: &mut C,
+    ldc: i32,
+) -> Result<(), Info> where
+    T: Scalar,
+    A: ToFortranArray<T>,
+    B: ToFortranArray<T>,
+    C: ToFortranArrayMut<T>,
+{
+    let nota = transa == Trans::NoTrans;
+    let notb = transb == Trans::NoTrans;
     let nrowa = if nota { m } else { k };
     let nrowb = if notb { k } else { n };
 
-    let mut info = 0;
+    let illegal = |position, name, value: &dyn Debug| {
+        illegal_argument(format!("{}GEMM", T::PREFIX), position, name, value)
+    };
 
-    if !nota && !lsame(transa, 'C') && !lsame(transa, 'T') {
-        info = 1;
-    } else if !notb && !lsame(transb, 'C') && !lsame(transb, 'T') {
-        info = 2;
-    } else if m < 0 {
-        info = 3;
+    if m < 0 {
+        return illegal(3, "m", &m);
     } else if n < 0 {
-        info = 4;
+        return illegal(4, "n", &n);
     } else if k < 0 {
-        info = 5;
+        return illegal(5, "k", &k);
     } else if lda < 1.max(nrowa) {
-        info = 8;
+        return illegal(8, "lda", &lda);
     } else if ldb < 1.max(nrowb) {
-        info = 10;
+        return illegal(10, "ldb", &ldb);
     } else if ldc < 1.max(m) {
-        info = 13;
-    }
-
-    if info != 0 {
-        xerbla("DGEMM ", info);
-        return;
+        return illegal(13, "ldc", &ldc);
     }
 
     // Quick return if possible.
-    if m == 0 || n == 0 || ((alpha == 0. || k == 0) && beta == 1.) {
-        return;
+    if m == 0 || n == 0 || ((alpha.is_zero() || k == 0) && beta.is_one()) {
+        return Ok(());
     }
 
-    // And if  alpha.eq.zero.
-    if alpha == 0. {
-        if beta == 0. {
-            for j in 1..=n {
-                for i in 1..=m {
-                    c_f[(i, j)] = 0.;
-                }
-            }
-        } else {
-            for j in 1..=n {
-                for i in 1..=m {
-                    c_f[(i, j)] *= beta;
-                }
-            }
-        }
-        *c = C::from(c_f.clone());
-        return;
-    }
+    let a_f = &a.fa_view_2d(lda);
+    let b_f = &b.fa_view_2d(ldb);
+    let c_f = &mut c.fa_view_2d_mut(ldc);
 
-    // Start the operations.
-    if notb {
-        if nota {
-            // Form  C := alpha*A*B + beta*C.
-            for j in 1..=n {
-                if beta == 0. {
-                    for i in 1..=m {
-                        c_f[(i, j)] = 0.;
-                    }
-                } else if beta != 1. {
-                    for i in 1..=m {
-                        c_f[(i, j)] *= beta;
-                    }
-                }
-                for l in 1..=k {
-                    let temp = alpha * b_f[(l, j)];
-                    for i in 1..=m {
-                        c_f[(i, j)] += temp * a_f[(i, l)];
-                    }
-                }
-            }
-        } else {
-            // Form  C := alpha*A**T*B + beta*C
-            for j in 1..=n {
-                for i in 1..=m {
-                    let mut temp = 0.;
-                    for l in 1..=k {
-                        temp += a_f[(l, i)] * b_f[(l, j)];
-                    }
-                    if beta == 0. {
-                        c_f[(i, j)] = alpha * temp;
-                    } else {
-                        c_f[(i, j)] = alpha * temp + beta * c_f[(i, j)];
-                    }
-                }
-            }
-        }
-    } else if nota {
-        // Form  C := alpha*A*B**T + beta*C
-        for j in 1..=n {
-            if beta == 0. {
-                for i in 1..=m {
-                    c_f[(i, j)] = 0.;
-                }
-            } else if beta != 1. {
-                for i in 1..=m {
-                    c_f[(i, j)] *= beta;
-                }
-            }
-            for l in 1..=k {
-                let temp = alpha * b_f[(j, l)];
-                for i in 1..=m {
-                    c_f[(i, j)] += temp * a_f[(i, l)];
-                }
-            }
-        }
-    } else {
-        // Form  C := alpha*A**T*B**T + beta*C
+    // Form  C := beta*C.
+    if !beta.is_one() {
         for j in 1..=n {
             for i in 1..=m {
-                let mut temp = 0.;
-                for l in 1..=k {
-                    temp += a_f[(l, i)] * b_f[(j, l)];
-                }
-                if beta == 0. {
-                    c_f[(i, j)] = alpha * temp;
-                } else {
-                    c_f[(i, j)] = alpha * temp + beta * c_f[(i, j)];
-                }
+                c_f[(i, j)] = if beta.is_zero() { T::zero() } else { beta * c_f[(i, j)] };
             }
         }
     }
 
-    *c = C::from(c_f.clone());
+    // And if  alpha.eq.zero.
+    if alpha.is_zero() {
+        return Ok(());
+    }
+
+    // Form  C := alpha*op( A )*op( B ) + C  with the packed, cache-blocked kernel, split over
+    // column blocks of C when it is large enough.
+    gemm_parallel(Config::current(), transa, transb, m as usize, n as usize, k as usize, alpha, a_f, b_f, c_f);
+
+    Ok(())
 }
 
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::LapackError;
     use rstest::rstest;
 
     #[rstest]
     #[case(
-        'T', 'T', 0.5f64, 0.5f64,
+        Trans::Trans, Trans::Trans, 0.5f64, 0.5f64,
         vec![vec![3., 5.], vec![7., 11.]],
         vec![vec![13., 17.], vec![19., 23.]],
         vec![vec![67.5, 150.5], vec![83.5, 186.5]],
     )]
     #[case(
-        'T', 'N', 0.5f64, 0.5f64,
+        Trans::Trans, Trans::NoTrans, 0.5f64, 0.5f64,
         vec![vec![3., 5.], vec![7., 11.]],
         vec![vec![13., 17.], vec![19., 23.]],
         vec![vec![62.5, 139.5], vec![86.5, 193.5]],
     )]
     #[case(
-        'N', 'T', 0.5f64, 0.5f64,
+        Trans::NoTrans, Trans::Trans, 0.5f64, 0.5f64,
         vec![vec![109., 113.], vec![199., 401.]],
         vec![vec![503., 601.], vec![701., 809.]],
         vec![vec![97163.5, 168970.5], vec![113250.5, 196161.5]],
     )]
     #[case(
-        'N', 'N', 0.5f64, 0.5f64,
+        Trans::NoTrans, Trans::NoTrans, 0.5f64, 0.5f64,
         vec![vec![109., 113.], vec![199., 401.]],
         vec![vec![503., 601.], vec![701., 809.]],
         vec![vec![87213.5, 148920.5], vec![118700.5, 201811.5]],
     )]
     fn dgemm_test(
-        #[case] transa: char,
-        #[case] transb: char,
+        #[case] transa: Trans,
+        #[case] transb: Trans,
         #[case] alpha: f64,
         #[case] beta: f64,
         #[case] a: Vec<Vec<f64>>,
@@ -220,7 +246,67 @@ mod tests {
         let (lda, ldb, ldc) = (2, 2, 2);
 
         let c = &mut vec![vec![1.; 2]; 2];
-        dgemm(transa, transb, m, n, k, alpha, &a, lda, &b, ldb, beta, c, ldc);
+        dgemm(transa, transb, m, n, k, alpha, &a, lda, &b, ldb, beta, c, ldc).unwrap();
         assert_eq!(expected, c.clone());
     }
+
+    #[test]
+    fn sgemm_test() {
+        let a = vec![vec![3f32, 5.], vec![7., 11.]];
+        let b = vec![vec![13f32, 17.], vec![19., 23.]];
+        let c = &mut vec![vec![1f32; 2]; 2];
+        sgemm(Trans::Trans, Trans::Trans, 2, 2, 2, 0.5, &a, 2, &b, 2, 0.5, c, 2).unwrap();
+        assert_eq!(vec![vec![67.5, 150.5], vec![83.5, 186.5]], c.clone());
+    }
+
+    #[rstest]
+    #[case(Trans::Trans, vec![vec![Complex64::new(1., 1.), Complex64::new(0., 0.)], vec![Complex64::new(2., 0.), Complex64::new(0., 3.)]])]
+    #[case(Trans::ConjTrans, vec![vec![Complex64::new(1., -1.), Complex64::new(0., 0.)], vec![Complex64::new(2., 0.), Complex64::new(0., -3.)]])]
+    fn zgemm_test(
+        #[case] transa: Trans,
+        #[case] expected: Vec<Vec<Complex64>>,
+    ) {
+        let a = vec![vec![Complex64::new(1., 1.), Complex64::new(2., 0.)], vec![Complex64::new(0., 0.), Complex64::new(0., 3.)]];
+        let b = vec![vec![Complex64::new(1., 0.), Complex64::new(0., 0.)], vec![Complex64::new(0., 0.), Complex64::new(1., 0.)]];
+        let c = &mut vec![vec![Complex64::new(0., 0.); 2]; 2];
+        zgemm(transa, Trans::NoTrans, 2, 2, 2, Complex64::new(1., 0.), &a, 2, &b, 2, Complex64::new(0., 0.), c, 2).unwrap();
+        assert_eq!(expected, c.clone());
+    }
+
+    #[test]
+    fn dgemm_leaves_rest_of_c_test() {
+        // C has a third column past n, which must not be written.
+        let (a, b, c) = (vec![1., 0., 0., 1.], vec![1., 2., 3., 4., 5., 6.], &mut vec![0.; 6]);
+        dgemm(Trans::NoTrans, Trans::NoTrans, 2, 2, 2, 1., &a, 2, &b, 2, 0., c, 2).unwrap();
+        assert_eq!(vec![1., 2., 3., 4., 0., 0.], *c);
+    }
+
+    #[test]
+    fn dgemm_partial_last_column_test() {
+        // The reference BLAS only needs lda*(k-1)+m elements of A.
+        let (a, b, c) = (vec![1., 2., 0., 3., 4.], vec![1., 0., 0., 1.], &mut vec![0.; 4]);
+        dgemm(Trans::NoTrans, Trans::NoTrans, 2, 2, 2, 1., &a, 3, &b, 2, 0., c, 2).unwrap();
+        assert_eq!(vec![1., 2., 3., 4.], *c);
+    }
+
+    #[rstest]
+    #[case(Trans::NoTrans, Trans::NoTrans, -1, 2, 1, 2, 3, "m", "-1")]
+    #[case(Trans::NoTrans, Trans::NoTrans, 3, 2, 1, 2, 8, "lda", "1")]
+    #[case(Trans::NoTrans, Trans::Trans, 2, 2, 2, 1, 10, "ldb", "1")]
+    fn dgemm_illegal_argument_test(
+        #[case] transa: Trans,
+        #[case] transb: Trans,
+        #[case] m: i32,
+        #[case] n: i32,
+        #[case] lda: i32,
+        #[case] ldb: i32,
+        #[case] position: i32,
+        #[case] name: &'static str,
+        #[case] value: &str,
+    ) {
+        let (a, b, c) = (vec![1.; 4], vec![1.; 4], &mut vec![1.; 9]);
+        let expected = LapackError { routine: "DGEMM".to_string(), position, name, value: value.to_string() };
+        assert_eq!(Err(Info::IllegalArgument(expected)), dgemm(transa, transb, m, n, 2, 1., &a, lda, &b, ldb, 0., c, 3));
+        assert_eq!(vec![1.; 9], *c);
+    }
 }
@@ -0,0 +1,270 @@
+use std::fmt::Debug;
+
+use crate::array::convert::{ToFortranArray, ToFortranArrayMut};
+use crate::error::Info;
+use crate::flags::Trans;
+use crate::scalar::{conj_if, Complex32, Complex64, Scalar};
+use crate::xerbla::illegal_argument;
+
+/// DGEMV
+///
+/// # Documentation
+///
+/// [Original] Online html documentation available at
+/// [http://www.netlib.org/lapack/explore-html/](http://www.netlib.org/lapack/explore-html/)
+///
+/// Performs one of the matrix-vector operations:
+///
+/// `y := alpha*A*x + beta*y,   or   y := alpha*A**T*x + beta*y,`
+///
+/// where `alpha` and `beta` are scalars, `x` and `y` are vectors and `A` is an
+/// `m` by `n` matrix.
+///
+/// # Arguments
+///
+/// For arguments definitions, please refer to the original documentation.
+pub fn dgemv<A, X, Y>(
+    trans: Trans,
+    m: i32,
+    n: i32,
+    alpha: f64,
+    a: &A,
+    lda: i32,
+    x: &X,
+    incx: i32,
+    beta: f64,
+    y: &mut Y,
+    incy: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray,
+    X: ToFortranArray,
+    Y: ToFortranArrayMut,
+{
+    gemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+/// SGEMV
+///
+/// Single precision variant of [`dgemv`].
+pub fn sgemv<A, X, Y>(
+    trans: Trans,
+    m: i32,
+    n: i32,
+    alpha: f32,
+    a: &A,
+    lda: i32,
+    x: &X,
+    incx: i32,
+    beta: f32,
+    y: &mut Y,
+    incy: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<f32>,
+    X: ToFortranArray<f32>,
+    Y: ToFortranArrayMut<f32>,
+{
+    gemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+/// CGEMV
+///
+/// Single precision complex variant of [`dgemv`].
+pub fn cgemv<A, X, Y>(
+    trans: Trans,
+    m: i32,
+    n: i32,
+    alpha: Complex32,
+    a: &A,
+    lda: i32,
+    x: &X,
+    incx: i32,
+    beta: Complex32,
+    y: &mut Y,
+    incy: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<Complex32>,
+    X: ToFortranArray<Complex32>,
+    Y: ToFortranArrayMut<Complex32>,
+{
+    gemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+/// ZGEMV
+///
+/// Double precision complex variant of [`dgemv`].
+pub fn zgemv<A, X, Y>(
+    trans: Trans,
+    m: i32,
+    n: i32,
+    alpha: Complex64,
+    a: &A,
+    lda: i32,
+    x: &X,
+    incx: i32,
+    beta: Complex64,
+    y: &mut Y,
+    incy: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<Complex64>,
+    X: ToFortranArray<Complex64>,
+    Y: ToFortranArrayMut<Complex64>,
+{
+    gemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+/// Shared implementation of [`sgemv`], [`dgemv`], [`cgemv`] and [`zgemv`].
+///
+/// For complex types [`Trans::ConjTrans`] applies the conjugate transpose.
+pub fn gemv<T, A, X, Y>(
+    trans: Trans,
+    m: i32,
+    n: i32,
+    alpha: T,
+    a: &A,
+    lda: i32,
+    x: &X,
+    incx: i32,
+    beta: T,
+    y: &mut Y,
+    incy: i32,
+) -> Result<(), Info> where
+    T: Scalar,
+    A: ToFortranArray<T>,
+    X: ToFortranArray<T>,
+    Y: ToFortranArrayMut<T>,
+{
+    let illegal = |position, name, value: &dyn Debug| {
+        illegal_argument(format!("{}GEMV", T::PREFIX), position, name, value)
+    };
+
+    if m < 0 {
+        return illegal(2, "m", &m);
+    } else if n < 0 {
+        return illegal(3, "n", &n);
+    } else if lda < 1.max(m) {
+        return illegal(6, "lda", &lda);
+    } else if incx == 0 {
+        return illegal(8, "incx", &incx);
+    } else if incy == 0 {
+        return illegal(11, "incy", &incy);
+    }
+
+    // Quick return if possible.
+    if m == 0 || n == 0 || (alpha.is_zero() && beta.is_one()) {
+        return Ok(());
+    }
+
+    let a_f = &a.fa_view_2d(lda);
+    let x_f = &x.fa_view();
+    let y_f = &mut y.fa_view_mut();
+
+    let notrans = trans == Trans::NoTrans;
+    let conj = trans == Trans::ConjTrans;
+
+    // Set lenx and leny, the lengths of the vectors x and y, and set up the start points.
+    let (lenx, leny) = if notrans { (n, m) } else { (m, n) };
+    let kx = if incx > 0 { 1 } else { 1 - (lenx - 1) * incx };
+    let ky = if incy > 0 { 1 } else { 1 - (leny - 1) * incy };
+
+    // First form  y := beta*y.
+    if !beta.is_one() {
+        let mut iy = ky;
+        for _i in 1..=leny {
+            y_f[iy] = if beta.is_zero() { T::zero() } else { beta * y_f[iy] };
+            iy += incy;
+        }
+    }
+    if alpha.is_zero() { return Ok(()); }
+
+    if notrans {
+        // Form  y := alpha*A*x + y.
+        let mut jx = kx;
+        for j in 1..=n {
+            let temp = alpha * x_f[jx];
+            let mut iy = ky;
+            for i in 1..=m {
+                y_f[iy] += temp * a_f[(i, j)];
+                iy += incy;
+            }
+            jx += incx;
+        }
+    } else {
+        // Form  y := alpha*A**T*x + y  or  y := alpha*A**H*x + y.
+        let mut jy = ky;
+        for j in 1..=n {
+            let mut temp = T::zero();
+            let mut ix = kx;
+            for i in 1..=m {
+                temp += conj_if(conj, a_f[(i, j)]) * x_f[ix];
+                ix += incx;
+            }
+            y_f[jy] += alpha * temp;
+            jy += incy;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::LapackError;
+    use rstest::rstest;
+
+    // A = [1 2 3; 4 5 6]
+    #[rstest]
+    #[case(Trans::NoTrans, 1., vec![1., 1., 1.], 1, 0., vec![0., 0.], 1, vec![6., 15.])]
+    #[case(Trans::NoTrans, 2., vec![1., 0., 1.], 1, 1., vec![1., 1.], 1, vec![9., 21.])]
+    #[case(Trans::NoTrans, 1., vec![1., 2., 3.], -1, 0., vec![0., 0.], 1, vec![10., 28.])]
+    #[case(Trans::NoTrans, 1., vec![1., 1., 1.], 1, 0., vec![0., 9., 0.], -2, vec![15., 9., 6.])]
+    #[case(Trans::Trans, 1., vec![1., 1.], 1, 0., vec![0., 0., 0.], 1, vec![5., 7., 9.])]
+    #[case(Trans::ConjTrans, 1., vec![1., 0., 2.], 2, 3., vec![1., 1., 1.], 1, vec![12., 15., 18.])]
+    #[case(Trans::Trans, 0., vec![1., 1.], 1, 2., vec![1., 2., 3.], 1, vec![2., 4., 6.])]
+    fn test_dgemv(
+        #[case] trans: Trans,
+        #[case] alpha: f64,
+        #[case] x: Vec<f64>,
+        #[case] incx: i32,
+        #[case] beta: f64,
+        #[case] mut y: Vec<f64>,
+        #[case] incy: i32,
+        #[case] expected: Vec<f64>,
+    ) {
+        let a = vec![1., 4., 2., 5., 3., 6.];
+        dgemv(trans, 2, 3, alpha, &a, 2, &x, incx, beta, &mut y, incy).unwrap();
+        assert_eq!(expected, y);
+    }
+
+    #[test]
+    fn test_zgemv_conjugate_transpose() {
+        let i = Complex64::new(0., 1.);
+        let a = vec![Complex64::new(1., 1.), Complex64::new(0., 2.)];
+        let x = vec![Complex64::new(1., 0.), i];
+        let mut y = vec![Complex64::new(0., 0.)];
+        zgemv(Trans::ConjTrans, 2, 1, Complex64::new(1., 0.), &a, 2, &x, 1, Complex64::new(0., 0.), &mut y, 1).unwrap();
+        assert_eq!(vec![Complex64::new(3., -1.)], y);
+    }
+
+    #[rstest]
+    #[case(-1, 3, 2, 1, 1, 2, "m", "-1")]
+    #[case(2, -1, 2, 1, 1, 3, "n", "-1")]
+    #[case(2, 3, 1, 1, 1, 6, "lda", "1")]
+    #[case(2, 3, 2, 0, 1, 8, "incx", "0")]
+    #[case(2, 3, 2, 1, 0, 11, "incy", "0")]
+    fn test_dgemv_illegal_argument(
+        #[case] m: i32,
+        #[case] n: i32,
+        #[case] lda: i32,
+        #[case] incx: i32,
+        #[case] incy: i32,
+        #[case] position: i32,
+        #[case] name: &'static str,
+        #[case] value: &str,
+    ) {
+        let (a, x, y) = (vec![1.; 6], vec![1.; 3], &mut vec![1.; 3]);
+        let expected = LapackError { routine: "DGEMV".to_string(), position, name, value: value.to_string() };
+        assert_eq!(Err(Info::IllegalArgument(expected)), dgemv(Trans::NoTrans, m, n, 1., &a, lda, &x, incx, 0., y, incy));
+        assert_eq!(vec![1.; 3], *y);
+    }
+}
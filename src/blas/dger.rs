@@ -0,0 +1,280 @@
+use std::fmt::Debug;
+
+use crate::array::convert::{ToFortranArray, ToFortranArrayMut};
+use crate::error::Info;
+use crate::scalar::{conj_if, Complex32, Complex64, Scalar};
+use crate::xerbla::illegal_argument;
+
+/// DGER
+///
+/// # Documentation
+///
+/// [Original] Online html documentation available at
+/// [http://www.netlib.org/lapack/explore-html/](http://www.netlib.org/lapack/explore-html/)
+///
+/// Performs the rank 1 operation
+///
+/// `A := alpha*x*y**T + A,`
+///
+/// where `alpha` is a scalar, `x` is an `m` element vector, `y` is an `n` element
+/// vector and `A` is an `m` by `n` matrix.
+///
+/// # Arguments
+///
+/// For arguments definitions, please refer to the original documentation.
+pub fn dger<X, Y, A>(
+    m: i32,
+    n: i32,
+    alpha: f64,
+    x: &X,
+    incx: i32,
+    y: &Y,
+    incy: i32,
+    a: &mut A,
+    lda: i32,
+) -> Result<(), Info> where
+    X: ToFortranArray,
+    Y: ToFortranArray,
+    A: ToFortranArrayMut,
+{
+    ger(false, m, n, alpha, x, incx, y, incy, a, lda)
+}
+
+/// SGER
+///
+/// Single precision variant of [`dger`].
+pub fn sger<X, Y, A>(
+    m: i32,
+    n: i32,
+    alpha: f32,
+    x: &X,
+    incx: i32,
+    y: &Y,
+    incy: i32,
+    a: &mut A,
+    lda: i32,
+) -> Result<(), Info> where
+    X: ToFortranArray<f32>,
+    Y: ToFortranArray<f32>,
+    A: ToFortranArrayMut<f32>,
+{
+    ger(false, m, n, alpha, x, incx, y, incy, a, lda)
+}
+
+/// CGERU
+///
+/// Single precision complex variant of [`dger`].
+pub fn cgeru<X, Y, A>(
+    m: i32,
+    n: i32,
+    alpha: Complex32,
+    x: &X,
+    incx: i32,
+    y: &Y,
+    incy: i32,
+    a: &mut A,
+    lda: i32,
+) -> Result<(), Info> where
+    X: ToFortranArray<Complex32>,
+    Y: ToFortranArray<Complex32>,
+    A: ToFortranArrayMut<Complex32>,
+{
+    ger(false, m, n, alpha, x, incx, y, incy, a, lda)
+}
+
+/// ZGERU
+///
+/// Double precision complex variant of [`dger`].
+pub fn zgeru<X, Y, A>(
+    m: i32,
+    n: i32,
+    alpha: Complex64,
+    x: &X,
+    incx: i32,
+    y: &Y,
+    incy: i32,
+    a: &mut A,
+    lda: i32,
+) -> Result<(), Info> where
+    X: ToFortranArray<Complex64>,
+    Y: ToFortranArray<Complex64>,
+    A: ToFortranArrayMut<Complex64>,
+{
+    ger(false, m, n, alpha, x, incx, y, incy, a, lda)
+}
+
+/// CGERC
+///
+/// Single precision complex variant of [`dger`], computing `A := alpha*x*y**H + A`.
+pub fn cgerc<X, Y, A>(
+    m: i32,
+    n: i32,
+    alpha: Complex32,
+    x: &X,
+    incx: i32,
+    y: &Y,
+    incy: i32,
+    a: &mut A,
+    lda: i32,
+) -> Result<(), Info> where
+    X: ToFortranArray<Complex32>,
+    Y: ToFortranArray<Complex32>,
+    A: ToFortranArrayMut<Complex32>,
+{
+    ger(true, m, n, alpha, x, incx, y, incy, a, lda)
+}
+
+/// ZGERC
+///
+/// Double precision complex variant of [`dger`], computing `A := alpha*x*y**H + A`.
+pub fn zgerc<X, Y, A>(
+    m: i32,
+    n: i32,
+    alpha: Complex64,
+    x: &X,
+    incx: i32,
+    y: &Y,
+    incy: i32,
+    a: &mut A,
+    lda: i32,
+) -> Result<(), Info> where
+    X: ToFortranArray<Complex64>,
+    Y: ToFortranArray<Complex64>,
+    A: ToFortranArrayMut<Complex64>,
+{
+    ger(true, m, n, alpha, x, incx, y, incy, a, lda)
+}
+
+/// Shared implementation of [`sger`], [`dger`], [`cgeru`], [`zgeru`], [`cgerc`] and [`zgerc`].
+///
+/// When `conj` is set `y` is conjugated, giving the `GERC` variants.
+pub fn ger<T, X, Y, A>(
+    conj: bool,
+    m: i32,
+    n: i32,
+    alpha: T,
+    x: &X,
+    incx: i32,
+    y: &Y,
+    incy: i32,
+    a: &mut A,
+    lda: i32,
+) -> Result<(), Info> where
+    T: Scalar,
+    X: ToFortranArray<T>,
+    Y: ToFortranArray<T>,
+    A: ToFortranArrayMut<T>,
+{
+    let routine = match (T::IS_COMPLEX, conj) {
+        (false, _) => "GER",
+        (true, false) => "GERU",
+        (true, true) => "GERC",
+    };
+    let illegal = |position, name, value: &dyn Debug| {
+        illegal_argument(format!("{}{}", T::PREFIX, routine), position, name, value)
+    };
+
+    if m < 0 {
+        return illegal(1, "m", &m);
+    } else if n < 0 {
+        return illegal(2, "n", &n);
+    } else if incx == 0 {
+        return illegal(5, "incx", &incx);
+    } else if incy == 0 {
+        return illegal(7, "incy", &incy);
+    } else if lda < 1.max(m) {
+        return illegal(9, "lda", &lda);
+    }
+
+    // Quick return if possible.
+    if m == 0 || n == 0 || alpha.is_zero() {
+        return Ok(());
+    }
+
+    let x_f = &x.fa_view();
+    let y_f = &y.fa_view();
+    let a_f = &mut a.fa_view_2d_mut(lda);
+
+    // Start the operations.
+    let kx = if incx > 0 { 1 } else { 1 - (m - 1) * incx };
+    let mut jy = if incy > 0 { 1 } else { 1 - (n - 1) * incy };
+    for j in 1..=n {
+        if !y_f[jy].is_zero() {
+            let temp = alpha * conj_if(conj, y_f[jy]);
+            let mut ix = kx;
+            for i in 1..=m {
+                a_f[(i, j)] += x_f[ix] * temp;
+                ix += incx;
+            }
+        }
+        jy += incy;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::LapackError;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1., vec![1., 2.], 1, vec![1., 0., -1.], 1, vec![2., 3., 1., 1., 0., -1.])]
+    #[case(2., vec![1., 2.], -1, vec![1., 0., -1.], 1, vec![5., 3., 1., 1., -3., -1.])]
+    #[case(1., vec![1., 9., 2.], 2, vec![1., 0., -1.], -1, vec![0., -1., 1., 1., 2., 3.])]
+    #[case(0., vec![1., 2.], 1, vec![1., 0., -1.], 1, vec![1.; 6])]
+    fn test_dger(
+        #[case] alpha: f64,
+        #[case] x: Vec<f64>,
+        #[case] incx: i32,
+        #[case] y: Vec<f64>,
+        #[case] incy: i32,
+        #[case] expected: Vec<f64>,
+    ) {
+        let a = &mut vec![1.; 6];
+        dger(2, 3, alpha, &x, incx, &y, incy, a, 2).unwrap();
+        assert_eq!(expected, *a);
+    }
+
+    #[test]
+    fn test_zgeru_zgerc() {
+        let i = Complex64::new(0., 1.);
+        let x = vec![Complex64::new(1., 0.), i];
+        let y = vec![i];
+
+        let a = &mut vec![Complex64::new(0., 0.); 2];
+        zgeru(2, 1, Complex64::new(1., 0.), &x, 1, &y, 1, a, 2).unwrap();
+        assert_eq!(vec![i, Complex64::new(-1., 0.)], *a);
+
+        let a = &mut vec![Complex64::new(0., 0.); 2];
+        zgerc(2, 1, Complex64::new(1., 0.), &x, 1, &y, 1, a, 2).unwrap();
+        assert_eq!(vec![-i, Complex64::new(1., 0.)], *a);
+    }
+
+    #[rstest]
+    #[case(-1, 3, 1, 1, 2, 1, "m", "-1")]
+    #[case(2, -1, 1, 1, 2, 2, "n", "-1")]
+    #[case(2, 3, 0, 1, 2, 5, "incx", "0")]
+    #[case(2, 3, 1, 0, 2, 7, "incy", "0")]
+    #[case(2, 3, 1, 1, 1, 9, "lda", "1")]
+    fn test_dger_illegal_argument(
+        #[case] m: i32,
+        #[case] n: i32,
+        #[case] incx: i32,
+        #[case] incy: i32,
+        #[case] lda: i32,
+        #[case] position: i32,
+        #[case] name: &'static str,
+        #[case] value: &str,
+    ) {
+        let (x, y, a) = (vec![1.; 2], vec![1.; 3], &mut vec![1.; 6]);
+        let expected = LapackError { routine: "DGER".to_string(), position, name, value: value.to_string() };
+        assert_eq!(Err(Info::IllegalArgument(expected)), dger(m, n, 1., &x, incx, &y, incy, a, lda));
+        assert_eq!(vec![1.; 6], *a);
+
+        let expected = LapackError { routine: "ZGERC".to_string(), position, name, value: value.to_string() };
+        let (x, y, a) = (vec![Complex64::new(1., 0.); 2], vec![Complex64::new(1., 0.); 3], &mut vec![Complex64::new(1., 0.); 6]);
+        assert_eq!(Err(Info::IllegalArgument(expected)), zgerc(m, n, Complex64::new(1., 0.), &x, incx, &y, incy, a, lda));
+    }
+}
@@ -1,4 +1,6 @@
 use crate::array::convert::ToFortranArray;
+use crate::blas::simd;
+use crate::scalar::RealScalar;
 
 /// DNRM2
 ///
@@ -31,57 +33,95 @@ pub fn dnrm2<X>(
 ) -> f64 where
     X: ToFortranArray,
 {
-    let x = x.to_fa();
+    nrm2(n, x, incx)
+}
 
-    // Blue's scaling constants.
-    let tsml: f64 = (f64::RADIX as f64).powf((f64::MIN_EXP as f64 - 1.) * 0.5);
-    let tbig: f64 = (f64::RADIX as f64).powf((f64::MAX_EXP as f64 - f64::DIGITS as f64 + 1.) * 0.5);
-    let ssml: f64 = (f64::RADIX as f64).powf(-(f64::MIN_EXP as f64 - f64::DIGITS as f64) * 0.5);
-    let sbig: f64 = (f64::RADIX as f64).powf(-(f64::MAX_EXP as f64 + f64::DIGITS as f64 - 1.) * 0.5);
+/// SNRM2
+///
+/// Single precision variant of [`dnrm2`].
+pub fn snrm2<X>(
+    n: i32,
+    x: &X,
+    incx: i32,
+) -> f32 where
+    X: ToFortranArray<f32>,
+{
+    nrm2(n, x, incx)
+}
+
+// Blue's scaling constants, as in the reference: `digits` is the mantissa width in bits
+// (Fortran's DIGITS), and the exponents are rounded so every constant is an exact power of the
+// radix.
+fn blue_constants<T: RealScalar>() -> (T, T, T, T) {
+    let radix = T::RADIX as f64;
+    let (min_exp, max_exp, digits) = (T::MIN_EXP as f64, T::MAX_EXP as f64, T::MANTISSA_DIGITS as f64);
+    let tsml = T::from_f64(radix.powf(((min_exp - 1.) * 0.5).ceil()));
+    let tbig = T::from_f64(radix.powf(((max_exp - digits + 1.) * 0.5).floor()));
+    let ssml = T::from_f64(radix.powf(-((min_exp - digits) * 0.5).floor()));
+    let sbig = T::from_f64(radix.powf(-((max_exp + digits - 1.) * 0.5).ceil()));
+    (tsml, tbig, ssml, sbig)
+}
+
+/// Shared implementation of [`snrm2`] and [`dnrm2`].
+pub fn nrm2<T, X>(
+    n: i32,
+    x: &X,
+    incx: i32,
+) -> T where
+    T: RealScalar,
+    X: ToFortranArray<T>,
+{
+    let x = x.fa_view();
+    let (tsml, tbig, ssml, sbig) = blue_constants::<T>();
 
     // Quick return if possible
-    if n <= 0 { return 0.; }
-
-    let mut notbig = true;
-    let mut asml = 0.;
-    let mut amed = 0.;
-    let mut abig = 0.;
-    let mut ix = 1;
-    if incx < 0 { ix = 1 - (n-1) * incx; }
-
-    for _ in 1..=n {
-        let ax = x[ix].abs();
-        if ax > tbig {
-            abig = abig + (ax*sbig).powf(2.);
-            notbig = false;
-        } else if ax < tsml {
-            if notbig { asml = asml + (ax*ssml).powf(2.); }
-        } else {
-            amed = amed + ax.powf(2.);
+    if n <= 0 { return T::zero(); }
+
+    let mut asml = T::zero();
+    let mut amed = T::zero();
+    let mut abig = T::zero();
+    let contiguous = if incx == 1 { x.as_slice(n as usize) } else { None };
+    if let Some(sums) = contiguous.and_then(|x| simd::nrm2_sums(x, tsml, tbig, ssml, sbig)) {
+        (asml, amed, abig) = sums;
+    } else {
+        let mut notbig = true;
+        let mut ix = 1;
+        if incx < 0 { ix = 1 - (n-1) * incx; }
+
+        for _ in 1..=n {
+            let ax = x[ix].abs();
+            if ax > tbig {
+                abig += (ax*sbig).powi(2);
+                notbig = false;
+            } else if ax < tsml {
+                if notbig { asml += (ax*ssml).powi(2); }
+            } else {
+                amed += ax.powi(2);
+            }
+            ix += incx;
         }
-        ix += incx;
     }
 
-    let (scl, sumsq) = if abig > 0. {
+    let (scl, sumsq) = if abig > T::zero() {
         // Combine abig and amed if abig > 0.
-        if amed > 0. || amed > f64::MAX || amed != amed {
-            abig = abig + (amed * sbig) * sbig;
+        if amed > T::zero() || amed > T::max_value() || amed.is_nan() {
+            abig += (amed * sbig) * sbig;
         }
 
-        (1. / sbig, abig)
-    } else if asml > 0. {
+        (T::one() / sbig, abig)
+    } else if asml > T::zero() {
         // Combine amed and asml if asml > 0.
-        if amed > 0. || amed > f64::MAX || amed != amed {
+        if amed > T::zero() || amed > T::max_value() || amed.is_nan() {
             (amed, asml) = (amed.sqrt(), asml.sqrt() / ssml);
             let (ymin, ymax) = if asml > amed { (amed, asml) } else { (asml, amed) };
 
-            (1., ymax.powf(2.) * (1. + (ymin / ymax).powf(2.)))
+            (T::one(), ymax.powi(2) * (T::one() + (ymin / ymax).powi(2)))
         } else {
-            (1. / ssml, asml)
+            (T::one() / ssml, asml)
         }
     } else {
         // Otherwise all values are in the mid-range.
-        (1., amed)
+        (T::one(), amed)
     };
 
     scl * sumsq.sqrt()
@@ -102,7 +142,7 @@ mod tests {
     #[case(2, vec![-1., 0.], 1, 1.)]
     #[case(2, vec![2.6, 7.8, 3.2], 2, 4.123105625617661)]
     #[case(3, vec![-1., 0., 1.], 1, std::f64::consts::SQRT_2)]
-    #[case(3, vec![-1., 0., 1.], 2, std::f64::consts::SQRT_2)]
+    #[case(2, vec![-1., 0., 1.], 2, std::f64::consts::SQRT_2)]
     fn test_dnrm2(
         #[case] n: i32,
         #[case] vectors: Vec<f64>,
@@ -110,6 +150,19 @@ mod tests {
         #[case] expected: f64) {
         assert!((dnrm2(n, &vectors, incx) - expected).abs() < 1e-15);
     }
+
+    #[test]
+    fn test_blue_constants() {
+        assert_eq!((2f64.powi(-511), 2f64.powi(486), 2f64.powi(537), 2f64.powi(-538)), blue_constants::<f64>());
+        assert_eq!((2f32.powi(-63), 2f32.powi(52), 2f32.powi(75), 2f32.powi(-76)), blue_constants::<f32>());
+    }
+
+    #[test]
+    #[cfg_attr(feature = "debug-bounds", should_panic(expected = "out of bounds"))]
+    fn test_dnrm2_past_end() {
+        // n = 3 with incx = 2 needs five elements.
+        assert!((dnrm2(3, &vec![-1., 0., 1.], 2) - std::f64::consts::SQRT_2).abs() < 1e-15);
+    }
 }
 
 #[test] fn test() {
@@ -1,4 +1,6 @@
-use crate::array::{convert::ToFortranArray, FortranArray};
+use crate::array::convert::ToFortranArrayMut;
+use crate::blas::simd;
+use crate::scalar::RealScalar;
 
 /// DROT
 ///
@@ -27,15 +29,53 @@ pub fn drot<DX, DY>(
     c: f64,
     s: f64,
 ) where
-    DX: ToFortranArray + From<FortranArray>,
-    DY: ToFortranArray + From<FortranArray>,
+    DX: ToFortranArrayMut,
+    DY: ToFortranArrayMut,
 {
-    let dx_f = &mut dx.to_fa();
-    let dy_f = &mut dy.to_fa();
+    rot(n, dx, incx, dy, incy, c, s)
+}
+
+/// SROT
+///
+/// Single precision variant of [`drot`].
+pub fn srot<DX, DY>(
+    n: i32,
+    dx: &mut DX,
+    incx: i32,
+    dy: &mut DY,
+    incy: i32,
+    c: f32,
+    s: f32,
+) where
+    DX: ToFortranArrayMut<f32>,
+    DY: ToFortranArrayMut<f32>,
+{
+    rot(n, dx, incx, dy, incy, c, s)
+}
+
+/// Shared implementation of [`srot`] and [`drot`].
+pub fn rot<T, DX, DY>(
+    n: i32,
+    dx: &mut DX,
+    incx: i32,
+    dy: &mut DY,
+    incy: i32,
+    c: T,
+    s: T,
+) where
+    T: RealScalar,
+    DX: ToFortranArrayMut<T>,
+    DY: ToFortranArrayMut<T>,
+{
+    let dx_f = &mut dx.fa_view_mut();
+    let dy_f = &mut dy.fa_view_mut();
 
     if n <= 0 { return; }
     if incx == 1 && incy == 1 {
         // code for both increments equal to 1
+        if let (Some(dx), Some(dy)) = (dx_f.as_mut_slice(n as usize), dy_f.as_mut_slice(n as usize)) {
+            if simd::rot(dx, dy, c, s) { return; }
+        }
         for i in 1..=n {
             let dtemp = c * dx_f[i] + s * dy_f[i];
             dy_f[i] = c * dy_f[i] - s * dx_f[i];
@@ -55,9 +95,6 @@ pub fn drot<DX, DY>(
             iy += incy;
         }
     }
-
-    *dx = DX::from(dx_f.clone());
-    *dy = DY::from(dy_f.clone());
 }
 
 #[cfg(test)]
@@ -0,0 +1,108 @@
+use crate::scalar::RealScalar;
+
+/// DROTG
+///
+/// # Documentation
+///
+/// [Original] Online html documentation available at
+/// [http://www.netlib.org/lapack/explore-html/](http://www.netlib.org/lapack/explore-html/)
+///
+/// Constructs a plane rotation that zeros `b`:
+///
+/// ```text
+/// [  c  s ] [ a ]   [ r ]
+/// [ -s  c ] [ b ] = [ 0 ]
+/// ```
+///
+/// On return `a` holds `r` and `b` holds `z`, from which `c` and `s` can be recovered.
+///
+/// # Arguments
+///
+/// * `a` - `f64` scalar, overwritten by `r`.
+/// * `b` - `f64` scalar, overwritten by `z`.
+/// * `c` - cosine of the rotation.
+/// * `s` - sine of the rotation.
+pub fn drotg(
+    a: &mut f64,
+    b: &mut f64,
+    c: &mut f64,
+    s: &mut f64,
+) {
+    rotg(a, b, c, s)
+}
+
+/// SROTG
+///
+/// Single precision variant of [`drotg`].
+pub fn srotg(
+    a: &mut f32,
+    b: &mut f32,
+    c: &mut f32,
+    s: &mut f32,
+) {
+    rotg(a, b, c, s)
+}
+
+/// Shared implementation of [`srotg`] and [`drotg`].
+pub fn rotg<T: RealScalar>(
+    a: &mut T,
+    b: &mut T,
+    c: &mut T,
+    s: &mut T,
+) {
+    let safmin = T::min_positive_value();
+    let safmax = T::one() / safmin;
+
+    let anorm = a.abs();
+    let bnorm = b.abs();
+    if bnorm == T::zero() {
+        *c = T::one();
+        *s = T::zero();
+        *b = T::zero();
+    } else if anorm == T::zero() {
+        *c = T::zero();
+        *s = T::one();
+        *a = *b;
+        *b = T::one();
+    } else {
+        let scl = safmax.min(safmin.max(anorm.max(bnorm)));
+        let roe = if anorm > bnorm { *a } else { *b };
+        let r = (scl * ((*a / scl).powi(2) + (*b / scl).powi(2)).sqrt()).copysign(roe);
+        *c = *a / r;
+        *s = *b / r;
+        let z = if anorm > bnorm {
+            *s
+        } else if *c != T::zero() {
+            T::one() / *c
+        } else {
+            T::one()
+        };
+        *a = r;
+        *b = z;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(3., 4., 5., 1.6666666666666667, 0.6, 0.8)]
+    #[case(-4., 3., -5., -0.6, 0.8, -0.6)]
+    #[case(2., 0., 2., 0., 1., 0.)]
+    #[case(0., -2., -2., 1., 0., 1.)]
+    #[case(1e300, 1e300, 1.4142135623730952e300, std::f64::consts::SQRT_2, 0.7071067811865475, 0.7071067811865475)]
+    fn test_drotg(
+        #[case] a: f64,
+        #[case] b: f64,
+        #[case] expected_r: f64,
+        #[case] expected_z: f64,
+        #[case] expected_c: f64,
+        #[case] expected_s: f64,
+    ) {
+        let (mut a, mut b, mut c, mut s) = (a, b, 0., 0.);
+        drotg(&mut a, &mut b, &mut c, &mut s);
+        assert_eq!((expected_r, expected_z, expected_c, expected_s), (a, b, c, s));
+    }
+}
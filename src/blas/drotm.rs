@@ -0,0 +1,136 @@
+use crate::array::convert::{ToFortranArray, ToFortranArrayMut};
+use crate::scalar::RealScalar;
+
+/// DROTM
+///
+/// # Documentation
+///
+/// [Original] Online html documentation available at
+/// [http://www.netlib.org/lapack/explore-html/](http://www.netlib.org/lapack/explore-html/)
+///
+/// Applies the modified Givens transformation `H` to the 2 by `n` matrix with rows `dx**T`
+/// and `dy**T`.
+///
+/// With `dflag = dparam(1)`, `H` is
+///
+/// ```text
+/// dflag = -1        dflag = 0         dflag = 1         dflag = -2
+/// [ h11  h12 ]      [  1   h12 ]      [ h11   1  ]      [ 1  0 ]
+/// [ h21  h22 ]      [ h21   1  ]      [ -1   h22 ]      [ 0  1 ]
+/// ```
+///
+/// and `dparam(2..=5)` holds `h11, h21, h12, h22`.
+///
+/// # Arguments
+///
+/// * `n` - number of elements in input vector(s)
+/// * `dx` - array
+/// * `incx` - storage spacing between elements of dx
+/// * `dy` - array
+/// * `incy` - storage spacing between elements of dy
+/// * `dparam` - array of dimension 5
+pub fn drotm<DX, DY, DP>(
+    n: i32,
+    dx: &mut DX,
+    incx: i32,
+    dy: &mut DY,
+    incy: i32,
+    dparam: &DP,
+) where
+    DX: ToFortranArrayMut,
+    DY: ToFortranArrayMut,
+    DP: ToFortranArray,
+{
+    rotm(n, dx, incx, dy, incy, dparam)
+}
+
+/// SROTM
+///
+/// Single precision variant of [`drotm`].
+pub fn srotm<DX, DY, DP>(
+    n: i32,
+    dx: &mut DX,
+    incx: i32,
+    dy: &mut DY,
+    incy: i32,
+    dparam: &DP,
+) where
+    DX: ToFortranArrayMut<f32>,
+    DY: ToFortranArrayMut<f32>,
+    DP: ToFortranArray<f32>,
+{
+    rotm(n, dx, incx, dy, incy, dparam)
+}
+
+/// Shared implementation of [`srotm`] and [`drotm`].
+pub fn rotm<T, DX, DY, DP>(
+    n: i32,
+    dx: &mut DX,
+    incx: i32,
+    dy: &mut DY,
+    incy: i32,
+    dparam: &DP,
+) where
+    T: RealScalar,
+    DX: ToFortranArrayMut<T>,
+    DY: ToFortranArrayMut<T>,
+    DP: ToFortranArray<T>,
+{
+    let dx_f = &mut dx.fa_view_mut();
+    let dy_f = &mut dy.fa_view_mut();
+    let dparam = dparam.fa_view();
+
+    let two = T::from_f64(2.);
+    let dflag = dparam[1];
+    if n <= 0 || dflag + two == T::zero() { return; }
+
+    // The implied ones of H are exact, so a single loop serves every flag.
+    let (dh11, dh21, dh12, dh22) = if dflag < T::zero() {
+        (dparam[2], dparam[3], dparam[4], dparam[5])
+    } else if dflag == T::zero() {
+        (T::one(), dparam[3], dparam[4], T::one())
+    } else {
+        (dparam[2], -T::one(), T::one(), dparam[5])
+    };
+
+    let mut kx = 1;
+    let mut ky = 1;
+    if incx < 0 { kx = 1 + (1 - n) * incx; }
+    if incy < 0 { ky = 1 + (1 - n) * incy; }
+    for _i in 1..=n {
+        let w = dx_f[kx];
+        let z = dy_f[ky];
+        dx_f[kx] = w * dh11 + z * dh12;
+        dy_f[ky] = w * dh21 + z * dh22;
+        kx += incx;
+        ky += incy;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(2, vec![1., 2.], 1, vec![3., 4.], 1, vec![-1., 2., 3., 4., 5.], vec![14., 20.], vec![18., 26.])]
+    #[case(2, vec![1., 2.], 1, vec![3., 4.], 1, vec![0., 0., 2., 3., 0.], vec![10., 14.], vec![5., 8.])]
+    #[case(2, vec![1., 2.], 1, vec![3., 4.], 1, vec![1., 2., 0., 0., 3.], vec![5., 8.], vec![8., 10.])]
+    #[case(2, vec![1., 2.], 1, vec![3., 4.], 1, vec![-2., 2., 3., 4., 5.], vec![1., 2.], vec![3., 4.])]
+    #[case(2, vec![1., 2.], -1, vec![3., 4.], 1, vec![-1., 1., 0., 1., 1.], vec![5., 5.], vec![3., 4.])]
+    #[case(2, vec![1., 0., 2.], 2, vec![3., 4.], -1, vec![-1., 0., 1., 1., 0.], vec![4., 0., 3.], vec![2., 1.])]
+    fn test_drotm(
+        #[case] n: i32,
+        #[case] mut dx: Vec<f64>,
+        #[case] incx: i32,
+        #[case] mut dy: Vec<f64>,
+        #[case] incy: i32,
+        #[case] dparam: Vec<f64>,
+        #[case] expected_dx: Vec<f64>,
+        #[case] expected_dy: Vec<f64>,
+    ) {
+        drotm(n, &mut dx, incx, &mut dy, incy, &dparam);
+        assert_eq!(expected_dx, dx);
+        assert_eq!(expected_dy, dy);
+    }
+}
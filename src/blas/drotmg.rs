@@ -0,0 +1,217 @@
+use crate::array::convert::ToFortranArrayMut;
+use crate::scalar::RealScalar;
+
+/// DROTMG
+///
+/// # Documentation
+///
+/// [Original] Online html documentation available at
+/// [http://www.netlib.org/lapack/explore-html/](http://www.netlib.org/lapack/explore-html/)
+///
+/// Constructs the modified Givens transformation `H` which zeros the second component of
+/// the 2-vector `(sqrt(dd1)*dx1, sqrt(dd2)*dy1)**T`. `H` is stored in `dparam` as described
+/// in [`drotm`](crate::blas::drotm::drotm).
+///
+/// # Arguments
+///
+/// * `dd1` - `f64` scalar, overwritten by the updated scale factor.
+/// * `dd2` - `f64` scalar, overwritten by the updated scale factor.
+/// * `dx1` - `f64` scalar, overwritten by the rotated first component.
+/// * `dy1` - `f64` scalar.
+/// * `dparam` - array of dimension 5, receives `dflag, h11, h21, h12, h22`.
+pub fn drotmg<DP>(
+    dd1: &mut f64,
+    dd2: &mut f64,
+    dx1: &mut f64,
+    dy1: f64,
+    dparam: &mut DP,
+) where
+    DP: ToFortranArrayMut,
+{
+    rotmg(dd1, dd2, dx1, dy1, dparam)
+}
+
+/// SROTMG
+///
+/// Single precision variant of [`drotmg`].
+pub fn srotmg<DP>(
+    dd1: &mut f32,
+    dd2: &mut f32,
+    dx1: &mut f32,
+    dy1: f32,
+    dparam: &mut DP,
+) where
+    DP: ToFortranArrayMut<f32>,
+{
+    rotmg(dd1, dd2, dx1, dy1, dparam)
+}
+
+/// Shared implementation of [`srotmg`] and [`drotmg`].
+pub fn rotmg<T, DP>(
+    dd1: &mut T,
+    dd2: &mut T,
+    dx1: &mut T,
+    dy1: T,
+    dparam: &mut DP,
+) where
+    T: RealScalar,
+    DP: ToFortranArrayMut<T>,
+{
+    let dparam_f = &mut dparam.fa_view_mut();
+
+    let (zero, one, two) = (T::zero(), T::one(), T::from_f64(2.));
+    let gam = T::from_f64(4096.);
+    let gamsq = T::from_f64(16777216.);
+    let rgamsq = T::from_f64(5.9604645e-8);
+
+    let (mut dh11, mut dh12, mut dh21, mut dh22) = (zero, zero, zero, zero);
+    let mut dflag;
+
+    if *dd1 < zero {
+        // Zero H, D and DX1
+        dflag = -one;
+        (*dd1, *dd2, *dx1) = (zero, zero, zero);
+    } else {
+        let dp2 = *dd2 * dy1;
+        if dp2 == zero {
+            dparam_f[1] = -two;
+            return;
+        }
+
+        // Regular case
+        let dp1 = *dd1 * *dx1;
+        let dq2 = dp2 * dy1;
+        let dq1 = dp1 * *dx1;
+        if dq1.abs() > dq2.abs() {
+            dh21 = -dy1 / *dx1;
+            dh12 = dp2 / dp1;
+            let du = one - dh12 * dh21;
+            if du > zero {
+                dflag = zero;
+                *dd1 /= du;
+                *dd2 /= du;
+                *dx1 *= du;
+            } else {
+                // Only reachable through rounding errors, see DOI: 10.1145/355841.355847.
+                dflag = -one;
+                (dh12, dh21) = (zero, zero);
+                (*dd1, *dd2, *dx1) = (zero, zero, zero);
+            }
+        } else if dq2 < zero {
+            // Zero H, D and DX1
+            dflag = -one;
+            (*dd1, *dd2, *dx1) = (zero, zero, zero);
+        } else {
+            dflag = one;
+            dh11 = dp1 / dp2;
+            dh22 = *dx1 / dy1;
+            let du = one + dh11 * dh22;
+            let dtemp = *dd2 / du;
+            *dd2 = *dd1 / du;
+            *dd1 = dtemp;
+            *dx1 = dy1 * du;
+        }
+
+        // Scale check
+        if *dd1 != zero {
+            while *dd1 <= rgamsq || *dd1 >= gamsq {
+                if dflag == zero {
+                    (dh11, dh22) = (one, one);
+                } else {
+                    (dh21, dh12) = (-one, one);
+                }
+                dflag = -one;
+                if *dd1 <= rgamsq {
+                    *dd1 *= gam.powi(2);
+                    *dx1 /= gam;
+                    dh11 /= gam;
+                    dh12 /= gam;
+                } else {
+                    *dd1 /= gam.powi(2);
+                    *dx1 *= gam;
+                    dh11 *= gam;
+                    dh12 *= gam;
+                }
+            }
+        }
+        if *dd2 != zero {
+            while dd2.abs() <= rgamsq || dd2.abs() >= gamsq {
+                if dflag == zero {
+                    (dh11, dh22) = (one, one);
+                } else {
+                    (dh21, dh12) = (-one, one);
+                }
+                dflag = -one;
+                if dd2.abs() <= rgamsq {
+                    *dd2 *= gam.powi(2);
+                    dh21 /= gam;
+                    dh22 /= gam;
+                } else {
+                    *dd2 /= gam.powi(2);
+                    dh21 *= gam;
+                    dh22 *= gam;
+                }
+            }
+        }
+    }
+
+    if dflag < zero {
+        dparam_f[2] = dh11;
+        dparam_f[3] = dh21;
+        dparam_f[4] = dh12;
+        dparam_f[5] = dh22;
+    } else if dflag == zero {
+        dparam_f[3] = dh21;
+        dparam_f[4] = dh12;
+    } else {
+        dparam_f[2] = dh11;
+        dparam_f[5] = dh22;
+    }
+    dparam_f[1] = dflag;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    use crate::blas::drotm::drotm;
+
+    #[rstest]
+    #[case(1., 1., 3., 1.)]
+    #[case(2., 3., 1., 4.)]
+    #[case(1e-10, 1., 1., 1e-3)]
+    fn test_drotmg(
+        #[case] dd1: f64,
+        #[case] dd2: f64,
+        #[case] dx1: f64,
+        #[case] dy1: f64,
+    ) {
+        let (mut d1, mut d2, mut x1) = (dd1, dd2, dx1);
+        let mut dparam = vec![0.; 5];
+        drotmg(&mut d1, &mut d2, &mut x1, dy1, &mut dparam);
+
+        // Applying H to (dx1, dy1) zeros the second component and gives the new dx1, and the
+        // weighted norm sqrt(d1*x**2 + d2*y**2) is preserved.
+        let (mut x, mut y) = (vec![dx1], vec![dy1]);
+        drotm(1, &mut x, 1, &mut y, 1, &dparam);
+        assert!((x[0] - x1).abs() <= 1e-12 * x1.abs());
+        assert!(y[0].abs() <= 1e-12 * x1.abs());
+        assert!((d1 * x1 * x1 - (dd1 * dx1 * dx1 + dd2 * dy1 * dy1)).abs() <= 1e-12 * d1 * x1 * x1);
+    }
+
+    #[test]
+    fn test_drotmg_special_cases() {
+        let (mut d1, mut d2, mut x1) = (-1., 1., 1.);
+        let mut dparam = vec![0.; 5];
+        drotmg(&mut d1, &mut d2, &mut x1, 1., &mut dparam);
+        assert_eq!((0., 0., 0.), (d1, d2, x1));
+        assert_eq!(vec![-1., 0., 0., 0., 0.], dparam);
+
+        // dd2*dy1 = 0 needs no rotation, flagged as the identity.
+        let (mut d1, mut d2, mut x1) = (4., 0., 1.);
+        drotmg(&mut d1, &mut d2, &mut x1, 2., &mut dparam);
+        assert_eq!((4., 0., 1.), (d1, d2, x1));
+        assert_eq!(-2., dparam[0]);
+    }
+}
@@ -1,4 +1,6 @@
-use crate::array::{convert::ToFortranArray, FortranArray};
+use crate::array::convert::ToFortranArrayMut;
+use crate::blas::simd;
+use crate::scalar::{Complex32, Complex64, Scalar};
 
 /// DSCAL
 ///
@@ -23,21 +25,76 @@ pub fn dscal<DX>(
     dx: &mut DX,
     incx: i32,
 ) where
-    DX: ToFortranArray + From<FortranArray>,
+    DX: ToFortranArrayMut,
 {
-    let dx_f = &mut dx.to_fa();
+    scal(n, da, dx, incx)
+}
+
+/// SSCAL
+///
+/// Single precision variant of [`dscal`].
+pub fn sscal<DX>(
+    n: i32,
+    da: f32,
+    dx: &mut DX,
+    incx: i32,
+) where
+    DX: ToFortranArrayMut<f32>,
+{
+    scal(n, da, dx, incx)
+}
 
-    if n <= 0 || incx <= 0 || da == 1.0 { return; }
+/// CSCAL
+///
+/// Single precision complex variant of [`dscal`].
+pub fn cscal<DX>(
+    n: i32,
+    da: Complex32,
+    dx: &mut DX,
+    incx: i32,
+) where
+    DX: ToFortranArrayMut<Complex32>,
+{
+    scal(n, da, dx, incx)
+}
+
+/// ZSCAL
+///
+/// Double precision complex variant of [`dscal`].
+pub fn zscal<DX>(
+    n: i32,
+    da: Complex64,
+    dx: &mut DX,
+    incx: i32,
+) where
+    DX: ToFortranArrayMut<Complex64>,
+{
+    scal(n, da, dx, incx)
+}
+
+/// Shared implementation of [`sscal`], [`dscal`], [`cscal`] and [`zscal`].
+pub fn scal<T, DX>(
+    n: i32,
+    da: T,
+    dx: &mut DX,
+    incx: i32,
+) where
+    T: Scalar,
+    DX: ToFortranArrayMut<T>,
+{
+    let dx_f = &mut dx.fa_view_mut();
+
+    if n <= 0 || incx <= 0 || da.is_one() { return; }
     if incx == 1 {
         // Code for increment equal to 1
+        if let Some(dx) = dx_f.as_mut_slice(n as usize) {
+            if simd::scal(da, dx) { return; }
+        }
         // Clean-up loop
         let m = n % 5;
         if m != 0 {
             for i in 1..=m { dx_f[i] *= da; }
-            if n < 5 {
-                *dx = DX::from(dx_f.clone());
-                return;
-            }
+            if n < 5 { return; }
         }
         for i in (m+1..=n).step_by(5) {
             dx_f[i] *= da;
@@ -55,8 +112,6 @@ pub fn dscal<DX>(
             i += incx;
         }
     }
-
-    *dx = DX::from(dx_f.clone());
 }
 
 #[cfg(test)]
@@ -69,9 +124,9 @@ mod tests {
     #[case(1, 2.0, vec![1.0], 1, vec![2.0])]
     #[case(2, -1.0, vec![1.0, 2.0], 1, vec![-1.0, -2.0])]
     #[case(5, 0.0, vec![1.0, 2.0, 3.0, 4.0, 5.0], 1, vec![0.0, 0.0, 0.0, 0.0, 0.0])]
-    #[case(3, 5.0, vec![1.0, 2.0, 3.0], 2, vec![5.0, 2.0, 15.0])]
-    #[case(4, -2.0, vec![1.0, 2.0, 3.0, 4.0], 2, vec![-2.0, 2.0, -6.0, 4.0])]
-    #[case(4, 3.0, vec![1.0, 2.0, 3.0, 4.0], 3, vec![3.0, 2.0, 3.0, 12.0])]
+    #[case(2, 5.0, vec![1.0, 2.0, 3.0], 2, vec![5.0, 2.0, 15.0])]
+    #[case(2, -2.0, vec![1.0, 2.0, 3.0, 4.0], 2, vec![-2.0, 2.0, -6.0, 4.0])]
+    #[case(2, 3.0, vec![1.0, 2.0, 3.0, 4.0], 3, vec![3.0, 2.0, 3.0, 12.0])]
     #[case(0, 1.0, vec![1.0, 2.0, 3.0, 4.0, 5.0], 2, vec![1.0, 2.0, 3.0, 4.0, 5.0])]
     #[case(5, 1.0, vec![1.0, 2.0, 3.0, 4.0, 5.0], 0, vec![1.0, 2.0, 3.0, 4.0, 5.0])]
     #[case(5, 0.0, vec![1.0, 2.0, 3.0, 4.0, 5.0], -1, vec![1.0, 2.0, 3.0, 4.0, 5.0])]
@@ -86,4 +141,13 @@ mod tests {
         dscal(n, da, &mut dx, incx);
         assert_eq!(dx, expected_dx);
     }
+
+    #[test]
+    #[cfg_attr(feature = "debug-bounds", should_panic(expected = "out of bounds"))]
+    fn test_dscal_past_end() {
+        // n = 3 with incx = 2 needs five elements.
+        let mut dx = vec![1.0, 2.0, 3.0];
+        dscal(3, 5.0, &mut dx, 2);
+        assert_eq!(vec![5.0, 2.0, 15.0], dx);
+    }
 }
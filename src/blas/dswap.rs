@@ -0,0 +1,140 @@
+use std::mem;
+
+use crate::array::convert::ToFortranArrayMut;
+use crate::scalar::{Complex32, Complex64, Scalar};
+
+/// DSWAP
+///
+/// # Documentation
+///
+/// [Original] Online html documentation available at
+/// [http://www.netlib.org/lapack/explore-html/](http://www.netlib.org/lapack/explore-html/)
+///
+/// Interchanges two vectors.
+///
+/// # Arguments
+///
+/// * `n` - number of elements in input vector(s)
+/// * `dx` - array
+/// * `incx` - storage spacing between elements of dx
+/// * `dy` - array
+/// * `incy` - storage spacing between elements of dy
+pub fn dswap<DX, DY>(
+    n: i32,
+    dx: &mut DX,
+    incx: i32,
+    dy: &mut DY,
+    incy: i32,
+) where
+    DX: ToFortranArrayMut,
+    DY: ToFortranArrayMut,
+{
+    swap(n, dx, incx, dy, incy)
+}
+
+/// SSWAP
+///
+/// Single precision variant of [`dswap`].
+pub fn sswap<DX, DY>(
+    n: i32,
+    dx: &mut DX,
+    incx: i32,
+    dy: &mut DY,
+    incy: i32,
+) where
+    DX: ToFortranArrayMut<f32>,
+    DY: ToFortranArrayMut<f32>,
+{
+    swap(n, dx, incx, dy, incy)
+}
+
+/// CSWAP
+///
+/// Single precision complex variant of [`dswap`].
+pub fn cswap<DX, DY>(
+    n: i32,
+    dx: &mut DX,
+    incx: i32,
+    dy: &mut DY,
+    incy: i32,
+) where
+    DX: ToFortranArrayMut<Complex32>,
+    DY: ToFortranArrayMut<Complex32>,
+{
+    swap(n, dx, incx, dy, incy)
+}
+
+/// ZSWAP
+///
+/// Double precision complex variant of [`dswap`].
+pub fn zswap<DX, DY>(
+    n: i32,
+    dx: &mut DX,
+    incx: i32,
+    dy: &mut DY,
+    incy: i32,
+) where
+    DX: ToFortranArrayMut<Complex64>,
+    DY: ToFortranArrayMut<Complex64>,
+{
+    swap(n, dx, incx, dy, incy)
+}
+
+/// Shared implementation of [`sswap`], [`dswap`], [`cswap`] and [`zswap`].
+pub fn swap<T, DX, DY>(
+    n: i32,
+    dx: &mut DX,
+    incx: i32,
+    dy: &mut DY,
+    incy: i32,
+) where
+    T: Scalar,
+    DX: ToFortranArrayMut<T>,
+    DY: ToFortranArrayMut<T>,
+{
+    let dx_f = &mut dx.fa_view_mut();
+    let dy_f = &mut dy.fa_view_mut();
+
+    if n <= 0 { return; }
+    if incx == 1 && incy == 1 {
+        for i in 1..=n {
+            mem::swap(&mut dx_f[i], &mut dy_f[i]);
+        }
+    } else {
+        let mut ix = 1;
+        let mut iy = 1;
+        if incx < 0 { ix = (-n + 1) * incx + 1; }
+        if incy < 0 { iy = (-n + 1) * incy + 1; }
+        for _i in 1..=n {
+            mem::swap(&mut dx_f[ix], &mut dy_f[iy]);
+            ix += incx;
+            iy += incy;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0, vec![1.], 1, vec![2.], 1, vec![1.], vec![2.])]
+    #[case(4, vec![1., 2., 3., 4.], 1, vec![5., 6., 7., 8.], 1, vec![5., 6., 7., 8.], vec![1., 2., 3., 4.])]
+    #[case(2, vec![1., 2., 3., 4.], 2, vec![10., 20.], 1, vec![10., 2., 20., 4.], vec![1., 3.])]
+    #[case(3, vec![1., 2., 3.], -1, vec![10., 20., 30.], 1, vec![30., 20., 10.], vec![3., 2., 1.])]
+    #[case(2, vec![1., 2.], 1, vec![10., 20., 30.], -2, vec![30., 10.], vec![2., 20., 1.])]
+    fn test_dswap(
+        #[case] n: i32,
+        #[case] mut dx: Vec<f64>,
+        #[case] incx: i32,
+        #[case] mut dy: Vec<f64>,
+        #[case] incy: i32,
+        #[case] expected_dx: Vec<f64>,
+        #[case] expected_dy: Vec<f64>,
+    ) {
+        dswap(n, &mut dx, incx, &mut dy, incy);
+        assert_eq!(expected_dx, dx);
+        assert_eq!(expected_dy, dy);
+    }
+}
@@ -0,0 +1,302 @@
+use std::fmt::Debug;
+
+use crate::array::convert::{ToFortranArray, ToFortranArrayMut};
+use crate::error::Info;
+use crate::flags::{Side, Uplo};
+use crate::scalar::{Complex32, Complex64, Scalar};
+use crate::xerbla::illegal_argument;
+
+/// DSYMM
+///
+/// # Documentation
+///
+/// [Original] Online html documentation available at
+/// [http://www.netlib.org/lapack/explore-html/](http://www.netlib.org/lapack/explore-html/)
+///
+/// Performs one of the matrix-matrix operations
+///
+/// ```text
+/// C := alpha*A*B + beta*C,   or   C := alpha*B*A + beta*C,
+/// ```
+///
+/// where `alpha` and `beta` are scalars, `A` is a symmetric matrix, of which only
+/// the `uplo` triangle is referenced, and `B` and `C` are `m` by `n` matrices.
+/// `A` is `m` by `m` for [`Side::Left`] and `n` by `n` for [`Side::Right`].
+///
+/// # Arguments
+///
+/// For arguments definitions, please refer to the original documentation.
+pub fn dsymm<A, B, C>(
+    side: Side,
+    uplo: Uplo,
+    m: i32,
+    n: i32,
+    alpha: f64,
+    a: &A,
+    lda: i32,
+    b: &B,
+    ldb: i32,
+    beta: f64,
+    c: &mut C,
+    ldc: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray,
+    B: ToFortranArray,
+    C: ToFortranArrayMut,
+{
+    symm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+/// SSYMM
+///
+/// Single precision variant of [`dsymm`].
+pub fn ssymm<A, B, C>(
+    side: Side,
+    uplo: Uplo,
+    m: i32,
+    n: i32,
+    alpha: f32,
+    a: &A,
+    lda: i32,
+    b: &B,
+    ldb: i32,
+    beta: f32,
+    c: &mut C,
+    ldc: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<f32>,
+    B: ToFortranArray<f32>,
+    C: ToFortranArrayMut<f32>,
+{
+    symm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+/// CSYMM
+///
+/// Single precision complex variant of [`dsymm`].
+pub fn csymm<A, B, C>(
+    side: Side,
+    uplo: Uplo,
+    m: i32,
+    n: i32,
+    alpha: Complex32,
+    a: &A,
+    lda: i32,
+    b: &B,
+    ldb: i32,
+    beta: Complex32,
+    c: &mut C,
+    ldc: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<Complex32>,
+    B: ToFortranArray<Complex32>,
+    C: ToFortranArrayMut<Complex32>,
+{
+    symm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+/// ZSYMM
+///
+/// Double precision complex variant of [`dsymm`].
+pub fn zsymm<A, B, C>(
+    side: Side,
+    uplo: Uplo,
+    m: i32,
+    n: i32,
+    alpha: Complex64,
+    a: &A,
+    lda: i32,
+    b: &B,
+    ldb: i32,
+    beta: Complex64,
+    c: &mut C,
+    ldc: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<Complex64>,
+    B: ToFortranArray<Complex64>,
+    C: ToFortranArrayMut<Complex64>,
+{
+    symm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+/// Shared implementation of [`ssymm`], [`dsymm`], [`csymm`] and [`zsymm`].
+///
+/// For complex types `A` is complex symmetric, not Hermitian.
+pub fn symm<T, A, B, C>(
+    side: Side,
+    uplo: Uplo,
+    m: i32,
+    n: i32,
+    alpha: T,
+    a: &A,
+    lda: i32,
+    b: &B,
+    ldb: i32,
+    beta: T,
+    c: &mut C,
+    ldc: i32,
+) -> Result<(), Info> where
+    T: Scalar,
+    A: ToFortranArray<T>,
+    B: ToFortranArray<T>,
+    C: ToFortranArrayMut<T>,
+{
+    let lside = side == Side::Left;
+    let nrowa = if lside { m } else { n };
+    let upper = uplo == Uplo::Upper;
+    let illegal = |position, name, value: &dyn Debug| {
+        illegal_argument(format!("{}SYMM", T::PREFIX), position, name, value)
+    };
+
+    if m < 0 {
+        return illegal(3, "m", &m);
+    } else if n < 0 {
+        return illegal(4, "n", &n);
+    } else if lda < 1.max(nrowa) {
+        return illegal(7, "lda", &lda);
+    } else if ldb < 1.max(m) {
+        return illegal(9, "ldb", &ldb);
+    } else if ldc < 1.max(m) {
+        return illegal(12, "ldc", &ldc);
+    }
+
+    // Quick return if possible.
+    if m == 0 || n == 0 || (alpha.is_zero() && beta.is_one()) {
+        return Ok(());
+    }
+
+    let a_f = &a.fa_view_2d(lda);
+    let b_f = &b.fa_view_2d(ldb);
+    let c_f = &mut c.fa_view_2d_mut(ldc);
+
+    // And when  alpha.eq.zero.
+    if alpha.is_zero() {
+        for j in 1..=n {
+            for i in 1..=m {
+                c_f[(i, j)] = if beta.is_zero() { T::zero() } else { beta * c_f[(i, j)] };
+            }
+        }
+        return Ok(());
+    }
+
+    // Start the operations.
+    if lside {
+        // Form  C := alpha*A*B + beta*C.
+        if upper {
+            for j in 1..=n {
+                for i in 1..=m {
+                    let temp1 = alpha * b_f[(i, j)];
+                    let mut temp2 = T::zero();
+                    for k in 1..i {
+                        c_f[(k, j)] += temp1 * a_f[(k, i)];
+                        temp2 += b_f[(k, j)] * a_f[(k, i)];
+                    }
+                    let update = temp1 * a_f[(i, i)] + alpha * temp2;
+                    c_f[(i, j)] = if beta.is_zero() { update } else { beta * c_f[(i, j)] + update };
+                }
+            }
+        } else {
+            for j in 1..=n {
+                for i in (1..=m).rev() {
+                    let temp1 = alpha * b_f[(i, j)];
+                    let mut temp2 = T::zero();
+                    for k in i + 1..=m {
+                        c_f[(k, j)] += temp1 * a_f[(k, i)];
+                        temp2 += b_f[(k, j)] * a_f[(k, i)];
+                    }
+                    let update = temp1 * a_f[(i, i)] + alpha * temp2;
+                    c_f[(i, j)] = if beta.is_zero() { update } else { beta * c_f[(i, j)] + update };
+                }
+            }
+        }
+    } else {
+        // Form  C := alpha*B*A + beta*C.
+        for j in 1..=n {
+            let temp1 = alpha * a_f[(j, j)];
+            for i in 1..=m {
+                c_f[(i, j)] = if beta.is_zero() { temp1 * b_f[(i, j)] } else { beta * c_f[(i, j)] + temp1 * b_f[(i, j)] };
+            }
+            for k in 1..j {
+                let temp1 = if upper { alpha * a_f[(k, j)] } else { alpha * a_f[(j, k)] };
+                for i in 1..=m {
+                    c_f[(i, j)] += temp1 * b_f[(i, k)];
+                }
+            }
+            for k in j + 1..=n {
+                let temp1 = if upper { alpha * a_f[(j, k)] } else { alpha * a_f[(k, j)] };
+                for i in 1..=m {
+                    c_f[(i, j)] += temp1 * b_f[(i, k)];
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blas::dgemm::dgemm;
+    use crate::error::LapackError;
+    use crate::flags::Trans;
+    use crate::blas::fixtures::{stored_symmetric, symmetric};
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_dsymm(
+        #[values(Side::Left, Side::Right)] side: Side,
+        #[values(Uplo::Upper, Uplo::Lower)] uplo: Uplo,
+        #[values((1., 0.), (2., 0.5), (-1., 1.), (0., 3.))] scalars: (f64, f64),
+    ) {
+        let (alpha, beta) = scalars;
+        let (m, n) = (3, 2);
+        let k = if side == Side::Left { m } else { n };
+        let a = symmetric(k as usize);
+        let b = vec![1., -2., 3., 4., -5., 6.];
+        let c = vec![7., 8., -9., 10., 11., -12.];
+
+        let expected = &mut c.clone();
+        match side {
+            Side::Left => dgemm(Trans::NoTrans, Trans::NoTrans, m, n, m, alpha, &a, m, &b, m, beta, expected, m),
+            Side::Right => dgemm(Trans::NoTrans, Trans::NoTrans, m, n, n, alpha, &b, m, &a, n, beta, expected, m),
+        }.unwrap();
+
+        let actual = &mut c.clone();
+        dsymm(side, uplo, m, n, alpha, &stored_symmetric(uplo, &a, k as usize), k, &b, m, beta, actual, m).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_zsymm_is_not_hermitian() {
+        let (one, i) = (Complex64::new(1., 0.), Complex64::new(0., 1.));
+        let a = vec![one, i, i, one];
+        let c = &mut vec![Complex64::new(0., 0.); 2];
+        zsymm(Side::Left, Uplo::Lower, 2, 1, one, &a, 2, &vec![one, one], 2, Complex64::new(0., 0.), c, 2).unwrap();
+        assert_eq!(vec![one + i, one + i], *c);
+    }
+
+    #[rstest]
+    #[case(Side::Left, -1, 2, 3, 3, 3, 3, "m", "-1")]
+    #[case(Side::Left, 3, -1, 3, 3, 3, 4, "n", "-1")]
+    #[case(Side::Left, 3, 2, 2, 3, 3, 7, "lda", "2")]
+    #[case(Side::Right, 3, 2, 1, 3, 3, 7, "lda", "1")]
+    #[case(Side::Right, 3, 2, 2, 2, 3, 9, "ldb", "2")]
+    #[case(Side::Right, 3, 2, 2, 3, 2, 12, "ldc", "2")]
+    fn test_dsymm_illegal_argument(
+        #[case] side: Side,
+        #[case] m: i32,
+        #[case] n: i32,
+        #[case] lda: i32,
+        #[case] ldb: i32,
+        #[case] ldc: i32,
+        #[case] position: i32,
+        #[case] name: &'static str,
+        #[case] value: &str,
+    ) {
+        let (a, b, c) = (vec![1.; 9], vec![1.; 9], &mut vec![1.; 9]);
+        let expected = LapackError { routine: "DSYMM".to_string(), position, name, value: value.to_string() };
+        assert_eq!(Err(Info::IllegalArgument(expected)), dsymm(side, Uplo::Upper, m, n, 1., &a, lda, &b, ldb, 0., c, ldc));
+        assert_eq!(vec![1.; 9], *c);
+    }
+}
@@ -0,0 +1,227 @@
+use std::fmt::Debug;
+
+use crate::array::convert::{ToFortranArray, ToFortranArrayMut};
+use crate::error::Info;
+use crate::flags::Uplo;
+use crate::scalar::RealScalar;
+use crate::xerbla::illegal_argument;
+
+/// DSYMV
+///
+/// # Documentation
+///
+/// [Original] Online html documentation available at
+/// [http://www.netlib.org/lapack/explore-html/](http://www.netlib.org/lapack/explore-html/)
+///
+/// Performs the matrix-vector operation
+///
+/// `y := alpha*A*x + beta*y,`
+///
+/// where `alpha` and `beta` are scalars, `x` and `y` are `n` element vectors and
+/// `A` is an `n` by `n` symmetric matrix, of which only the `uplo` triangle is referenced.
+///
+/// # Arguments
+///
+/// For arguments definitions, please refer to the original documentation.
+pub fn dsymv<A, X, Y>(
+    uplo: Uplo,
+    n: i32,
+    alpha: f64,
+    a: &A,
+    lda: i32,
+    x: &X,
+    incx: i32,
+    beta: f64,
+    y: &mut Y,
+    incy: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray,
+    X: ToFortranArray,
+    Y: ToFortranArrayMut,
+{
+    symv(uplo, n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+/// SSYMV
+///
+/// Single precision variant of [`dsymv`].
+pub fn ssymv<A, X, Y>(
+    uplo: Uplo,
+    n: i32,
+    alpha: f32,
+    a: &A,
+    lda: i32,
+    x: &X,
+    incx: i32,
+    beta: f32,
+    y: &mut Y,
+    incy: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<f32>,
+    X: ToFortranArray<f32>,
+    Y: ToFortranArrayMut<f32>,
+{
+    symv(uplo, n, alpha, a, lda, x, incx, beta, y, incy)
+}
+
+/// Shared implementation of [`ssymv`] and [`dsymv`].
+pub fn symv<T, A, X, Y>(
+    uplo: Uplo,
+    n: i32,
+    alpha: T,
+    a: &A,
+    lda: i32,
+    x: &X,
+    incx: i32,
+    beta: T,
+    y: &mut Y,
+    incy: i32,
+) -> Result<(), Info> where
+    T: RealScalar,
+    A: ToFortranArray<T>,
+    X: ToFortranArray<T>,
+    Y: ToFortranArrayMut<T>,
+{
+    let illegal = |position, name, value: &dyn Debug| {
+        illegal_argument(format!("{}SYMV", T::PREFIX), position, name, value)
+    };
+
+    if n < 0 {
+        return illegal(2, "n", &n);
+    } else if lda < 1.max(n) {
+        return illegal(5, "lda", &lda);
+    } else if incx == 0 {
+        return illegal(7, "incx", &incx);
+    } else if incy == 0 {
+        return illegal(10, "incy", &incy);
+    }
+
+    // Quick return if possible.
+    if n == 0 || (alpha.is_zero() && beta.is_one()) {
+        return Ok(());
+    }
+
+    let a_f = &a.fa_view_2d(lda);
+    let x_f = &x.fa_view();
+    let y_f = &mut y.fa_view_mut();
+
+    // Set up the start points in x and y.
+    let kx = if incx > 0 { 1 } else { 1 - (n - 1) * incx };
+    let ky = if incy > 0 { 1 } else { 1 - (n - 1) * incy };
+
+    // First form  y := beta*y.
+    if !beta.is_one() {
+        let mut iy = ky;
+        for _i in 1..=n {
+            y_f[iy] = if beta.is_zero() { T::zero() } else { beta * y_f[iy] };
+            iy += incy;
+        }
+    }
+    if alpha.is_zero() { return Ok(()); }
+
+    let mut jx = kx;
+    let mut jy = ky;
+    match uplo {
+        Uplo::Upper => {
+            // Form  y  when A is stored in upper triangle.
+            for j in 1..=n {
+                let temp1 = alpha * x_f[jx];
+                let mut temp2 = T::zero();
+                let mut ix = kx;
+                let mut iy = ky;
+                for i in 1..j {
+                    y_f[iy] += temp1 * a_f[(i, j)];
+                    temp2 += a_f[(i, j)] * x_f[ix];
+                    ix += incx;
+                    iy += incy;
+                }
+                y_f[jy] += temp1 * a_f[(j, j)] + alpha * temp2;
+                jx += incx;
+                jy += incy;
+            }
+        },
+        Uplo::Lower => {
+            // Form  y  when A is stored in lower triangle.
+            for j in 1..=n {
+                let temp1 = alpha * x_f[jx];
+                let mut temp2 = T::zero();
+                y_f[jy] += temp1 * a_f[(j, j)];
+                let mut ix = jx;
+                let mut iy = jy;
+                for i in j + 1..=n {
+                    ix += incx;
+                    iy += incy;
+                    y_f[iy] += temp1 * a_f[(i, j)];
+                    temp2 += a_f[(i, j)] * x_f[ix];
+                }
+                y_f[jy] += alpha * temp2;
+                jx += incx;
+                jy += incy;
+            }
+        },
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blas::dgemv::dgemv;
+    use crate::error::LapackError;
+    use crate::flags::Trans;
+    use crate::blas::fixtures::{stored_symmetric, symmetric};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Uplo::Upper, 1., 1, 0., 1)]
+    #[case(Uplo::Lower, 1., 1, 0., 1)]
+    #[case(Uplo::Upper, 2., -1, 0.5, 2)]
+    #[case(Uplo::Lower, -1., 2, 3., -1)]
+    #[case(Uplo::Lower, 0., 1, 2., -2)]
+    fn test_dsymv(
+        #[case] uplo: Uplo,
+        #[case] alpha: f64,
+        #[case] incx: i32,
+        #[case] beta: f64,
+        #[case] incy: i32,
+    ) {
+        let x = vec![1., -2., 3., 4., -5., 6.];
+        let y = vec![7., 8., -9., 10., 11., -12.];
+
+        let expected = &mut y.clone();
+        dgemv(Trans::NoTrans, 3, 3, alpha, &symmetric(3), 3, &x, incx, beta, expected, incy).unwrap();
+
+        let actual = &mut y.clone();
+        dsymv(uplo, 3, alpha, &stored_symmetric(uplo, &symmetric(3), 3), 3, &x, incx, beta, actual, incy).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_ssymv() {
+        let a = vec![2f32, 99., 1., 3.];
+        let y = &mut vec![0f32; 2];
+        ssymv(Uplo::Upper, 2, 1., &a, 2, &vec![1f32, 1.], 1, 0., y, 1).unwrap();
+        assert_eq!(vec![3f32, 4.], *y);
+    }
+
+    #[rstest]
+    #[case(-1, 3, 1, 1, 2, "n", "-1")]
+    #[case(3, 2, 1, 1, 5, "lda", "2")]
+    #[case(3, 3, 0, 1, 7, "incx", "0")]
+    #[case(3, 3, 1, 0, 10, "incy", "0")]
+    fn test_dsymv_illegal_argument(
+        #[case] n: i32,
+        #[case] lda: i32,
+        #[case] incx: i32,
+        #[case] incy: i32,
+        #[case] position: i32,
+        #[case] name: &'static str,
+        #[case] value: &str,
+    ) {
+        let (a, x, y) = (symmetric(3), vec![1.; 3], &mut vec![1.; 3]);
+        let expected = LapackError { routine: "DSYMV".to_string(), position, name, value: value.to_string() };
+        assert_eq!(Err(Info::IllegalArgument(expected)), dsymv(Uplo::Upper, n, 1., &a, lda, &x, incx, 0., y, incy));
+        assert_eq!(vec![1.; 3], *y);
+    }
+}
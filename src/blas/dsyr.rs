@@ -0,0 +1,163 @@
+use std::fmt::Debug;
+
+use crate::array::convert::{ToFortranArray, ToFortranArrayMut};
+use crate::error::Info;
+use crate::flags::Uplo;
+use crate::scalar::RealScalar;
+use crate::xerbla::illegal_argument;
+
+/// DSYR
+///
+/// # Documentation
+///
+/// [Original] Online html documentation available at
+/// [http://www.netlib.org/lapack/explore-html/](http://www.netlib.org/lapack/explore-html/)
+///
+/// Performs the symmetric rank 1 operation
+///
+/// `A := alpha*x*x**T + A,`
+///
+/// where `alpha` is a real scalar, `x` is an `n` element vector and `A` is an
+/// `n` by `n` symmetric matrix, of which only the `uplo` triangle is referenced and updated.
+///
+/// # Arguments
+///
+/// For arguments definitions, please refer to the original documentation.
+pub fn dsyr<X, A>(
+    uplo: Uplo,
+    n: i32,
+    alpha: f64,
+    x: &X,
+    incx: i32,
+    a: &mut A,
+    lda: i32,
+) -> Result<(), Info> where
+    X: ToFortranArray,
+    A: ToFortranArrayMut,
+{
+    syr(uplo, n, alpha, x, incx, a, lda)
+}
+
+/// SSYR
+///
+/// Single precision variant of [`dsyr`].
+pub fn ssyr<X, A>(
+    uplo: Uplo,
+    n: i32,
+    alpha: f32,
+    x: &X,
+    incx: i32,
+    a: &mut A,
+    lda: i32,
+) -> Result<(), Info> where
+    X: ToFortranArray<f32>,
+    A: ToFortranArrayMut<f32>,
+{
+    syr(uplo, n, alpha, x, incx, a, lda)
+}
+
+/// Shared implementation of [`ssyr`] and [`dsyr`].
+pub fn syr<T, X, A>(
+    uplo: Uplo,
+    n: i32,
+    alpha: T,
+    x: &X,
+    incx: i32,
+    a: &mut A,
+    lda: i32,
+) -> Result<(), Info> where
+    T: RealScalar,
+    X: ToFortranArray<T>,
+    A: ToFortranArrayMut<T>,
+{
+    let illegal = |position, name, value: &dyn Debug| {
+        illegal_argument(format!("{}SYR", T::PREFIX), position, name, value)
+    };
+
+    if n < 0 {
+        return illegal(2, "n", &n);
+    } else if incx == 0 {
+        return illegal(5, "incx", &incx);
+    } else if lda < 1.max(n) {
+        return illegal(7, "lda", &lda);
+    }
+
+    // Quick return if possible.
+    if n == 0 || alpha.is_zero() {
+        return Ok(());
+    }
+
+    let x_f = &x.fa_view();
+    let a_f = &mut a.fa_view_2d_mut(lda);
+
+    // Set the start point in x if the increment is not unity.
+    let kx = if incx > 0 { 1 } else { 1 - (n - 1) * incx };
+
+    let mut jx = kx;
+    for j in 1..=n {
+        if !x_f[jx].is_zero() {
+            let temp = alpha * x_f[jx];
+            // Form  A  when A is stored in the upper triangle or in the lower triangle.
+            let (rows, mut ix) = match uplo {
+                Uplo::Upper => (1..=j, kx),
+                Uplo::Lower => (j..=n, jx),
+            };
+            for i in rows {
+                a_f[(i, j)] += x_f[ix] * temp;
+                ix += incx;
+            }
+        }
+        jx += incx;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blas::dger::dger;
+    use crate::error::LapackError;
+    use crate::blas::fixtures::{stored_symmetric, symmetric};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Uplo::Upper, 1., 1)]
+    #[case(Uplo::Lower, 1., 1)]
+    #[case(Uplo::Upper, -2., -1)]
+    #[case(Uplo::Lower, 0.5, 2)]
+    #[case(Uplo::Lower, 3., -2)]
+    fn test_dsyr(
+        #[case] uplo: Uplo,
+        #[case] alpha: f64,
+        #[case] incx: i32,
+    ) {
+        let x = vec![1., -2., 3., 4., -5., 6.];
+
+        let full = &mut symmetric(3);
+        dger(3, 3, alpha, &x, incx, &x, incx, full, 3).unwrap();
+        let expected = &mut stored_symmetric(uplo, full, 3);
+
+        let actual = &mut stored_symmetric(uplo, &symmetric(3), 3);
+        dsyr(uplo, 3, alpha, &x, incx, actual, 3).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    #[case(-1, 1, 3, 2, "n", "-1")]
+    #[case(3, 0, 3, 5, "incx", "0")]
+    #[case(3, 1, 2, 7, "lda", "2")]
+    fn test_dsyr_illegal_argument(
+        #[case] n: i32,
+        #[case] incx: i32,
+        #[case] lda: i32,
+        #[case] position: i32,
+        #[case] name: &'static str,
+        #[case] value: &str,
+    ) {
+        let (x, a) = (vec![1.; 3], &mut symmetric(3));
+        let expected = LapackError { routine: "DSYR".to_string(), position, name, value: value.to_string() };
+        assert_eq!(Err(Info::IllegalArgument(expected)), dsyr(Uplo::Lower, n, 1., &x, incx, a, lda));
+        assert_eq!(symmetric(3), *a);
+    }
+}
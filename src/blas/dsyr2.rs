@@ -0,0 +1,185 @@
+use std::fmt::Debug;
+
+use crate::array::convert::{ToFortranArray, ToFortranArrayMut};
+use crate::error::Info;
+use crate::flags::Uplo;
+use crate::scalar::RealScalar;
+use crate::xerbla::illegal_argument;
+
+/// DSYR2
+///
+/// # Documentation
+///
+/// [Original] Online html documentation available at
+/// [http://www.netlib.org/lapack/explore-html/](http://www.netlib.org/lapack/explore-html/)
+///
+/// Performs the symmetric rank 2 operation
+///
+/// `A := alpha*x*y**T + alpha*y*x**T + A,`
+///
+/// where `alpha` is a scalar, `x` and `y` are `n` element vectors and `A` is an
+/// `n` by `n` symmetric matrix, of which only the `uplo` triangle is referenced and updated.
+///
+/// # Arguments
+///
+/// For arguments definitions, please refer to the original documentation.
+pub fn dsyr2<X, Y, A>(
+    uplo: Uplo,
+    n: i32,
+    alpha: f64,
+    x: &X,
+    incx: i32,
+    y: &Y,
+    incy: i32,
+    a: &mut A,
+    lda: i32,
+) -> Result<(), Info> where
+    X: ToFortranArray,
+    Y: ToFortranArray,
+    A: ToFortranArrayMut,
+{
+    syr2(uplo, n, alpha, x, incx, y, incy, a, lda)
+}
+
+/// SSYR2
+///
+/// Single precision variant of [`dsyr2`].
+pub fn ssyr2<X, Y, A>(
+    uplo: Uplo,
+    n: i32,
+    alpha: f32,
+    x: &X,
+    incx: i32,
+    y: &Y,
+    incy: i32,
+    a: &mut A,
+    lda: i32,
+) -> Result<(), Info> where
+    X: ToFortranArray<f32>,
+    Y: ToFortranArray<f32>,
+    A: ToFortranArrayMut<f32>,
+{
+    syr2(uplo, n, alpha, x, incx, y, incy, a, lda)
+}
+
+/// Shared implementation of [`ssyr2`] and [`dsyr2`].
+pub fn syr2<T, X, Y, A>(
+    uplo: Uplo,
+    n: i32,
+    alpha: T,
+    x: &X,
+    incx: i32,
+    y: &Y,
+    incy: i32,
+    a: &mut A,
+    lda: i32,
+) -> Result<(), Info> where
+    T: RealScalar,
+    X: ToFortranArray<T>,
+    Y: ToFortranArray<T>,
+    A: ToFortranArrayMut<T>,
+{
+    let illegal = |position, name, value: &dyn Debug| {
+        illegal_argument(format!("{}SYR2", T::PREFIX), position, name, value)
+    };
+
+    if n < 0 {
+        return illegal(2, "n", &n);
+    } else if incx == 0 {
+        return illegal(5, "incx", &incx);
+    } else if incy == 0 {
+        return illegal(7, "incy", &incy);
+    } else if lda < 1.max(n) {
+        return illegal(9, "lda", &lda);
+    }
+
+    // Quick return if possible.
+    if n == 0 || alpha.is_zero() {
+        return Ok(());
+    }
+
+    let x_f = &x.fa_view();
+    let y_f = &y.fa_view();
+    let a_f = &mut a.fa_view_2d_mut(lda);
+
+    // Set up the start points in x and y if the increments are not both unity.
+    let kx = if incx > 0 { 1 } else { 1 - (n - 1) * incx };
+    let ky = if incy > 0 { 1 } else { 1 - (n - 1) * incy };
+
+    let mut jx = kx;
+    let mut jy = ky;
+    for j in 1..=n {
+        if !x_f[jx].is_zero() || !y_f[jy].is_zero() {
+            let temp1 = alpha * y_f[jy];
+            let temp2 = alpha * x_f[jx];
+            // Form  A  when A is stored in the upper triangle or in the lower triangle.
+            let (rows, mut ix, mut iy) = match uplo {
+                Uplo::Upper => (1..=j, kx, ky),
+                Uplo::Lower => (j..=n, jx, jy),
+            };
+            for i in rows {
+                a_f[(i, j)] += x_f[ix] * temp1 + y_f[iy] * temp2;
+                ix += incx;
+                iy += incy;
+            }
+        }
+        jx += incx;
+        jy += incy;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blas::dger::dger;
+    use crate::error::LapackError;
+    use crate::blas::fixtures::{stored_symmetric, symmetric};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Uplo::Upper, 1., 1, 1)]
+    #[case(Uplo::Lower, 1., 1, 1)]
+    #[case(Uplo::Upper, -2., -1, 2)]
+    #[case(Uplo::Lower, 0.5, 2, -1)]
+    #[case(Uplo::Upper, 3., -2, -2)]
+    fn test_dsyr2(
+        #[case] uplo: Uplo,
+        #[case] alpha: f64,
+        #[case] incx: i32,
+        #[case] incy: i32,
+    ) {
+        let x = vec![1., -2., 3., 4., -5., 6.];
+        let y = vec![7., 0., -9., 10., 11., -12.];
+
+        let full = &mut symmetric(3);
+        dger(3, 3, alpha, &x, incx, &y, incy, full, 3).unwrap();
+        dger(3, 3, alpha, &y, incy, &x, incx, full, 3).unwrap();
+        let expected = &mut stored_symmetric(uplo, full, 3);
+
+        let actual = &mut stored_symmetric(uplo, &symmetric(3), 3);
+        dsyr2(uplo, 3, alpha, &x, incx, &y, incy, actual, 3).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    #[case(-1, 1, 1, 3, 2, "n", "-1")]
+    #[case(3, 0, 1, 3, 5, "incx", "0")]
+    #[case(3, 1, 0, 3, 7, "incy", "0")]
+    #[case(3, 1, 1, 2, 9, "lda", "2")]
+    fn test_dsyr2_illegal_argument(
+        #[case] n: i32,
+        #[case] incx: i32,
+        #[case] incy: i32,
+        #[case] lda: i32,
+        #[case] position: i32,
+        #[case] name: &'static str,
+        #[case] value: &str,
+    ) {
+        let (x, y, a) = (vec![1.; 3], vec![1.; 3], &mut symmetric(3));
+        let expected = LapackError { routine: "DSYR2".to_string(), position, name, value: value.to_string() };
+        assert_eq!(Err(Info::IllegalArgument(expected)), dsyr2(Uplo::Upper, n, 1., &x, incx, &y, incy, a, lda));
+        assert_eq!(symmetric(3), *a);
+    }
+}
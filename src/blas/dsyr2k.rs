@@ -0,0 +1,292 @@
+use std::fmt::Debug;
+
+use crate::array::convert::{ToFortranArray, ToFortranArrayMut};
+use crate::error::Info;
+use crate::flags::{Trans, Uplo};
+use crate::scalar::{Complex32, Complex64, Scalar};
+use crate::xerbla::illegal_argument;
+
+/// DSYR2K
+///
+/// # Documentation
+///
+/// [Original] Online html documentation available at
+/// [http://www.netlib.org/lapack/explore-html/](http://www.netlib.org/lapack/explore-html/)
+///
+/// Performs one of the symmetric rank 2k operations
+///
+/// ```text
+/// C := alpha*A*B**T + alpha*B*A**T + beta*C,   or
+/// C := alpha*A**T*B + alpha*B**T*A + beta*C,
+/// ```
+///
+/// where `alpha` and `beta` are scalars, `C` is an `n` by `n` symmetric matrix, of
+/// which only the `uplo` triangle is referenced and updated, and `A` and `B` are `n`
+/// by `k` matrices in the first case and `k` by `n` matrices in the second case.
+///
+/// # Arguments
+///
+/// For arguments definitions, please refer to the original documentation.
+pub fn dsyr2k<A, B, C>(
+    uplo: Uplo,
+    trans: Trans,
+    n: i32,
+    k: i32,
+    alpha: f64,
+    a: &A,
+    lda: i32,
+    b: &B,
+    ldb: i32,
+    beta: f64,
+    c: &mut C,
+    ldc: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray,
+    B: ToFortranArray,
+    C: ToFortranArrayMut,
+{
+    syr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+/// SSYR2K
+///
+/// Single precision variant of [`dsyr2k`].
+pub fn ssyr2k<A, B, C>(
+    uplo: Uplo,
+    trans: Trans,
+    n: i32,
+    k: i32,
+    alpha: f32,
+    a: &A,
+    lda: i32,
+    b: &B,
+    ldb: i32,
+    beta: f32,
+    c: &mut C,
+    ldc: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<f32>,
+    B: ToFortranArray<f32>,
+    C: ToFortranArrayMut<f32>,
+{
+    syr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+/// CSYR2K
+///
+/// Single precision complex variant of [`dsyr2k`].
+pub fn csyr2k<A, B, C>(
+    uplo: Uplo,
+    trans: Trans,
+    n: i32,
+    k: i32,
+    alpha: Complex32,
+    a: &A,
+    lda: i32,
+    b: &B,
+    ldb: i32,
+    beta: Complex32,
+    c: &mut C,
+    ldc: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<Complex32>,
+    B: ToFortranArray<Complex32>,
+    C: ToFortranArrayMut<Complex32>,
+{
+    syr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+/// ZSYR2K
+///
+/// Double precision complex variant of [`dsyr2k`].
+pub fn zsyr2k<A, B, C>(
+    uplo: Uplo,
+    trans: Trans,
+    n: i32,
+    k: i32,
+    alpha: Complex64,
+    a: &A,
+    lda: i32,
+    b: &B,
+    ldb: i32,
+    beta: Complex64,
+    c: &mut C,
+    ldc: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<Complex64>,
+    B: ToFortranArray<Complex64>,
+    C: ToFortranArrayMut<Complex64>,
+{
+    syr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+/// Shared implementation of [`ssyr2k`], [`dsyr2k`], [`csyr2k`] and [`zsyr2k`].
+///
+/// For real types [`Trans::ConjTrans`] is the same as [`Trans::Trans`]; for complex
+/// types `C` is complex symmetric and [`Trans::ConjTrans`] is illegal.
+pub fn syr2k<T, A, B, C>(
+    uplo: Uplo,
+    trans: Trans,
+    n: i32,
+    k: i32,
+    alpha: T,
+    a: &A,
+    lda: i32,
+    b: &B,
+    ldb: i32,
+    beta: T,
+    c: &mut C,
+    ldc: i32,
+) -> Result<(), Info> where
+    T: Scalar,
+    A: ToFortranArray<T>,
+    B: ToFortranArray<T>,
+    C: ToFortranArrayMut<T>,
+{
+    let notrans = trans == Trans::NoTrans;
+    let nrowa = if notrans { n } else { k };
+    let upper = uplo == Uplo::Upper;
+    let illegal = |position, name, value: &dyn Debug| {
+        illegal_argument(format!("{}SYR2K", T::PREFIX), position, name, value)
+    };
+
+    if T::IS_COMPLEX && trans == Trans::ConjTrans {
+        return illegal(2, "trans", &trans);
+    } else if n < 0 {
+        return illegal(3, "n", &n);
+    } else if k < 0 {
+        return illegal(4, "k", &k);
+    } else if lda < 1.max(nrowa) {
+        return illegal(7, "lda", &lda);
+    } else if ldb < 1.max(nrowa) {
+        return illegal(9, "ldb", &ldb);
+    } else if ldc < 1.max(n) {
+        return illegal(12, "ldc", &ldc);
+    }
+
+    // Quick return if possible.
+    if n == 0 || ((alpha.is_zero() || k == 0) && beta.is_one()) {
+        return Ok(());
+    }
+
+    let a_f = &a.fa_view_2d(lda);
+    let b_f = &b.fa_view_2d(ldb);
+    let c_f = &mut c.fa_view_2d_mut(ldc);
+
+    // And when  alpha.eq.zero.
+    if alpha.is_zero() {
+        for j in 1..=n {
+            let rows = if upper { 1..=j } else { j..=n };
+            for i in rows {
+                c_f[(i, j)] = if beta.is_zero() { T::zero() } else { beta * c_f[(i, j)] };
+            }
+        }
+        return Ok(());
+    }
+
+    // Start the operations.
+    if notrans {
+        // Form  C := alpha*A*B**T + alpha*B*A**T + C.
+        for j in 1..=n {
+            let rows = if upper { 1..=j } else { j..=n };
+            if beta.is_zero() {
+                for i in rows.clone() {
+                    c_f[(i, j)] = T::zero();
+                }
+            } else if !beta.is_one() {
+                for i in rows.clone() {
+                    c_f[(i, j)] *= beta;
+                }
+            }
+            for l in 1..=k {
+                if !a_f[(j, l)].is_zero() || !b_f[(j, l)].is_zero() {
+                    let temp1 = alpha * b_f[(j, l)];
+                    let temp2 = alpha * a_f[(j, l)];
+                    for i in rows.clone() {
+                        c_f[(i, j)] += a_f[(i, l)] * temp1 + b_f[(i, l)] * temp2;
+                    }
+                }
+            }
+        }
+    } else {
+        // Form  C := alpha*A**T*B + alpha*B**T*A + C.
+        for j in 1..=n {
+            let rows = if upper { 1..=j } else { j..=n };
+            for i in rows {
+                let mut temp1 = T::zero();
+                let mut temp2 = T::zero();
+                for l in 1..=k {
+                    temp1 += a_f[(l, i)] * b_f[(l, j)];
+                    temp2 += b_f[(l, i)] * a_f[(l, j)];
+                }
+                let update = alpha * temp1 + alpha * temp2;
+                c_f[(i, j)] = if beta.is_zero() { update } else { beta * c_f[(i, j)] + update };
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blas::dgemm::dgemm;
+    use crate::error::LapackError;
+    use crate::blas::fixtures::{stored_symmetric, symmetric};
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_dsyr2k(
+        #[values(Uplo::Upper, Uplo::Lower)] uplo: Uplo,
+        #[values(Trans::NoTrans, Trans::Trans, Trans::ConjTrans)] trans: Trans,
+        #[values((1., 0.), (2., 0.5), (-1., 1.), (0., 3.))] scalars: (f64, f64),
+    ) {
+        let (alpha, beta) = scalars;
+        let (n, k) = (3, 2);
+        let a = vec![1., -2., 3., 4., -5., 6.];
+        let b = vec![0., 7., -1., 2., 8., -3.];
+        let (ld, transb) = if trans == Trans::NoTrans { (n, Trans::Trans) } else { (k, Trans::NoTrans) };
+
+        let full = &mut symmetric(3);
+        dgemm(trans, transb, n, n, k, alpha, &a, ld, &b, ld, beta, full, n).unwrap();
+        dgemm(trans, transb, n, n, k, alpha, &b, ld, &a, ld, 1., full, n).unwrap();
+        let expected = stored_symmetric(uplo, full, 3);
+
+        let actual = &mut stored_symmetric(uplo, &symmetric(3), 3);
+        dsyr2k(uplo, trans, n, k, alpha, &a, ld, &b, ld, beta, actual, n).unwrap();
+        assert_eq!(expected, *actual);
+    }
+
+    #[test]
+    fn test_zsyr2k_conjugate_transpose() {
+        let (zero, one) = (Complex64::new(0., 0.), Complex64::new(1., 0.));
+        let Err(Info::IllegalArgument(err)) = zsyr2k(Uplo::Upper, Trans::ConjTrans, 1, 1, one, &vec![one], 1, &vec![one], 1, zero, &mut vec![zero], 1) else {
+            panic!("expected an illegal argument");
+        };
+        assert_eq!(("ZSYR2K".to_string(), 2, "trans"), (err.routine, err.position, err.name));
+    }
+
+    #[rstest]
+    #[case(Trans::NoTrans, -1, 2, 3, 3, 3, 3, "n", "-1")]
+    #[case(Trans::NoTrans, 3, -1, 3, 3, 3, 4, "k", "-1")]
+    #[case(Trans::NoTrans, 3, 2, 2, 3, 3, 7, "lda", "2")]
+    #[case(Trans::Trans, 3, 2, 2, 1, 3, 9, "ldb", "1")]
+    #[case(Trans::Trans, 3, 2, 2, 2, 2, 12, "ldc", "2")]
+    fn test_dsyr2k_illegal_argument(
+        #[case] trans: Trans,
+        #[case] n: i32,
+        #[case] k: i32,
+        #[case] lda: i32,
+        #[case] ldb: i32,
+        #[case] ldc: i32,
+        #[case] position: i32,
+        #[case] name: &'static str,
+        #[case] value: &str,
+    ) {
+        let (a, b, c) = (vec![1.; 9], vec![1.; 9], &mut vec![1.; 9]);
+        let expected = LapackError { routine: "DSYR2K".to_string(), position, name, value: value.to_string() };
+        assert_eq!(Err(Info::IllegalArgument(expected)), dsyr2k(Uplo::Upper, trans, n, k, 1., &a, lda, &b, ldb, 0., c, ldc));
+        assert_eq!(vec![1.; 9], *c);
+    }
+}
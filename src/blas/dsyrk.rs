@@ -0,0 +1,270 @@
+use std::fmt::Debug;
+
+use crate::array::convert::{ToFortranArray, ToFortranArrayMut};
+use crate::error::Info;
+use crate::flags::{Trans, Uplo};
+use crate::scalar::{Complex32, Complex64, Scalar};
+use crate::xerbla::illegal_argument;
+
+/// DSYRK
+///
+/// # Documentation
+///
+/// [Original] Online html documentation available at
+/// [http://www.netlib.org/lapack/explore-html/](http://www.netlib.org/lapack/explore-html/)
+///
+/// Performs one of the symmetric rank k operations
+///
+/// ```text
+/// C := alpha*A*A**T + beta*C,   or   C := alpha*A**T*A + beta*C,
+/// ```
+///
+/// where `alpha` and `beta` are scalars, `C` is an `n` by `n` symmetric matrix, of
+/// which only the `uplo` triangle is referenced and updated, and `A` is an `n` by `k`
+/// matrix in the first case and a `k` by `n` matrix in the second case.
+///
+/// # Arguments
+///
+/// For arguments definitions, please refer to the original documentation.
+pub fn dsyrk<A, C>(
+    uplo: Uplo,
+    trans: Trans,
+    n: i32,
+    k: i32,
+    alpha: f64,
+    a: &A,
+    lda: i32,
+    beta: f64,
+    c: &mut C,
+    ldc: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray,
+    C: ToFortranArrayMut,
+{
+    syrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+}
+
+/// SSYRK
+///
+/// Single precision variant of [`dsyrk`].
+pub fn ssyrk<A, C>(
+    uplo: Uplo,
+    trans: Trans,
+    n: i32,
+    k: i32,
+    alpha: f32,
+    a: &A,
+    lda: i32,
+    beta: f32,
+    c: &mut C,
+    ldc: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<f32>,
+    C: ToFortranArrayMut<f32>,
+{
+    syrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+}
+
+/// CSYRK
+///
+/// Single precision complex variant of [`dsyrk`].
+pub fn csyrk<A, C>(
+    uplo: Uplo,
+    trans: Trans,
+    n: i32,
+    k: i32,
+    alpha: Complex32,
+    a: &A,
+    lda: i32,
+    beta: Complex32,
+    c: &mut C,
+    ldc: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<Complex32>,
+    C: ToFortranArrayMut<Complex32>,
+{
+    syrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+}
+
+/// ZSYRK
+///
+/// Double precision complex variant of [`dsyrk`].
+pub fn zsyrk<A, C>(
+    uplo: Uplo,
+    trans: Trans,
+    n: i32,
+    k: i32,
+    alpha: Complex64,
+    a: &A,
+    lda: i32,
+    beta: Complex64,
+    c: &mut C,
+    ldc: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<Complex64>,
+    C: ToFortranArrayMut<Complex64>,
+{
+    syrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+}
+
+/// Shared implementation of [`ssyrk`], [`dsyrk`], [`csyrk`] and [`zsyrk`].
+///
+/// For real types [`Trans::ConjTrans`] is the same as [`Trans::Trans`]; for complex
+/// types `C` is complex symmetric and [`Trans::ConjTrans`] is illegal.
+pub fn syrk<T, A, C>(
+    uplo: Uplo,
+    trans: Trans,
+    n: i32,
+    k: i32,
+    alpha: T,
+    a: &A,
+    lda: i32,
+    beta: T,
+    c: &mut C,
+    ldc: i32,
+) -> Result<(), Info> where
+    T: Scalar,
+    A: ToFortranArray<T>,
+    C: ToFortranArrayMut<T>,
+{
+    let notrans = trans == Trans::NoTrans;
+    let nrowa = if notrans { n } else { k };
+    let upper = uplo == Uplo::Upper;
+    let illegal = |position, name, value: &dyn Debug| {
+        illegal_argument(format!("{}SYRK", T::PREFIX), position, name, value)
+    };
+
+    if T::IS_COMPLEX && trans == Trans::ConjTrans {
+        return illegal(2, "trans", &trans);
+    } else if n < 0 {
+        return illegal(3, "n", &n);
+    } else if k < 0 {
+        return illegal(4, "k", &k);
+    } else if lda < 1.max(nrowa) {
+        return illegal(7, "lda", &lda);
+    } else if ldc < 1.max(n) {
+        return illegal(10, "ldc", &ldc);
+    }
+
+    // Quick return if possible.
+    if n == 0 || ((alpha.is_zero() || k == 0) && beta.is_one()) {
+        return Ok(());
+    }
+
+    let a_f = &a.fa_view_2d(lda);
+    let c_f = &mut c.fa_view_2d_mut(ldc);
+
+    // And when  alpha.eq.zero.
+    if alpha.is_zero() {
+        for j in 1..=n {
+            let rows = if upper { 1..=j } else { j..=n };
+            for i in rows {
+                c_f[(i, j)] = if beta.is_zero() { T::zero() } else { beta * c_f[(i, j)] };
+            }
+        }
+        return Ok(());
+    }
+
+    // Start the operations.
+    if notrans {
+        // Form  C := alpha*A*A**T + beta*C.
+        for j in 1..=n {
+            let rows = if upper { 1..=j } else { j..=n };
+            if beta.is_zero() {
+                for i in rows.clone() {
+                    c_f[(i, j)] = T::zero();
+                }
+            } else if !beta.is_one() {
+                for i in rows.clone() {
+                    c_f[(i, j)] *= beta;
+                }
+            }
+            for l in 1..=k {
+                if !a_f[(j, l)].is_zero() {
+                    let temp = alpha * a_f[(j, l)];
+                    for i in rows.clone() {
+                        c_f[(i, j)] += temp * a_f[(i, l)];
+                    }
+                }
+            }
+        }
+    } else {
+        // Form  C := alpha*A**T*A + beta*C.
+        for j in 1..=n {
+            let rows = if upper { 1..=j } else { j..=n };
+            for i in rows {
+                let mut temp = T::zero();
+                for l in 1..=k {
+                    temp += a_f[(l, i)] * a_f[(l, j)];
+                }
+                c_f[(i, j)] = if beta.is_zero() { alpha * temp } else { alpha * temp + beta * c_f[(i, j)] };
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blas::dgemm::dgemm;
+    use crate::error::LapackError;
+    use crate::blas::fixtures::{stored_symmetric, symmetric};
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_dsyrk(
+        #[values(Uplo::Upper, Uplo::Lower)] uplo: Uplo,
+        #[values(Trans::NoTrans, Trans::Trans, Trans::ConjTrans)] trans: Trans,
+        #[values((1., 0.), (2., 0.5), (-1., 1.), (0., 3.))] scalars: (f64, f64),
+    ) {
+        let (alpha, beta) = scalars;
+        let (n, k) = (3, 2);
+        let a = vec![1., -2., 3., 4., -5., 6.];
+        let (lda, transb) = if trans == Trans::NoTrans { (n, Trans::Trans) } else { (k, Trans::NoTrans) };
+
+        let full = &mut symmetric(3);
+        dgemm(trans, transb, n, n, k, alpha, &a, lda, &a, lda, beta, full, n).unwrap();
+        let expected = stored_symmetric(uplo, full, 3);
+
+        let actual = &mut stored_symmetric(uplo, &symmetric(3), 3);
+        dsyrk(uplo, trans, n, k, alpha, &a, lda, beta, actual, n).unwrap();
+        assert_eq!(expected, *actual);
+    }
+
+    #[test]
+    fn test_zsyrk_is_not_hermitian() {
+        let (zero, one, i) = (Complex64::new(0., 0.), Complex64::new(1., 0.), Complex64::new(0., 1.));
+        let c = &mut vec![zero];
+        zsyrk(Uplo::Upper, Trans::Trans, 1, 2, one, &vec![one, i], 2, zero, c, 1).unwrap();
+        assert_eq!(vec![zero], *c);
+
+        let Err(Info::IllegalArgument(err)) = zsyrk(Uplo::Upper, Trans::ConjTrans, 1, 2, one, &vec![one, i], 2, zero, c, 1) else {
+            panic!("expected an illegal argument");
+        };
+        assert_eq!(("ZSYRK".to_string(), 2, "trans"), (err.routine, err.position, err.name));
+    }
+
+    #[rstest]
+    #[case(Trans::NoTrans, -1, 2, 3, 3, 3, "n", "-1")]
+    #[case(Trans::NoTrans, 3, -1, 3, 3, 4, "k", "-1")]
+    #[case(Trans::NoTrans, 3, 2, 2, 3, 7, "lda", "2")]
+    #[case(Trans::Trans, 3, 2, 1, 3, 7, "lda", "1")]
+    #[case(Trans::Trans, 3, 2, 2, 2, 10, "ldc", "2")]
+    fn test_dsyrk_illegal_argument(
+        #[case] trans: Trans,
+        #[case] n: i32,
+        #[case] k: i32,
+        #[case] lda: i32,
+        #[case] ldc: i32,
+        #[case] position: i32,
+        #[case] name: &'static str,
+        #[case] value: &str,
+    ) {
+        let (a, c) = (vec![1.; 9], &mut vec![1.; 9]);
+        let expected = LapackError { routine: "DSYRK".to_string(), position, name, value: value.to_string() };
+        assert_eq!(Err(Info::IllegalArgument(expected)), dsyrk(Uplo::Lower, trans, n, k, 1., &a, lda, 0., c, ldc));
+        assert_eq!(vec![1.; 9], *c);
+    }
+}
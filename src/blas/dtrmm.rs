@@ -1,7 +1,12 @@
-use crate::array::convert::ToFortranArray;
-use crate::array::FortranArray;
-use crate::blas::lsame::lsame;
-use crate::xerbla::xerbla;
+use std::fmt::Debug;
+
+use crate::array::convert::{ToFortranArray, ToFortranArrayMut};
+use crate::array::view::{FortranView, FortranViewMut};
+use crate::blas::parallel::{for_each_column_block, for_each_row_block, Config};
+use crate::error::Info;
+use crate::flags::{Diag, Side, Trans, Uplo};
+use crate::scalar::{conj_if, Complex32, Complex64, Scalar};
+use crate::xerbla::illegal_argument;
 
 /// DTRMM
 ///
@@ -32,73 +37,205 @@ use crate::xerbla::xerbla;
 /// SIDE = 'R' or 'r'   B := alpha*B*op( A ).
 /// ```
 pub fn dtrmm<A, B>(
-    side: char,
-    uplo: char,
-    transa: char,
-    diag: char,
+    side: Side,
+    uplo: Uplo,
+    transa: Trans,
+    diag: Diag,
     m: i32,
     n: i32,
     alpha: f64,
-    a: &mut A,
+    a: &A,
+    lda: i32,
+    b: &mut B,
+    ldb: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray,
+    B: ToFortranArrayMut,
+{
+    trmm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+}
+
+/// STRMM
+///
+/// Single precision variant of [`dtrmm`].
+pub fn strmm<A, B>(
+    side: Side,
+    uplo: Uplo,
+    transa: Trans,
+    diag: Diag,
+    m: i32,
+    n: i32,
+    alpha: f32,
+    a: &A,
+    lda: i32,
+    b: &mut B,
+    ldb: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<f32>,
+    B: ToFortranArrayMut<f32>,
+{
+    trmm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+}
+
+/// CTRMM
+///
+/// Single precision complex variant of [`dtrmm`].
+pub fn ctrmm<A, B>(
+    side: Side,
+    uplo: Uplo,
+    transa: Trans,
+    diag: Diag,
+    m: i32,
+    n: i32,
+    alpha: Complex32,
+    a: &A,
     lda: i32,
     b: &mut B,
     ldb: i32,
-) where
-    A: ToFortranArray + From<FortranArray>,
-    B: ToFortranArray + From<FortranArray>,
+) -> Result<(), Info> where
+    A: ToFortranArray<Complex32>,
+    B: ToFortranArrayMut<Complex32>,
 {
-    let a_f = &mut a.to_fa();
-    let b_f = &mut b.to_fa();
+    trmm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+}
 
-    let lside = lsame(side, 'L');
+/// ZTRMM
+///
+/// Double precision complex variant of [`dtrmm`].
+pub fn ztrmm<A, B>(
+    side: Side,
+    uplo: Uplo,
+    transa: Trans,
+    diag: Diag,
+    m: i32,
+    n: i32,
+    alpha: Complex64,
+    a: &A,
+    lda: i32,
+    b: &mut B,
+    ldb: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<Complex64>,
+    B: ToFortranArrayMut<Complex64>,
+{
+    trmm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+}
+
+/// Shared implementation of [`strmm`], [`dtrmm`], [`ctrmm`] and [`ztrmm`].
+///
+/// For complex types [`Trans::ConjTrans`] applies the conjugate transpose.
+pub fn trmm<T, A, B>(
+    side: Side,
+    uplo: Uplo,
+    transa: Trans,
+    diag: Diag,
+    m: i32,
+    n: i32,
+    alpha: T,
+    a: &A,
+    lda: i32,
+    b: &mut B,
+    ldb: i32,
+) -> Result<(), Info> where
+    T: Scalar,
+    A: ToFortranArray<T>,
+    B: ToFortranArrayMut<T>,
+{
+    let lside = side == Side::Left;
     let nrowa = if lside { m } else { n };
 
-    let nounit = lsame(diag, 'N');
-    let upper = lsame(uplo, 'U');
-    let mut info = 0;
-    if  !lside && !lsame(side, 'R') {
-        info = 1;
-    } else if !upper && !lsame(uplo, 'L') {
-        info = 2;
-    } else if !lsame(transa, 'N') && !lsame(transa, 'T') && !lsame(transa, 'C') {
-        info = 3;
-    } else if !lsame(diag, 'U') && !lsame(diag, 'N') {
-        info = 4;
-    } else if m < 0 {
-        info = 5;
+    let illegal = |position, name, value: &dyn Debug| {
+        illegal_argument(format!("{}TRMM", T::PREFIX), position, name, value)
+    };
+
+    if m < 0 {
+        return illegal(5, "m", &m);
     } else if n < 0 {
-        info = 6;
+        return illegal(6, "n", &n);
     } else if lda < 1.max(nrowa) {
-        info = 9;
+        return illegal(9, "lda", &lda);
     } else if ldb < 1.max(m) {
-        info = 11;
-    }
-
-    if info != 0 {
-        xerbla("DTRMM ", info);
-        return;
+        return illegal(11, "ldb", &ldb);
     }
 
     if m == 0 || n == 0 {
-        return;
+        return Ok(());
     }
 
-    if alpha == 0. {
+    let a_f = &a.fa_view_2d(lda);
+    let b_f = &mut b.fa_view_2d_mut(ldb);
+
+    if alpha.is_zero() {
         for j in 1..=n {
             for i in 1..=m {
-                b_f[(i, j)] = 0.;
+                b_f[(i, j)] = T::zero();
             }
         }
-        *b = B::from(b_f.clone());
-        return;
+        return Ok(());
     }
 
+    trmm_blocks(Config::current(), side, uplo, transa, diag, m, n, alpha, a_f, b_f);
+
+    Ok(())
+}
+
+/// Runs [`trmm_kernel`] on blocks of `B`, split over the threads of `config`.
+///
+/// Left-side products transform every column of `B` on its own and right-side products every
+/// row, so the blocks give the same result for any number of threads.
+fn trmm_blocks<T: Scalar>(
+    config: Config,
+    side: Side,
+    uplo: Uplo,
+    transa: Trans,
+    diag: Diag,
+    m: i32,
+    n: i32,
+    alpha: T,
+    a_f: &FortranView<T>,
+    b_f: &mut FortranViewMut<T>,
+) {
+    // The blocks cover the `m` by `n` matrix, not the rest of `B`'s leading dimension.
+    let b_f = &mut b_f.sub_mut(1, 1, m, n);
+    if side == Side::Left {
+        let work = (m as usize).pow(2) * n as usize / 2;
+        for_each_column_block(config, work, b_f, |_, b_block| {
+            trmm_kernel(side, uplo, transa, diag, m, b_block.cols(), alpha, a_f, b_block)
+        });
+    } else {
+        let work = m as usize * (n as usize).pow(2) / 2;
+        // SAFETY: the kernel only addresses rows 1..=m of its block.
+        unsafe {
+            for_each_row_block(config, work, b_f, |_, b_block| {
+                trmm_kernel(side, uplo, transa, diag, b_block.rows(), n, alpha, a_f, b_block)
+            });
+        }
+    }
+}
+
+/// `B := alpha*op( A )*B` or `B := alpha*B*op( A )` for an `m` by `n` block of `B`.
+fn trmm_kernel<T: Scalar>(
+    side: Side,
+    uplo: Uplo,
+    transa: Trans,
+    diag: Diag,
+    m: i32,
+    n: i32,
+    alpha: T,
+    a_f: &FortranView<T>,
+    b_f: &mut FortranViewMut<T>,
+) {
+    let lside = side == Side::Left;
+    let nounit = diag == Diag::NonUnit;
+    let upper = uplo == Uplo::Upper;
+    let conja = transa == Trans::ConjTrans;
+
     if lside {
-        if lsame(transa, 'N') {
+        if transa == Trans::NoTrans {
             if upper {
                 for j in 1..=n {
                     for k in 1..=m {
-                        if b_f[(k, j)] != 0. {
+                        if b_f[(k, j)] != T::zero() {
                             let mut temp = alpha * b_f[(k, j)];
                             for i in 1..k {
                                 b_f[(i, j)] += temp * a_f[(i, k)];
@@ -113,7 +250,7 @@ pub fn dtrmm<A, B>(
             } else {
                 for j in 1..=n {
                     for k in (1..=m).rev() {
-                        if b_f[(k, j)] != 0. {
+                        if b_f[(k, j)] != T::zero() {
                             let temp = alpha * b_f[(k, j)];
                             b_f[(k, j)] = temp;
                             if nounit {
@@ -132,10 +269,10 @@ pub fn dtrmm<A, B>(
                     for i in (1..=m).rev() {
                         let mut temp = b_f[(i, j)];
                         if nounit {
-                            temp *= a_f[(i, i)];
+                            temp *= conj_if(conja, a_f[(i, i)]);
                         }
                         for k in 1..i {
-                            temp += a_f[(k, i)] * b_f[(k, j)];
+                            temp += conj_if(conja, a_f[(k, i)]) * b_f[(k, j)];
                         }
                         b_f[(i, j)] = alpha * temp;
                     }
@@ -145,10 +282,10 @@ pub fn dtrmm<A, B>(
                     for i in 1..=m {
                         let mut temp = b_f[(i, j)];
                         if nounit {
-                            temp *= a_f[(i, i)];
+                            temp *= conj_if(conja, a_f[(i, i)]);
                         }
                         for k in i + 1..=m {
-                            temp += a_f[(k, i)] * b_f[(k, j)];
+                            temp += conj_if(conja, a_f[(k, i)]) * b_f[(k, j)];
                         }
                         b_f[(i, j)] = alpha * temp;
                     }
@@ -156,7 +293,7 @@ pub fn dtrmm<A, B>(
             }
         }
     } else {
-        if lsame(transa, 'N') {
+        if transa == Trans::NoTrans {
             if upper {
                 for j in (1..=n).rev() {
                     let mut temp = alpha;
@@ -164,13 +301,14 @@ pub fn dtrmm<A, B>(
                         temp *= a_f[(j, j)];
                     }
                     for i in 1..=m {
-                        b_f[(i, j)] = temp * b_f[(i, j)];
+                        b_f[(i, j)] *= temp;
                     }
                     for k in 1..j {
-                        if a_f[(k, j)] != 0. {
+                        if a_f[(k, j)] != T::zero() {
                             temp = alpha * a_f[(k, j)];
                             for i in 1..=m {
-                                b_f[(i, j)] += temp * b_f[(i, k)];
+                                let bik = b_f[(i, k)];
+                                b_f[(i, j)] += temp * bik;
                             }
                         }
                     }
@@ -182,13 +320,14 @@ pub fn dtrmm<A, B>(
                         temp *= a_f[(j, j)];
                     }
                     for i in 1..=m {
-                        b_f[(i, j)] = temp * b_f[(i, j)];
+                        b_f[(i, j)] *= temp;
                     }
                     for k in j + 1..=n {
-                        if a_f[(k, j)] != 0. {
+                        if a_f[(k, j)] != T::zero() {
                             temp = alpha * a_f[(k, j)];
                             for i in 1..=m {
-                                b_f[(i, j)] += temp * b_f[(i, k)];
+                                let bik = b_f[(i, k)];
+                                b_f[(i, j)] += temp * bik;
                             }
                         }
                     }
@@ -198,49 +337,48 @@ pub fn dtrmm<A, B>(
             if upper {
                 for k in 1..=n {
                     for j in 1..k {
-                        if a_f[(j, k)] != 0. {
-                            let temp = alpha * a_f[(j, k)];
+                        if a_f[(j, k)] != T::zero() {
+                            let temp = alpha * conj_if(conja, a_f[(j, k)]);
                             for i in 1..=m {
-                                b_f[(i, j)] = b_f[(i, j)] + temp * b_f[(i, k)];
+                                let bik = b_f[(i, k)];
+                                b_f[(i, j)] += temp * bik;
                             }
                         }
                     }
                     let mut temp = alpha;
                     if nounit {
-                        temp *= a_f[(k, k)];
+                        temp *= conj_if(conja, a_f[(k, k)]);
                     }
-                    if temp != 1. {
+                    if !temp.is_one() {
                         for i in 1..=m {
-                            b_f[(i, k)] = temp * b_f[(i, k)];
+                            b_f[(i, k)] *= temp;
                         }
                     }
                 }
             } else {
                 for k in (1..=n).rev() {
                     for j in k + 1..=n {
-                        if a_f[(j, k)] != 0. {
-                            let temp = alpha * a_f[(j, k)];
+                        if a_f[(j, k)] != T::zero() {
+                            let temp = alpha * conj_if(conja, a_f[(j, k)]);
                             for i in 1..=m {
-                                b_f[(i, j)] = b_f[(i, j)] + temp * b_f[(i, k)];
+                                let bik = b_f[(i, k)];
+                                b_f[(i, j)] += temp * bik;
                             }
                         }
                     }
                     let mut temp = alpha;
                     if nounit {
-                        temp *= a_f[(k, k)];
+                        temp *= conj_if(conja, a_f[(k, k)]);
                     }
-                    if temp != 1. {
+                    if !temp.is_one() {
                         for i in 1..=m {
-                            b_f[(i, k)] = temp * b_f[(i, k)];
+                            b_f[(i, k)] *= temp;
                         }
                     }
                 }
             }
         }
     }
-
-    *a = A::from(a_f.clone());
-    *b = B::from(b_f.clone());
 }
 
 #[cfg(test)]
@@ -249,17 +387,17 @@ mod tests {
     use rstest::rstest;
 
     #[rstest]
-    #[case('L', 'U', 'N', 'N', 2, 2, 1., [[1., 2.], [0., 1.]], 2, [[3., 4.], [5., 6.]], 2, [[1., 2.], [0., 1.]], [[3., 4.], [5., 6.]])]
-    #[case('R', 'U', 'N', 'U', 2, 2, 2., [[1., 0.], [2., 1.]], 2, [[1., 2.], [3., 4.]], 2, [[1., 0.], [2., 1.]], [[2., 4.], [10., 16.]])]
-    #[case('L', 'L', 'T', 'N', 2, 2, 3., [[1., 0.], [1., 2.]], 2, [[1., 2.], [3., 4.]], 2, [[1., 0.], [1., 2.]], [[3., 12.], [9., 24.]])]
-    #[case('R', 'L', 'N', 'N', 2, 2, 2., [[1., 1.], [0., 2.]], 2, [[1., 2.], [3., 4.]], 2, [[1., 1.], [0., 2.]], [[8., 12.], [12., 16.]])]
-    #[case('L', 'U', 'N', 'U', 2, 2, 1., [[1., 2.], [0., 1.]], 2, [[0., 0.], [0., 0.]], 2, [[1., 2.], [0., 1.]], [[0., 0.], [0., 0.]])]
-    #[case('R', 'U', 'T', 'N', 2, 2, 2., [[1., 0.], [2., 1.]], 2, [[0., 0.], [0., 0.]], 2, [[1., 0.], [2., 1.]], [[0., 0.], [0., 0.]])]
+    #[case(Side::Left, Uplo::Upper, Trans::NoTrans, Diag::NonUnit, 2, 2, 1., [[1., 2.], [0., 1.]], 2, [[3., 4.], [5., 6.]], 2, [[1., 2.], [0., 1.]], [[3., 4.], [5., 6.]])]
+    #[case(Side::Right, Uplo::Upper, Trans::NoTrans, Diag::Unit, 2, 2, 2., [[1., 0.], [2., 1.]], 2, [[1., 2.], [3., 4.]], 2, [[1., 0.], [2., 1.]], [[2., 4.], [10., 16.]])]
+    #[case(Side::Left, Uplo::Lower, Trans::Trans, Diag::NonUnit, 2, 2, 3., [[1., 0.], [1., 2.]], 2, [[1., 2.], [3., 4.]], 2, [[1., 0.], [1., 2.]], [[3., 12.], [9., 24.]])]
+    #[case(Side::Right, Uplo::Lower, Trans::NoTrans, Diag::NonUnit, 2, 2, 2., [[1., 1.], [0., 2.]], 2, [[1., 2.], [3., 4.]], 2, [[1., 1.], [0., 2.]], [[8., 12.], [12., 16.]])]
+    #[case(Side::Left, Uplo::Upper, Trans::NoTrans, Diag::Unit, 2, 2, 1., [[1., 2.], [0., 1.]], 2, [[0., 0.], [0., 0.]], 2, [[1., 2.], [0., 1.]], [[0., 0.], [0., 0.]])]
+    #[case(Side::Right, Uplo::Upper, Trans::Trans, Diag::NonUnit, 2, 2, 2., [[1., 0.], [2., 1.]], 2, [[0., 0.], [0., 0.]], 2, [[1., 0.], [2., 1.]], [[0., 0.], [0., 0.]])]
     fn test_dtrmm(
-        #[case] side: char,
-        #[case] uplo: char,
-        #[case] transa: char,
-        #[case] diag: char,
+        #[case] side: Side,
+        #[case] uplo: Uplo,
+        #[case] transa: Trans,
+        #[case] diag: Diag,
         #[case] m: i32,
         #[case] n: i32,
         #[case] alpha: f64,
@@ -276,9 +414,63 @@ mod tests {
         let expected_a = expected_a.iter().map(|r| r.to_vec()).collect::<Vec<Vec<f64>>>();
         let expected_b = expected_b.iter().map(|r| r.to_vec()).collect::<Vec<Vec<f64>>>();
 
-        dtrmm(side, uplo, transa, diag, m, n, alpha, a_mut, lda, b_mut, ldb);
+        dtrmm(side, uplo, transa, diag, m, n, alpha, a_mut, lda, b_mut, ldb).unwrap();
 
         assert_eq!(expected_a, a_mut.clone());
         assert_eq!(expected_b, b_mut.clone());
     }
+
+    #[test]
+    fn test_ztrmm_conjugate_transpose() {
+        let (zero, one, i) = (Complex64::new(0., 0.), Complex64::new(1., 0.), Complex64::new(0., 1.));
+        let a = vec![vec![one, zero], vec![i, one + one]];
+        let b = &mut vec![vec![one, zero], vec![zero, one]];
+        ztrmm(Side::Left, Uplo::Upper, Trans::ConjTrans, Diag::NonUnit, 2, 2, one, &a, 2, b, 2).unwrap();
+        assert_eq!(vec![vec![one, -i], vec![zero, one + one]], b.clone());
+    }
+
+    #[rstest]
+    fn test_trmm_blocks_deterministic(
+        #[values(Side::Left, Side::Right)] side: Side,
+        #[values(Uplo::Upper, Uplo::Lower)] uplo: Uplo,
+        #[values(Trans::NoTrans, Trans::Trans)] transa: Trans,
+    ) {
+        let (m, n): (usize, usize) = (90, 110);
+        let k = if side == Side::Left { m } else { n };
+        let a: Vec<f64> = (0..k * k).map(|p| ((p * 7) % 17) as f64 / 8. - 1.).collect();
+        let b: Vec<f64> = (0..m * n).map(|p| ((p * 5) % 13) as f64 / 4. - 1.5).collect();
+        let a_f = &FortranView::new(&a, k as i32, k as i32, k as i32);
+        let run = |threads| {
+            let mut b = b.clone();
+            let mut b_f = FortranViewMut::new(&mut b, m as i32, n as i32, m as i32);
+            let config = Config { threads, dedicated: false, deterministic: true };
+            trmm_blocks(config, side, uplo, transa, Diag::NonUnit, m as i32, n as i32, 0.5, a_f, &mut b_f);
+            drop(b_f);
+            b
+        };
+        let serial = run(1);
+        for threads in 2..=4 {
+            assert_eq!(serial, run(threads));
+        }
+    }
+
+    #[rstest]
+    fn test_dtrmm_leaves_rest_of_b(
+        #[values(Side::Left, Side::Right)] side: Side,
+    ) {
+        // B is 2 by 2 inside 3 by 3 storage; the padding row and the spare column stay put.
+        let a = vec![2., 0., 0., 2.];
+        let b = &mut vec![1., 2., 9., 3., 4., 9., 9., 9., 9.];
+        dtrmm(side, Uplo::Upper, Trans::NoTrans, Diag::NonUnit, 2, 2, 1., &a, 2, b, 3).unwrap();
+        assert_eq!(vec![2., 4., 9., 6., 8., 9., 9., 9., 9.], *b);
+    }
+
+    #[test]
+    fn test_trmm_illegal_argument() {
+        let Err(Info::IllegalArgument(err)) = strmm(Side::Left, Uplo::Upper, Trans::NoTrans, Diag::NonUnit, 2, 2, 1., &vec![1f32; 4], 1, &mut vec![1f32; 4], 2) else {
+            panic!("expected an illegal argument");
+        };
+        assert_eq!(("STRMM".to_string(), 9, "lda"), (err.routine, err.position, err.name));
+        assert_eq!(-5, dtrmm(Side::Left, Uplo::Upper, Trans::NoTrans, Diag::NonUnit, -1, 2, 1., &vec![1.; 4], 2, &mut vec![1.; 4], 2).unwrap_err().info());
+    }
 }
@@ -0,0 +1,271 @@
+use std::fmt::Debug;
+
+use crate::array::convert::{ToFortranArray, ToFortranArrayMut};
+use crate::error::Info;
+use crate::flags::{Diag, Trans, Uplo};
+use crate::scalar::{conj_if, Complex32, Complex64, Scalar};
+use crate::xerbla::illegal_argument;
+
+/// DTRMV
+///
+/// # Documentation
+///
+/// [Original] Online html documentation available at
+/// [http://www.netlib.org/lapack/explore-html/](http://www.netlib.org/lapack/explore-html/)
+///
+/// Performs one of the matrix-vector operations
+///
+/// ```text
+/// x := A*x,   or   x := A**T*x,
+/// ```
+///
+/// where `x` is an `n` element vector and `A` is an `n` by `n` unit, or non-unit,
+/// upper or lower triangular matrix.
+///
+/// # Arguments
+///
+/// For arguments definitions, please refer to the original documentation.
+pub fn dtrmv<A, X>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: i32,
+    a: &A,
+    lda: i32,
+    x: &mut X,
+    incx: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray,
+    X: ToFortranArrayMut,
+{
+    trmv(uplo, trans, diag, n, a, lda, x, incx)
+}
+
+/// STRMV
+///
+/// Single precision variant of [`dtrmv`].
+pub fn strmv<A, X>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: i32,
+    a: &A,
+    lda: i32,
+    x: &mut X,
+    incx: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<f32>,
+    X: ToFortranArrayMut<f32>,
+{
+    trmv(uplo, trans, diag, n, a, lda, x, incx)
+}
+
+/// CTRMV
+///
+/// Single precision complex variant of [`dtrmv`].
+pub fn ctrmv<A, X>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: i32,
+    a: &A,
+    lda: i32,
+    x: &mut X,
+    incx: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<Complex32>,
+    X: ToFortranArrayMut<Complex32>,
+{
+    trmv(uplo, trans, diag, n, a, lda, x, incx)
+}
+
+/// ZTRMV
+///
+/// Double precision complex variant of [`dtrmv`].
+pub fn ztrmv<A, X>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: i32,
+    a: &A,
+    lda: i32,
+    x: &mut X,
+    incx: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<Complex64>,
+    X: ToFortranArrayMut<Complex64>,
+{
+    trmv(uplo, trans, diag, n, a, lda, x, incx)
+}
+
+/// Shared implementation of [`strmv`], [`dtrmv`], [`ctrmv`] and [`ztrmv`].
+///
+/// For complex types [`Trans::ConjTrans`] applies the conjugate transpose.
+pub fn trmv<T, A, X>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: i32,
+    a: &A,
+    lda: i32,
+    x: &mut X,
+    incx: i32,
+) -> Result<(), Info> where
+    T: Scalar,
+    A: ToFortranArray<T>,
+    X: ToFortranArrayMut<T>,
+{
+    let nounit = diag == Diag::NonUnit;
+    let upper = uplo == Uplo::Upper;
+    let conj = trans == Trans::ConjTrans;
+    let illegal = |position, name, value: &dyn Debug| {
+        illegal_argument(format!("{}TRMV", T::PREFIX), position, name, value)
+    };
+
+    if n < 0 {
+        return illegal(4, "n", &n);
+    } else if lda < 1.max(n) {
+        return illegal(6, "lda", &lda);
+    } else if incx == 0 {
+        return illegal(8, "incx", &incx);
+    }
+
+    // Quick return if possible.
+    if n == 0 {
+        return Ok(());
+    }
+
+    let a_f = &a.fa_view_2d(lda);
+    let x_f = &mut x.fa_view_mut();
+
+    // Set up the start point in x if the increment is not unity.
+    let mut kx = if incx > 0 { 1 } else { 1 - (n - 1) * incx };
+
+    // Start the operations. In this version the elements of A are accessed
+    // sequentially with one pass through A.
+    if trans == Trans::NoTrans {
+        // Form  x := A*x.
+        if upper {
+            let mut jx = kx;
+            for j in 1..=n {
+                if !x_f[jx].is_zero() {
+                    let temp = x_f[jx];
+                    let mut ix = kx;
+                    for i in 1..j {
+                        x_f[ix] += temp * a_f[(i, j)];
+                        ix += incx;
+                    }
+                    if nounit {
+                        x_f[jx] *= a_f[(j, j)];
+                    }
+                }
+                jx += incx;
+            }
+        } else {
+            kx += (n - 1) * incx;
+            let mut jx = kx;
+            for j in (1..=n).rev() {
+                if !x_f[jx].is_zero() {
+                    let temp = x_f[jx];
+                    let mut ix = kx;
+                    for i in (j + 1..=n).rev() {
+                        x_f[ix] += temp * a_f[(i, j)];
+                        ix -= incx;
+                    }
+                    if nounit {
+                        x_f[jx] *= a_f[(j, j)];
+                    }
+                }
+                jx -= incx;
+            }
+        }
+    } else {
+        // Form  x := A**T*x  or  x := A**H*x.
+        if upper {
+            let mut jx = kx + (n - 1) * incx;
+            for j in (1..=n).rev() {
+                let mut temp = x_f[jx];
+                let mut ix = jx;
+                if nounit {
+                    temp *= conj_if(conj, a_f[(j, j)]);
+                }
+                for i in (1..j).rev() {
+                    ix -= incx;
+                    temp += conj_if(conj, a_f[(i, j)]) * x_f[ix];
+                }
+                x_f[jx] = temp;
+                jx -= incx;
+            }
+        } else {
+            let mut jx = kx;
+            for j in 1..=n {
+                let mut temp = x_f[jx];
+                let mut ix = jx;
+                if nounit {
+                    temp *= conj_if(conj, a_f[(j, j)]);
+                }
+                for i in j + 1..=n {
+                    ix += incx;
+                    temp += conj_if(conj, a_f[(i, j)]) * x_f[ix];
+                }
+                x_f[jx] = temp;
+                jx += incx;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blas::dgemv::dgemv;
+    use crate::error::LapackError;
+    use crate::blas::fixtures::{stored_triangular, triangular};
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_dtrmv(
+        #[values(Uplo::Upper, Uplo::Lower)] uplo: Uplo,
+        #[values(Trans::NoTrans, Trans::Trans, Trans::ConjTrans)] trans: Trans,
+        #[values(Diag::NonUnit, Diag::Unit)] diag: Diag,
+        #[values(1, -1, 2, -2)] incx: i32,
+    ) {
+        let x = vec![1., -2., 3., 4., -5.];
+
+        let expected = &mut x.clone();
+        dgemv(trans, 3, 3, 1., &triangular(uplo, diag), 3, &x, incx, 0., expected, incx).unwrap();
+
+        let actual = &mut x.clone();
+        dtrmv(uplo, trans, diag, 3, &stored_triangular(uplo, diag), 3, actual, incx).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_ztrmv_conjugate_transpose() {
+        let (zero, one, i) = (Complex64::new(0., 0.), Complex64::new(1., 0.), Complex64::new(0., 1.));
+        let a = vec![one, zero, i, one + one];
+        let x = &mut vec![one, one];
+        ztrmv(Uplo::Upper, Trans::ConjTrans, Diag::NonUnit, 2, &a, 2, x, 1).unwrap();
+        assert_eq!(vec![one, one + one - i], *x);
+    }
+
+    #[rstest]
+    #[case(-1, 3, 1, 4, "n", "-1")]
+    #[case(3, 2, 1, 6, "lda", "2")]
+    #[case(3, 3, 0, 8, "incx", "0")]
+    fn test_dtrmv_illegal_argument(
+        #[case] n: i32,
+        #[case] lda: i32,
+        #[case] incx: i32,
+        #[case] position: i32,
+        #[case] name: &'static str,
+        #[case] value: &str,
+    ) {
+        let x = &mut vec![1.; 3];
+        let expected = LapackError { routine: "DTRMV".to_string(), position, name, value: value.to_string() };
+        assert_eq!(Err(Info::IllegalArgument(expected)), dtrmv(Uplo::Upper, Trans::NoTrans, Diag::NonUnit, n, &vec![1.; 9], lda, x, incx));
+        assert_eq!(vec![1.; 3], *x);
+    }
+}
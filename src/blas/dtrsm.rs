@@ -0,0 +1,437 @@
+use std::fmt::Debug;
+
+use crate::array::convert::{ToFortranArray, ToFortranArrayMut};
+use crate::error::Info;
+use crate::flags::{Diag, Side, Trans, Uplo};
+use crate::scalar::{conj_if, Complex32, Complex64, Scalar};
+use crate::xerbla::illegal_argument;
+
+/// DTRSM
+///
+/// # Documentation
+///
+/// [Original] Online html documentation available at
+/// [http://www.netlib.org/lapack/explore-html/](http://www.netlib.org/lapack/explore-html/)
+///
+/// Solves one of the matrix equations
+///
+/// ```text
+/// op( A )*X = alpha*B,   or   X*op( A ) = alpha*B,
+/// ```
+///
+/// where alpha is a scalar, X and B are m by n matrices, A is a unit, or
+/// non-unit,  upper or lower triangular matrix  and  op( A )  is one  of
+///
+/// ```text
+/// op( A ) = A   or   op( A ) = A**T.
+/// ```
+///
+/// The matrix X is overwritten on B.
+///
+/// On entry, SIDE specifies whether op( A ) appears on the left
+/// or right of X as follows:
+///
+/// ```text
+/// SIDE = 'L' or 'l'   op( A )*X = alpha*B.
+///
+/// SIDE = 'R' or 'r'   X*op( A ) = alpha*B.
+/// ```
+///
+/// No test for singularity or near-singularity is included in this routine.
+/// Such tests must be performed before calling this routine.
+pub fn dtrsm<A, B>(
+    side: Side,
+    uplo: Uplo,
+    transa: Trans,
+    diag: Diag,
+    m: i32,
+    n: i32,
+    alpha: f64,
+    a: &A,
+    lda: i32,
+    b: &mut B,
+    ldb: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray,
+    B: ToFortranArrayMut,
+{
+    trsm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+}
+
+/// STRSM
+///
+/// Single precision variant of [`dtrsm`].
+pub fn strsm<A, B>(
+    side: Side,
+    uplo: Uplo,
+    transa: Trans,
+    diag: Diag,
+    m: i32,
+    n: i32,
+    alpha: f32,
+    a: &A,
+    lda: i32,
+    b: &mut B,
+    ldb: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<f32>,
+    B: ToFortranArrayMut<f32>,
+{
+    trsm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+}
+
+/// CTRSM
+///
+/// Single precision complex variant of [`dtrsm`].
+pub fn ctrsm<A, B>(
+    side: Side,
+    uplo: Uplo,
+    transa: Trans,
+    diag: Diag,
+    m: i32,
+    n: i32,
+    alpha: Complex32,
+    a: &A,
+    lda: i32,
+    b: &mut B,
+    ldb: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<Complex32>,
+    B: ToFortranArrayMut<Complex32>,
+{
+    trsm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+}
+
+/// ZTRSM
+///
+/// Double precision complex variant of [`dtrsm`].
+pub fn ztrsm<A, B>(
+    side: Side,
+    uplo: Uplo,
+    transa: Trans,
+    diag: Diag,
+    m: i32,
+    n: i32,
+    alpha: Complex64,
+    a: &A,
+    lda: i32,
+    b: &mut B,
+    ldb: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<Complex64>,
+    B: ToFortranArrayMut<Complex64>,
+{
+    trsm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+}
+
+/// Shared implementation of [`strsm`], [`dtrsm`], [`ctrsm`] and [`ztrsm`].
+///
+/// For complex types [`Trans::ConjTrans`] applies the conjugate transpose.
+pub fn trsm<T, A, B>(
+    side: Side,
+    uplo: Uplo,
+    transa: Trans,
+    diag: Diag,
+    m: i32,
+    n: i32,
+    alpha: T,
+    a: &A,
+    lda: i32,
+    b: &mut B,
+    ldb: i32,
+) -> Result<(), Info> where
+    T: Scalar,
+    A: ToFortranArray<T>,
+    B: ToFortranArrayMut<T>,
+{
+    let lside = side == Side::Left;
+    let nrowa = if lside { m } else { n };
+
+    let nounit = diag == Diag::NonUnit;
+    let upper = uplo == Uplo::Upper;
+    let conja = transa == Trans::ConjTrans;
+    let illegal = |position, name, value: &dyn Debug| {
+        illegal_argument(format!("{}TRSM", T::PREFIX), position, name, value)
+    };
+
+    if m < 0 {
+        return illegal(5, "m", &m);
+    } else if n < 0 {
+        return illegal(6, "n", &n);
+    } else if lda < 1.max(nrowa) {
+        return illegal(9, "lda", &lda);
+    } else if ldb < 1.max(m) {
+        return illegal(11, "ldb", &ldb);
+    }
+
+    if m == 0 || n == 0 {
+        return Ok(());
+    }
+
+    let a_f = &a.fa_view_2d(lda);
+    let b_f = &mut b.fa_view_2d_mut(ldb);
+
+    if alpha.is_zero() {
+        for j in 1..=n {
+            for i in 1..=m {
+                b_f[(i, j)] = T::zero();
+            }
+        }
+        return Ok(());
+    }
+
+    if lside {
+        if transa == Trans::NoTrans {
+            // Form  B := alpha*inv( A )*B.
+            if upper {
+                for j in 1..=n {
+                    if !alpha.is_one() {
+                        for i in 1..=m {
+                            b_f[(i, j)] *= alpha;
+                        }
+                    }
+                    for k in (1..=m).rev() {
+                        if b_f[(k, j)] != T::zero() {
+                            if nounit {
+                                b_f[(k, j)] /= a_f[(k, k)];
+                            }
+                            let bkj = b_f[(k, j)];
+                            for i in 1..k {
+                                b_f[(i, j)] -= bkj * a_f[(i, k)];
+                            }
+                        }
+                    }
+                }
+            } else {
+                for j in 1..=n {
+                    if !alpha.is_one() {
+                        for i in 1..=m {
+                            b_f[(i, j)] *= alpha;
+                        }
+                    }
+                    for k in 1..=m {
+                        if b_f[(k, j)] != T::zero() {
+                            if nounit {
+                                b_f[(k, j)] /= a_f[(k, k)];
+                            }
+                            let bkj = b_f[(k, j)];
+                            for i in k + 1..=m {
+                                b_f[(i, j)] -= bkj * a_f[(i, k)];
+                            }
+                        }
+                    }
+                }
+            }
+        } else {
+            // Form  B := alpha*inv( A**T )*B  or  B := alpha*inv( A**H )*B.
+            if upper {
+                for j in 1..=n {
+                    for i in 1..=m {
+                        let mut temp = alpha * b_f[(i, j)];
+                        for k in 1..i {
+                            temp -= conj_if(conja, a_f[(k, i)]) * b_f[(k, j)];
+                        }
+                        if nounit {
+                            temp /= conj_if(conja, a_f[(i, i)]);
+                        }
+                        b_f[(i, j)] = temp;
+                    }
+                }
+            } else {
+                for j in 1..=n {
+                    for i in (1..=m).rev() {
+                        let mut temp = alpha * b_f[(i, j)];
+                        for k in i + 1..=m {
+                            temp -= conj_if(conja, a_f[(k, i)]) * b_f[(k, j)];
+                        }
+                        if nounit {
+                            temp /= conj_if(conja, a_f[(i, i)]);
+                        }
+                        b_f[(i, j)] = temp;
+                    }
+                }
+            }
+        }
+    } else {
+        if transa == Trans::NoTrans {
+            // Form  B := alpha*B*inv( A ).
+            if upper {
+                for j in 1..=n {
+                    if !alpha.is_one() {
+                        for i in 1..=m {
+                            b_f[(i, j)] *= alpha;
+                        }
+                    }
+                    for k in 1..j {
+                        if a_f[(k, j)] != T::zero() {
+                            let temp = a_f[(k, j)];
+                            for i in 1..=m {
+                                let bik = b_f[(i, k)];
+                                b_f[(i, j)] -= temp * bik;
+                            }
+                        }
+                    }
+                    if nounit {
+                        let temp = T::one() / a_f[(j, j)];
+                        for i in 1..=m {
+                            b_f[(i, j)] *= temp;
+                        }
+                    }
+                }
+            } else {
+                for j in (1..=n).rev() {
+                    if !alpha.is_one() {
+                        for i in 1..=m {
+                            b_f[(i, j)] *= alpha;
+                        }
+                    }
+                    for k in j + 1..=n {
+                        if a_f[(k, j)] != T::zero() {
+                            let temp = a_f[(k, j)];
+                            for i in 1..=m {
+                                let bik = b_f[(i, k)];
+                                b_f[(i, j)] -= temp * bik;
+                            }
+                        }
+                    }
+                    if nounit {
+                        let temp = T::one() / a_f[(j, j)];
+                        for i in 1..=m {
+                            b_f[(i, j)] *= temp;
+                        }
+                    }
+                }
+            }
+        } else {
+            // Form  B := alpha*B*inv( A**T )  or  B := alpha*B*inv( A**H ).
+            if upper {
+                for k in (1..=n).rev() {
+                    if nounit {
+                        let temp = T::one() / conj_if(conja, a_f[(k, k)]);
+                        for i in 1..=m {
+                            b_f[(i, k)] *= temp;
+                        }
+                    }
+                    for j in 1..k {
+                        if a_f[(j, k)] != T::zero() {
+                            let temp = conj_if(conja, a_f[(j, k)]);
+                            for i in 1..=m {
+                                let bik = b_f[(i, k)];
+                                b_f[(i, j)] -= temp * bik;
+                            }
+                        }
+                    }
+                    if !alpha.is_one() {
+                        for i in 1..=m {
+                            b_f[(i, k)] *= alpha;
+                        }
+                    }
+                }
+            } else {
+                for k in 1..=n {
+                    if nounit {
+                        let temp = T::one() / conj_if(conja, a_f[(k, k)]);
+                        for i in 1..=m {
+                            b_f[(i, k)] *= temp;
+                        }
+                    }
+                    for j in k + 1..=n {
+                        if a_f[(j, k)] != T::zero() {
+                            let temp = conj_if(conja, a_f[(j, k)]);
+                            for i in 1..=m {
+                                let bik = b_f[(i, k)];
+                                b_f[(i, j)] -= temp * bik;
+                            }
+                        }
+                    }
+                    if !alpha.is_one() {
+                        for i in 1..=m {
+                            b_f[(i, k)] *= alpha;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blas::dtrmm::dtrmm;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Side::Left, Uplo::Upper, Trans::NoTrans, Diag::NonUnit, 2, 2, 1., [[1., 2.], [0., 1.]], 2, [[3., 4.], [5., 6.]], 2, [[1., 2.], [0., 1.]], [[3., 4.], [5., 6.]])]
+    #[case(Side::Right, Uplo::Upper, Trans::NoTrans, Diag::Unit, 2, 2, 0.5, [[1., 0.], [2., 1.]], 2, [[2., 4.], [10., 16.]], 2, [[1., 0.], [2., 1.]], [[1., 2.], [3., 4.]])]
+    #[case(Side::Left, Uplo::Lower, Trans::Trans, Diag::NonUnit, 2, 2, 1. / 3., [[1., 0.], [1., 2.]], 2, [[3., 12.], [9., 24.]], 2, [[1., 0.], [1., 2.]], [[1., 2.], [3., 4.]])]
+    #[case(Side::Right, Uplo::Lower, Trans::NoTrans, Diag::NonUnit, 2, 2, 0.5, [[1., 1.], [0., 2.]], 2, [[8., 12.], [12., 16.]], 2, [[1., 1.], [0., 2.]], [[1., 2.], [3., 4.]])]
+    #[case(Side::Left, Uplo::Upper, Trans::NoTrans, Diag::Unit, 2, 2, 1., [[1., 2.], [0., 1.]], 2, [[0., 0.], [0., 0.]], 2, [[1., 2.], [0., 1.]], [[0., 0.], [0., 0.]])]
+    #[case(Side::Right, Uplo::Upper, Trans::Trans, Diag::NonUnit, 2, 2, 0., [[1., 0.], [2., 1.]], 2, [[1., 2.], [3., 4.]], 2, [[1., 0.], [2., 1.]], [[0., 0.], [0., 0.]])]
+    fn test_dtrsm(
+        #[case] side: Side,
+        #[case] uplo: Uplo,
+        #[case] transa: Trans,
+        #[case] diag: Diag,
+        #[case] m: i32,
+        #[case] n: i32,
+        #[case] alpha: f64,
+        #[case] a: [[f64; 2]; 2],
+        #[case] lda: i32,
+        #[case] b: [[f64; 2]; 2],
+        #[case] ldb: i32,
+        #[case] expected_a: [[f64; 2]; 2],
+        #[case] expected_b: [[f64; 2]; 2],
+    ) {
+        let a_mut = &mut a.iter().map(|r| r.to_vec()).collect::<Vec<Vec<f64>>>();
+        let b_mut = &mut b.iter().map(|r| r.to_vec()).collect::<Vec<Vec<f64>>>();
+
+        let expected_a = expected_a.iter().map(|r| r.to_vec()).collect::<Vec<Vec<f64>>>();
+        let expected_b = expected_b.iter().map(|r| r.to_vec()).collect::<Vec<Vec<f64>>>();
+
+        dtrsm(side, uplo, transa, diag, m, n, alpha, a_mut, lda, b_mut, ldb).unwrap();
+
+        assert_eq!(expected_a, a_mut.clone());
+        assert_eq!(expected_b, b_mut.clone());
+    }
+
+    #[rstest]
+    fn test_dtrsm_inverts_dtrmm(
+        #[values(Side::Left, Side::Right)] side: Side,
+        #[values(Uplo::Upper, Uplo::Lower)] uplo: Uplo,
+        #[values(Trans::NoTrans, Trans::Trans, Trans::ConjTrans)] transa: Trans,
+        #[values(Diag::NonUnit, Diag::Unit)] diag: Diag,
+    ) {
+        let a = vec![2., 4., 1., -1., 5., 6., 3., -2., 7.];
+        let b = vec![1., -2., 3., 4., -5., 6., 7., 8., -9.];
+
+        // Solving op( A )*X = 2*B and multiplying back by op( A ) gives 2*B.
+        let x = &mut b.clone();
+        dtrsm(side, uplo, transa, diag, 3, 3, 2., &a, 3, x, 3).unwrap();
+        dtrmm(side, uplo, transa, diag, 3, 3, 1., &a, 3, x, 3).unwrap();
+        for (b, x) in b.iter().zip(x.iter()) {
+            assert!((2. * b - x).abs() <= 1e-13 * b.abs());
+        }
+    }
+
+    #[test]
+    fn test_ztrsm_conjugate_transpose() {
+        let (zero, one, i) = (Complex64::new(0., 0.), Complex64::new(1., 0.), Complex64::new(0., 1.));
+        let a = vec![vec![one, zero], vec![i, one + one]];
+        let b = &mut vec![vec![one, -i], vec![zero, one + one]];
+        ztrsm(Side::Left, Uplo::Upper, Trans::ConjTrans, Diag::NonUnit, 2, 2, one, &a, 2, b, 2).unwrap();
+        assert_eq!(vec![vec![one, zero], vec![zero, one]], b.clone());
+    }
+
+    #[test]
+    fn test_trsm_illegal_argument() {
+        let Err(Info::IllegalArgument(err)) = strsm(Side::Right, Uplo::Lower, Trans::NoTrans, Diag::NonUnit, 2, 3, 1., &vec![1f32; 9], 2, &mut vec![1f32; 6], 2) else {
+            panic!("expected an illegal argument");
+        };
+        assert_eq!(("STRSM".to_string(), 9, "lda"), (err.routine, err.position, err.name));
+        assert_eq!(-6, dtrsm(Side::Left, Uplo::Upper, Trans::NoTrans, Diag::NonUnit, 2, -1, 1., &vec![1.; 4], 2, &mut vec![1.; 4], 2).unwrap_err().info());
+        assert_eq!(-11, dtrsm(Side::Left, Uplo::Upper, Trans::NoTrans, Diag::NonUnit, 2, 2, 1., &vec![1.; 4], 2, &mut vec![1.; 4], 1).unwrap_err().info());
+    }
+}
@@ -0,0 +1,279 @@
+use std::fmt::Debug;
+
+use crate::array::convert::{ToFortranArray, ToFortranArrayMut};
+use crate::error::Info;
+use crate::flags::{Diag, Trans, Uplo};
+use crate::scalar::{conj_if, Complex32, Complex64, Scalar};
+use crate::xerbla::illegal_argument;
+
+/// DTRSV
+///
+/// # Documentation
+///
+/// [Original] Online html documentation available at
+/// [http://www.netlib.org/lapack/explore-html/](http://www.netlib.org/lapack/explore-html/)
+///
+/// Solves one of the systems of equations
+///
+/// ```text
+/// A*x = b,   or   A**T*x = b,
+/// ```
+///
+/// where `b` and `x` are `n` element vectors and `A` is an `n` by `n` unit, or
+/// non-unit, upper or lower triangular matrix. On entry `x` holds `b` and it is
+/// overwritten by the solution.
+///
+/// No test for singularity or near-singularity is included in this routine.
+/// Such tests must be performed before calling this routine.
+///
+/// # Arguments
+///
+/// For arguments definitions, please refer to the original documentation.
+pub fn dtrsv<A, X>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: i32,
+    a: &A,
+    lda: i32,
+    x: &mut X,
+    incx: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray,
+    X: ToFortranArrayMut,
+{
+    trsv(uplo, trans, diag, n, a, lda, x, incx)
+}
+
+/// STRSV
+///
+/// Single precision variant of [`dtrsv`].
+pub fn strsv<A, X>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: i32,
+    a: &A,
+    lda: i32,
+    x: &mut X,
+    incx: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<f32>,
+    X: ToFortranArrayMut<f32>,
+{
+    trsv(uplo, trans, diag, n, a, lda, x, incx)
+}
+
+/// CTRSV
+///
+/// Single precision complex variant of [`dtrsv`].
+pub fn ctrsv<A, X>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: i32,
+    a: &A,
+    lda: i32,
+    x: &mut X,
+    incx: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<Complex32>,
+    X: ToFortranArrayMut<Complex32>,
+{
+    trsv(uplo, trans, diag, n, a, lda, x, incx)
+}
+
+/// ZTRSV
+///
+/// Double precision complex variant of [`dtrsv`].
+pub fn ztrsv<A, X>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: i32,
+    a: &A,
+    lda: i32,
+    x: &mut X,
+    incx: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<Complex64>,
+    X: ToFortranArrayMut<Complex64>,
+{
+    trsv(uplo, trans, diag, n, a, lda, x, incx)
+}
+
+/// Shared implementation of [`strsv`], [`dtrsv`], [`ctrsv`] and [`ztrsv`].
+///
+/// For complex types [`Trans::ConjTrans`] applies the conjugate transpose.
+pub fn trsv<T, A, X>(
+    uplo: Uplo,
+    trans: Trans,
+    diag: Diag,
+    n: i32,
+    a: &A,
+    lda: i32,
+    x: &mut X,
+    incx: i32,
+) -> Result<(), Info> where
+    T: Scalar,
+    A: ToFortranArray<T>,
+    X: ToFortranArrayMut<T>,
+{
+    let nounit = diag == Diag::NonUnit;
+    let upper = uplo == Uplo::Upper;
+    let conj = trans == Trans::ConjTrans;
+    let illegal = |position, name, value: &dyn Debug| {
+        illegal_argument(format!("{}TRSV", T::PREFIX), position, name, value)
+    };
+
+    if n < 0 {
+        return illegal(4, "n", &n);
+    } else if lda < 1.max(n) {
+        return illegal(6, "lda", &lda);
+    } else if incx == 0 {
+        return illegal(8, "incx", &incx);
+    }
+
+    // Quick return if possible.
+    if n == 0 {
+        return Ok(());
+    }
+
+    let a_f = &a.fa_view_2d(lda);
+    let x_f = &mut x.fa_view_mut();
+
+    // Set up the start point in x if the increment is not unity.
+    let mut kx = if incx > 0 { 1 } else { 1 - (n - 1) * incx };
+
+    // Start the operations. In this version the elements of A are accessed
+    // sequentially with one pass through A.
+    if trans == Trans::NoTrans {
+        // Form  x := inv( A )*x.
+        if upper {
+            let mut jx = kx + (n - 1) * incx;
+            for j in (1..=n).rev() {
+                if !x_f[jx].is_zero() {
+                    if nounit {
+                        x_f[jx] /= a_f[(j, j)];
+                    }
+                    let temp = x_f[jx];
+                    let mut ix = jx;
+                    for i in (1..j).rev() {
+                        ix -= incx;
+                        x_f[ix] -= temp * a_f[(i, j)];
+                    }
+                }
+                jx -= incx;
+            }
+        } else {
+            let mut jx = kx;
+            for j in 1..=n {
+                if !x_f[jx].is_zero() {
+                    if nounit {
+                        x_f[jx] /= a_f[(j, j)];
+                    }
+                    let temp = x_f[jx];
+                    let mut ix = jx;
+                    for i in j + 1..=n {
+                        ix += incx;
+                        x_f[ix] -= temp * a_f[(i, j)];
+                    }
+                }
+                jx += incx;
+            }
+        }
+    } else {
+        // Form  x := inv( A**T )*x  or  x := inv( A**H )*x.
+        if upper {
+            let mut jx = kx;
+            for j in 1..=n {
+                let mut temp = x_f[jx];
+                let mut ix = kx;
+                for i in 1..j {
+                    temp -= conj_if(conj, a_f[(i, j)]) * x_f[ix];
+                    ix += incx;
+                }
+                if nounit {
+                    temp /= conj_if(conj, a_f[(j, j)]);
+                }
+                x_f[jx] = temp;
+                jx += incx;
+            }
+        } else {
+            kx += (n - 1) * incx;
+            let mut jx = kx;
+            for j in (1..=n).rev() {
+                let mut temp = x_f[jx];
+                let mut ix = kx;
+                for i in (j + 1..=n).rev() {
+                    temp -= conj_if(conj, a_f[(i, j)]) * x_f[ix];
+                    ix -= incx;
+                }
+                if nounit {
+                    temp /= conj_if(conj, a_f[(j, j)]);
+                }
+                x_f[jx] = temp;
+                jx -= incx;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blas::dgemv::dgemv;
+    use crate::error::LapackError;
+    use crate::blas::fixtures::{stored_triangular, triangular};
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_dtrsv(
+        #[values(Uplo::Upper, Uplo::Lower)] uplo: Uplo,
+        #[values(Trans::NoTrans, Trans::Trans, Trans::ConjTrans)] trans: Trans,
+        #[values(Diag::NonUnit, Diag::Unit)] diag: Diag,
+        #[values(1, -1, 2, -2)] incx: i32,
+    ) {
+        let b = vec![1., -2., 3., 4., -5.];
+
+        let x = &mut b.clone();
+        dtrsv(uplo, trans, diag, 3, &stored_triangular(uplo, diag), 3, x, incx).unwrap();
+
+        // Multiplying the solution back by op( A ) recovers b, and the elements of x
+        // outside the increment are left alone.
+        let actual = &mut x.clone();
+        dgemv(trans, 3, 3, 1., &triangular(uplo, diag), 3, x, incx, 0., actual, incx).unwrap();
+        for (b, actual) in b.iter().zip(actual.iter()) {
+            assert!((b - actual).abs() <= 1e-14 * b.abs());
+        }
+    }
+
+    #[test]
+    fn test_ztrsv_conjugate_transpose() {
+        let (zero, one, i) = (Complex64::new(0., 0.), Complex64::new(1., 0.), Complex64::new(0., 1.));
+        let a = vec![one, zero, i, one + one];
+        let x = &mut vec![one, one + one - i];
+        ztrsv(Uplo::Upper, Trans::ConjTrans, Diag::NonUnit, 2, &a, 2, x, 1).unwrap();
+        assert_eq!(vec![one, one], *x);
+    }
+
+    #[rstest]
+    #[case(-1, 3, 1, 4, "n", "-1")]
+    #[case(3, 2, 1, 6, "lda", "2")]
+    #[case(3, 3, 0, 8, "incx", "0")]
+    fn test_dtrsv_illegal_argument(
+        #[case] n: i32,
+        #[case] lda: i32,
+        #[case] incx: i32,
+        #[case] position: i32,
+        #[case] name: &'static str,
+        #[case] value: &str,
+    ) {
+        let x = &mut vec![1.; 3];
+        let expected = LapackError { routine: "DTRSV".to_string(), position, name, value: value.to_string() };
+        assert_eq!(Err(Info::IllegalArgument(expected)), dtrsv(Uplo::Lower, Trans::Trans, Diag::Unit, n, &vec![1.; 9], lda, x, incx));
+        assert_eq!(vec![1.; 3], *x);
+    }
+}
@@ -0,0 +1,48 @@
+use crate::flags::{Diag, Uplo};
+
+// The leading k by k block of S = [4 1 2; 1 5 3; 2 3 6], column by column; k is at most 3.
+pub(super) fn symmetric(k: usize) -> Vec<f64> {
+    const S: [f64; 9] = [4., 1., 2., 1., 5., 3., 2., 3., 6.];
+    (0..k * k).map(|p| S[p % k + 3 * (p / k)]).collect()
+}
+
+// The uplo triangle of the k by k symmetric `full`, with 99 in the triangle that must not be
+// referenced.
+pub(super) fn stored_symmetric(uplo: Uplo, full: &[f64], k: usize) -> Vec<f64> {
+    full.iter().enumerate().map(|(p, &s)| {
+        let (i, j) = (p % k, p / k);
+        if (uplo == Uplo::Upper && i > j) || (uplo == Uplo::Lower && i < j) { 99. } else { s }
+    }).collect()
+}
+
+// The uplo triangle of G = [2 -1 3; 4 5 -2; 1 6 7], zero elsewhere and with a unit diagonal for
+// Diag::Unit.
+pub(super) fn triangular(uplo: Uplo, diag: Diag) -> Vec<f64> {
+    const G: [f64; 9] = [2., 4., 1., -1., 5., 6., 3., -2., 7.];
+    (0..9).map(|p| {
+        let (i, j) = (p % 3, p / 3);
+        if i == j { if diag == Diag::Unit { 1. } else { G[p] } }
+        else if (uplo == Uplo::Upper) == (i < j) { G[p] }
+        else { 0. }
+    }).collect()
+}
+
+// The same triangle as stored, with 99 wherever the routine must not read.
+pub(super) fn stored_triangular(uplo: Uplo, diag: Diag) -> Vec<f64> {
+    let a = triangular(uplo, diag);
+    (0..9).map(|p| {
+        let (i, j) = (p % 3, p / 3);
+        let referenced = if i == j { diag == Diag::NonUnit } else { (uplo == Uplo::Upper) == (i < j) };
+        if referenced { a[p] } else { 99. }
+    }).collect()
+}
+
+// `len` values in [-2, 2] that repeat every 17 elements; different seeds give shifted copies.
+pub(super) fn values(len: usize, seed: usize) -> Vec<f64> {
+    (0..len).map(|p| ((p * 7 + seed * 13) % 17) as f64 / 4. - 2.).collect()
+}
+
+// [`values`] with a small ramp added, so that no two elements are equal.
+pub(super) fn distinct_values(len: usize, seed: usize) -> Vec<f64> {
+    values(len, seed).into_iter().enumerate().map(|(p, x)| x + p as f64 * 1e-3).collect()
+}
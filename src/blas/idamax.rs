@@ -0,0 +1,121 @@
+use crate::array::convert::ToFortranArray;
+use crate::scalar::{Complex32, Complex64, Scalar};
+
+/// IDAMAX
+///
+/// # Documentation
+///
+/// [Original] Online html documentation available at
+/// [http://www.netlib.org/lapack/explore-html/](http://www.netlib.org/lapack/explore-html/)
+///
+/// Finds the index of the first element having maximum absolute value.
+/// As in the reference, it is zero when `n < 1` or `incx <= 0`.
+///
+/// # Arguments
+///
+/// * `n` - number of elements in input vector
+/// * `dx` - array
+/// * `incx` - storage spacing between elements of dx
+pub fn idamax<DX>(
+    n: i32,
+    dx: &DX,
+    incx: i32,
+) -> i32 where
+    DX: ToFortranArray,
+{
+    iamax(n, dx, incx)
+}
+
+/// ISAMAX
+///
+/// Single precision variant of [`idamax`].
+pub fn isamax<DX>(
+    n: i32,
+    dx: &DX,
+    incx: i32,
+) -> i32 where
+    DX: ToFortranArray<f32>,
+{
+    iamax(n, dx, incx)
+}
+
+/// ICAMAX
+///
+/// Single precision complex variant of [`idamax`], comparing `|re| + |im|`.
+pub fn icamax<DX>(
+    n: i32,
+    dx: &DX,
+    incx: i32,
+) -> i32 where
+    DX: ToFortranArray<Complex32>,
+{
+    iamax(n, dx, incx)
+}
+
+/// IZAMAX
+///
+/// Double precision complex variant of [`idamax`], comparing `|re| + |im|`.
+pub fn izamax<DX>(
+    n: i32,
+    dx: &DX,
+    incx: i32,
+) -> i32 where
+    DX: ToFortranArray<Complex64>,
+{
+    iamax(n, dx, incx)
+}
+
+/// Shared implementation of [`isamax`], [`idamax`], [`icamax`] and [`izamax`].
+pub fn iamax<T, DX>(
+    n: i32,
+    dx: &DX,
+    incx: i32,
+) -> i32 where
+    T: Scalar,
+    DX: ToFortranArray<T>,
+{
+    let dx = dx.fa_view();
+
+    if n < 1 || incx <= 0 { return 0; }
+    if n == 1 { return 1; }
+
+    let mut iamax = 1;
+    let mut dmax = dx[1].abs1();
+    let mut ix = 1 + incx;
+    for i in 2..=n {
+        if dx[ix].abs1() > dmax {
+            iamax = i;
+            dmax = dx[ix].abs1();
+        }
+        ix += incx;
+    }
+    iamax
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0, vec![1.], 1, 0)]
+    #[case(1, vec![-5.], 1, 1)]
+    #[case(4, vec![1., -7., 3., 7.], 1, 2)]
+    #[case(3, vec![1., 9., -2., 9., 4.], 2, 3)]
+    #[case(3, vec![1., -2., 3.], -1, 0)]
+    fn test_idamax(
+        #[case] n: i32,
+        #[case] dx: Vec<f64>,
+        #[case] incx: i32,
+        #[case] expected: i32,
+    ) {
+        assert_eq!(expected, idamax(n, &dx, incx));
+    }
+
+    #[test]
+    fn test_izamax() {
+        // |re| + |im| picks the second element, the modulus would pick the first.
+        let x = vec![Complex64::new(3., 0.), Complex64::new(2., -2.)];
+        assert_eq!(2, izamax(2, &x, 1));
+    }
+}
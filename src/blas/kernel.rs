@@ -0,0 +1,278 @@
+use crate::array::view::{FortranView, FortranViewMut};
+use crate::blas::simd::{self, simd_level, SimdLevel};
+use crate::flags::Trans;
+use crate::scalar::{conj_if, Scalar};
+
+/// Rows of `C` computed by one call of the portable microkernel.
+pub(crate) const MR: usize = 8;
+
+/// Columns of `C` computed by one call of the portable microkernel.
+pub(crate) const NR: usize = 4;
+
+/// Cache blocking of the packed `C := alpha*op( A )*op( B ) + C` update.
+///
+/// An `mc` by `kc` block of `op( A )` is packed to stay in the L2 cache, and a `kc` by `nc`
+/// panel of `op( B )` is packed to stay in the L3 cache while every block of `A` streams
+/// past it. The microkernel then keeps an `MR` by `NR` tile of `C` in registers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Blocking {
+    pub(crate) mc: usize,
+    pub(crate) kc: usize,
+    pub(crate) nc: usize,
+}
+
+impl Blocking {
+    pub(crate) const DEFAULT: Blocking = Blocking { mc: 128, kc: 256, nc: 4096 };
+}
+
+/// `C := alpha*op( A )*op( B ) + C` for an `m` by `n` matrix `C` and inner dimension `k`.
+///
+/// `C` is expected to be scaled by `beta` already. `op( A )` and `op( B )` are read once
+/// per block while packing, so the views keep their usual bounds handling, and the
+/// products are accumulated in packed buffers before a single update of each element of `C`
+/// per `kc` panel.
+///
+/// The microkernel is picked from [`simd_level`]; double precision uses the vector kernels
+/// of [`simd`] when the CPU has them.
+pub(crate) fn gemm_packed<T: Scalar>(
+    transa: Trans,
+    transb: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a_f: &FortranView<T>,
+    b_f: &FortranView<T>,
+    c_f: &mut FortranViewMut<T>,
+    blocking: Blocking,
+) {
+    gemm_packed_at(simd_level(), transa, transb, m, n, k, alpha, a_f, b_f, c_f, blocking)
+}
+
+/// [`gemm_packed`] with the microkernel of `level`, which the running CPU must support.
+pub(crate) fn gemm_packed_at<T: Scalar>(
+    level: SimdLevel,
+    transa: Trans,
+    transb: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a_f: &FortranView<T>,
+    b_f: &FortranView<T>,
+    c_f: &mut FortranViewMut<T>,
+    blocking: Blocking,
+) {
+    match level {
+        #[cfg(target_arch = "x86_64")]
+        SimdLevel::Avx512 if simd::is_f64::<T>() => {
+            blocked(transa, transb, m, n, k, alpha, a_f, b_f, c_f, blocking, simd::gemm_avx512::<T>)
+        }
+        #[cfg(target_arch = "x86_64")]
+        SimdLevel::Avx2Fma if simd::is_f64::<T>() => {
+            blocked(transa, transb, m, n, k, alpha, a_f, b_f, c_f, blocking, simd::gemm_avx2::<T>)
+        }
+        _ => blocked(transa, transb, m, n, k, alpha, a_f, b_f, c_f, blocking, micro_kernel::<T, MR, NR>),
+    }
+}
+
+/// The blocked loops around a microkernel computing `MR` by `NR` tiles.
+fn blocked<T: Scalar, const MR: usize, const NR: usize>(
+    transa: Trans,
+    transb: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a_f: &FortranView<T>,
+    b_f: &FortranView<T>,
+    c_f: &mut FortranViewMut<T>,
+    blocking: Blocking,
+    kernel: impl Fn(&[T], &[T]) -> [[T; MR]; NR],
+) {
+    let Blocking { mc, kc, nc } = blocking;
+    let mut a_pack = vec![T::zero(); mc.min(m).next_multiple_of(MR) * kc.min(k)];
+    let mut b_pack = vec![T::zero(); nc.min(n).next_multiple_of(NR) * kc.min(k)];
+
+    for jc in (0..n).step_by(nc) {
+        let nb = nc.min(n - jc);
+        for pc in (0..k).step_by(kc) {
+            let kb = kc.min(k - pc);
+            pack_b::<T, NR>(transb, b_f, pc, jc, kb, nb, &mut b_pack);
+            for ic in (0..m).step_by(mc) {
+                let mb = mc.min(m - ic);
+                pack_a::<T, MR>(transa, a_f, ic, pc, mb, kb, &mut a_pack);
+                macro_kernel(mb, nb, kb, alpha, &a_pack, &b_pack, c_f, ic, jc, &kernel);
+            }
+        }
+    }
+}
+
+/// Packs the `mb` by `kb` block of `op( A )` at `(ic, pc)` into slivers of `MR` rows, each
+/// stored column after column and padded with zeros past the last row.
+fn pack_a<T: Scalar, const MR: usize>(
+    transa: Trans,
+    a_f: &FortranView<T>,
+    ic: usize,
+    pc: usize,
+    mb: usize,
+    kb: usize,
+    a_pack: &mut [T],
+) {
+    let conj = transa == Trans::ConjTrans;
+    for (s, sliver) in a_pack.chunks_exact_mut(MR * kb).take(mb.div_ceil(MR)).enumerate() {
+        for (l, column) in sliver.chunks_exact_mut(MR).enumerate() {
+            for (r, packed) in column.iter_mut().enumerate() {
+                let i = s * MR + r;
+                *packed = if i >= mb {
+                    T::zero()
+                } else {
+                    let (i, l) = ((ic + i + 1) as i32, (pc + l + 1) as i32);
+                    if transa == Trans::NoTrans { a_f[(i, l)] } else { conj_if(conj, a_f[(l, i)]) }
+                };
+            }
+        }
+    }
+}
+
+/// Packs the `kb` by `nb` panel of `op( B )` at `(pc, jc)` into slivers of `NR` columns, each
+/// stored row after row and padded with zeros past the last column.
+fn pack_b<T: Scalar, const NR: usize>(
+    transb: Trans,
+    b_f: &FortranView<T>,
+    pc: usize,
+    jc: usize,
+    kb: usize,
+    nb: usize,
+    b_pack: &mut [T],
+) {
+    let conj = transb == Trans::ConjTrans;
+    for (s, sliver) in b_pack.chunks_exact_mut(NR * kb).take(nb.div_ceil(NR)).enumerate() {
+        for (l, row) in sliver.chunks_exact_mut(NR).enumerate() {
+            for (c, packed) in row.iter_mut().enumerate() {
+                let j = s * NR + c;
+                *packed = if j >= nb {
+                    T::zero()
+                } else {
+                    let (l, j) = ((pc + l + 1) as i32, (jc + j + 1) as i32);
+                    if transb == Trans::NoTrans { b_f[(l, j)] } else { conj_if(conj, b_f[(j, l)]) }
+                };
+            }
+        }
+    }
+}
+
+/// Multiplies a packed block of `A` by a packed panel of `B` one `MR` by `NR` tile at a time
+/// and adds `alpha` times each tile to `C` at `(ic, jc)`.
+fn macro_kernel<T: Scalar, const MR: usize, const NR: usize>(
+    mb: usize,
+    nb: usize,
+    kb: usize,
+    alpha: T,
+    a_pack: &[T],
+    b_pack: &[T],
+    c_f: &mut FortranViewMut<T>,
+    ic: usize,
+    jc: usize,
+    kernel: &impl Fn(&[T], &[T]) -> [[T; MR]; NR],
+) {
+    for (jr, b_sliver) in b_pack.chunks_exact(NR * kb).take(nb.div_ceil(NR)).enumerate() {
+        let nr = NR.min(nb - jr * NR);
+        for (ir, a_sliver) in a_pack.chunks_exact(MR * kb).take(mb.div_ceil(MR)).enumerate() {
+            let mr = MR.min(mb - ir * MR);
+            let ab = kernel(a_sliver, b_sliver);
+            for (j, column) in ab.iter().enumerate().take(nr) {
+                let j = (jc + jr * NR + j + 1) as i32;
+                for (i, &value) in column.iter().enumerate().take(mr) {
+                    c_f[((ic + ir * MR + i + 1) as i32, j)] += alpha * value;
+                }
+            }
+        }
+    }
+}
+
+/// `a*b` for an `MR` by `kb` sliver `a` and a `kb` by `NR` sliver `b`, both packed.
+#[inline]
+fn micro_kernel<T: Scalar, const MR: usize, const NR: usize>(a: &[T], b: &[T]) -> [[T; MR]; NR] {
+    let mut ab = [[T::zero(); MR]; NR];
+    for (a, b) in a.chunks_exact(MR).zip(b.chunks_exact(NR)) {
+        let a: &[T; MR] = a.try_into().unwrap();
+        for (column, &b) in ab.iter_mut().zip(b) {
+            for (ab, &a) in column.iter_mut().zip(a) {
+                *ab += a * b;
+            }
+        }
+    }
+    ab
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    use crate::blas::fixtures::values;
+    use crate::scalar::Complex64;
+
+    // The textbook triple loop, C := alpha*op( A )*op( B ) + C.
+    fn reference<T: Scalar>(transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: T, a: &[T], lda: usize, b: &[T], ldb: usize, c: &mut [T]) {
+        let op = |trans: Trans, x: &[T], ld: usize, i: usize, j: usize| match trans {
+            Trans::NoTrans => x[i + j * ld],
+            Trans::Trans => x[j + i * ld],
+            Trans::ConjTrans => x[j + i * ld].conj(),
+        };
+        for j in 0..n {
+            for i in 0..m {
+                let mut temp = T::zero();
+                for l in 0..k {
+                    temp += op(transa, a, lda, i, l) * op(transb, b, ldb, l, j);
+                }
+                c[i + j * m] += alpha * temp;
+            }
+        }
+    }
+
+    #[rstest]
+    fn test_gemm_packed(
+        #[values(Trans::NoTrans, Trans::Trans)] transa: Trans,
+        #[values(Trans::NoTrans, Trans::ConjTrans)] transb: Trans,
+        #[values(Blocking::DEFAULT, Blocking { mc: 8, kc: 5, nc: 4 }, Blocking { mc: 16, kc: 3, nc: 12 })] blocking: Blocking,
+        #[values(SimdLevel::Scalar, SimdLevel::Avx2Fma, SimdLevel::Avx512)] level: SimdLevel,
+    ) {
+        if level > simd_level() { return; }
+        let (m, n, k) = (19, 13, 11);
+        let (a_rows, a_cols) = if transa == Trans::NoTrans { (m, k) } else { (k, m) };
+        let (b_rows, b_cols) = if transb == Trans::NoTrans { (k, n) } else { (n, k) };
+        let (a, b) = (values(a_rows * a_cols, 1), values(b_rows * b_cols, 2));
+
+        let expected = &mut values(m * n, 3);
+        reference(transa, transb, m, n, k, 0.5, &a, a_rows, &b, b_rows, expected);
+
+        let actual = &mut values(m * n, 3);
+        let a_f = FortranView::new(&a, a_rows as i32, a_cols as i32, a_rows as i32);
+        let b_f = FortranView::new(&b, b_rows as i32, b_cols as i32, b_rows as i32);
+        gemm_packed_at(level, transa, transb, m, n, k, 0.5, &a_f, &b_f, &mut FortranViewMut::new(actual, m as i32, n as i32, m as i32), blocking);
+        for (e, a) in expected.iter().zip(actual.iter()) {
+            assert!((e - a).abs() <= 1e-13 * e.abs().max(1.));
+        }
+    }
+
+    #[test]
+    fn test_gemm_packed_complex() {
+        let (m, n, k) = (5, 6, 7);
+        let a: Vec<Complex64> = values(2 * m * k, 4).chunks(2).map(|p| Complex64::new(p[0], p[1])).collect();
+        let b: Vec<Complex64> = values(2 * k * n, 5).chunks(2).map(|p| Complex64::new(p[0], p[1])).collect();
+        let alpha = Complex64::new(0.5, -1.);
+
+        let expected = &mut vec![Complex64::new(1., 1.); m * n];
+        reference(Trans::ConjTrans, Trans::NoTrans, m, n, k, alpha, &a, k, &b, k, expected);
+
+        let actual = &mut vec![Complex64::new(1., 1.); m * n];
+        let a_f = FortranView::new(&a, k as i32, m as i32, k as i32);
+        let b_f = FortranView::new(&b, k as i32, n as i32, k as i32);
+        gemm_packed(Trans::ConjTrans, Trans::NoTrans, m, n, k, alpha, &a_f, &b_f, &mut FortranViewMut::new(actual, m as i32, n as i32, m as i32), Blocking { mc: 4, kc: 3, nc: 5 });
+        for (e, a) in expected.iter().zip(actual.iter()) {
+            assert!((e - a).norm() <= 1e-13 * e.norm().max(1.));
+        }
+    }
+}
@@ -1,7 +1,32 @@
+pub mod backend;
+pub mod dasum;
+pub mod daxpy;
 pub mod dcopy;
+pub mod ddot;
 pub mod dgemm;
+pub mod dgemv;
+pub mod dger;
 pub mod dnrm2;
 pub mod drot;
+pub mod drotg;
+pub mod drotm;
+pub mod drotmg;
 pub mod dscal;
+pub mod dswap;
+pub mod dsymm;
+pub mod dsymv;
+pub mod dsyr;
+pub mod dsyr2;
+pub mod dsyr2k;
+pub mod dsyrk;
 pub mod dtrmm;
+pub mod dtrmv;
+pub mod dtrsm;
+pub mod dtrsv;
+#[cfg(test)]
+mod fixtures;
+pub mod idamax;
+pub(crate) mod kernel;
 pub mod lsame;
+pub mod parallel;
+pub mod simd;
@@ -0,0 +1,314 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+#[cfg(feature = "parallel")]
+use std::sync::{Arc, Mutex};
+
+use crate::array::view::{FortranView, FortranViewMut};
+use crate::blas::kernel::{gemm_packed, Blocking};
+use crate::flags::Trans;
+use crate::scalar::Scalar;
+
+/// Multiply-adds below which a level-3 product is not worth splitting further.
+const MIN_WORK: usize = 64 * 64 * 64;
+
+/// Fewest columns (or rows) handed to one thread.
+const MIN_BLOCK: usize = 16;
+
+/// Shortest inner dimension that [`dgemm`](crate::blas::dgemm::dgemm) splits in
+/// non-deterministic mode.
+const MIN_SPLIT_K: usize = 2 * Blocking::DEFAULT.kc;
+
+static THREADS: AtomicUsize = AtomicUsize::new(0);
+static DETERMINISTIC: AtomicBool = AtomicBool::new(false);
+
+/// Sets the number of threads used by the level-3 BLAS routines.
+///
+/// `0`, the default, uses rayon's pool of the calling context, which is the global pool unless
+/// called from within [`rayon::ThreadPool::install`]. Any other value runs the routines on a
+/// dedicated pool of that many threads.
+///
+/// Large [`dgemm`](crate::blas::dgemm::dgemm) products are split over column blocks of `C`,
+/// and large [`dtrmm`](crate::blas::dtrmm::dtrmm) products over column blocks of `B` (row
+/// blocks when `A` is applied from the right). Without the `parallel` feature every routine
+/// runs on the calling thread and this setting is ignored.
+pub fn set_num_threads(threads: usize) {
+    THREADS.store(threads, Ordering::Relaxed);
+}
+
+/// Number of threads the level-3 BLAS routines split their work over, see [`set_num_threads`].
+pub fn num_threads() -> usize {
+    resolve(THREADS.load(Ordering::Relaxed))
+}
+
+fn resolve(setting: usize) -> usize {
+    #[cfg(feature = "parallel")]
+    match setting {
+        0 => rayon::current_num_threads(),
+        threads => threads,
+    }
+    #[cfg(not(feature = "parallel"))]
+    { let _ = setting; 1 }
+}
+
+/// Makes the level-3 BLAS routines return bitwise-identical results for any number of threads.
+///
+/// Splitting over blocks of the output is always reproducible, since every element is computed
+/// by the same operations in the same order. When `C` is too narrow to give every thread a
+/// block, [`dgemm`](crate::blas::dgemm::dgemm) otherwise also splits the inner dimension and
+/// sums the partial products, whose rounding depends on the number of threads. Deterministic
+/// mode never does so. It is off by default.
+pub fn set_deterministic(deterministic: bool) {
+    DETERMINISTIC.store(deterministic, Ordering::Relaxed);
+}
+
+/// Whether deterministic mode is on, see [`set_deterministic`].
+pub fn is_deterministic() -> bool {
+    DETERMINISTIC.load(Ordering::Relaxed)
+}
+
+/// Threading settings of one call.
+///
+/// They are read once, so the work is split for the same pool it runs on even if the settings
+/// change meanwhile.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Config {
+    pub(crate) threads: usize,
+    /// Whether `threads` is a dedicated pool rather than the calling context's.
+    #[cfg_attr(not(feature = "parallel"), allow(dead_code))]
+    pub(crate) dedicated: bool,
+    pub(crate) deterministic: bool,
+}
+
+impl Config {
+    pub(crate) fn current() -> Config {
+        let setting = THREADS.load(Ordering::Relaxed);
+        Config { threads: resolve(setting), dedicated: setting != 0, deterministic: is_deterministic() }
+    }
+
+    /// Number of blocks to split `len` columns or rows into for `work` multiply-adds.
+    fn parts(&self, work: usize, len: usize) -> usize {
+        self.threads.min(work / MIN_WORK).min(len.div_ceil(MIN_BLOCK)).max(1)
+    }
+}
+
+/// `C := alpha*op( A )*op( B ) + C` like [`gemm_packed`], split over the threads of `config`.
+pub(crate) fn gemm_parallel<T: Scalar>(
+    config: Config,
+    transa: Trans,
+    transb: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a_f: &FortranView<T>,
+    b_f: &FortranView<T>,
+    c_f: &mut FortranViewMut<T>,
+) {
+    let work = m * n * k;
+    let max_parts = config.threads.min(work / MIN_WORK).max(1);
+    if !config.deterministic && config.parts(work, n) < max_parts && k >= MIN_SPLIT_K {
+        return gemm_split_k(config, max_parts.min(k / Blocking::DEFAULT.kc), transa, transb, m, n, k, alpha, a_f, b_f, c_f);
+    }
+
+    // `C` may be wider than `n`: only its leading `m` by `n` block is split and written.
+    let c_f = &mut c_f.sub_mut(1, 1, m as i32, n as i32);
+    for_each_column_block(config, work, c_f, |j0, c_block| {
+        let nb = c_block.cols();
+        let (j0, k) = (j0 as i32, k as i32);
+        let b_block = if transb == Trans::NoTrans { b_f.sub(1, j0 + 1, k, nb) } else { b_f.sub(j0 + 1, 1, nb, k) };
+        gemm_packed(transa, transb, m, nb as usize, k as usize, alpha, a_f, &b_block, c_block, Blocking::DEFAULT);
+    });
+}
+
+// Each part multiplies a slice of the inner dimension into its own buffer; the buffers are then
+// added to `C` in order.
+fn gemm_split_k<T: Scalar>(
+    config: Config,
+    parts: usize,
+    transa: Trans,
+    transb: Trans,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a_f: &FortranView<T>,
+    b_f: &FortranView<T>,
+    c_f: &mut FortranViewMut<T>,
+) {
+    let depth = k.div_ceil(parts);
+    let partials = map(config, (0..k).step_by(depth).collect(), |l0| {
+        let (l0, kb, mi, ni) = (l0 as i32, depth.min(k - l0) as i32, m as i32, n as i32);
+        let a_block = if transa == Trans::NoTrans { a_f.sub(1, l0 + 1, mi, kb) } else { a_f.sub(l0 + 1, 1, kb, mi) };
+        let b_block = if transb == Trans::NoTrans { b_f.sub(l0 + 1, 1, kb, ni) } else { b_f.sub(1, l0 + 1, ni, kb) };
+        let mut partial = vec![T::zero(); m * n];
+        let mut partial_f = FortranViewMut::new(&mut partial, mi, ni, mi);
+        gemm_packed(transa, transb, m, n, kb as usize, alpha, &a_block, &b_block, &mut partial_f, Blocking::DEFAULT);
+        drop(partial_f);
+        partial
+    });
+    for partial in partials {
+        for (j, column) in partial.chunks_exact(m).enumerate() {
+            for (i, &value) in column.iter().enumerate() {
+                c_f[((i + 1) as i32, (j + 1) as i32)] += value;
+            }
+        }
+    }
+}
+
+/// Runs `f` on disjoint column blocks of `c_f`, in parallel when `work` multiply-adds are worth
+/// splitting. `f` gets the 0-based index of the first column of its block.
+pub(crate) fn for_each_column_block<T: Scalar>(
+    config: Config,
+    work: usize,
+    c_f: &mut FortranViewMut<T>,
+    f: impl Fn(usize, &mut FortranViewMut<T>) + Sync,
+) {
+    let cols = c_f.cols().max(0) as usize;
+    let parts = config.parts(work, cols);
+    if parts <= 1 {
+        return f(0, c_f);
+    }
+    let width = cols.div_ceil(parts);
+    let blocks = c_f.column_blocks_mut(width).into_iter().enumerate().collect();
+    for_each(config, blocks, |(b, mut block)| f(b * width, &mut block));
+}
+
+/// Runs `f` on row blocks of `c_f`, in parallel when `work` multiply-adds are worth splitting.
+/// `f` gets the 0-based index of the first row of its block.
+///
+/// # Safety
+///
+/// `f` must only address rows `1..=rows()` of its block, see
+/// [`FortranViewMut::row_blocks_mut`].
+pub(crate) unsafe fn for_each_row_block<T: Scalar>(
+    config: Config,
+    work: usize,
+    c_f: &mut FortranViewMut<T>,
+    f: impl Fn(usize, &mut FortranViewMut<T>) + Sync,
+) {
+    let rows = c_f.rows().max(0) as usize;
+    let parts = config.parts(work, rows);
+    if parts <= 1 {
+        return f(0, c_f);
+    }
+    let height = rows.div_ceil(parts);
+    let blocks = c_f.row_blocks_mut(height).into_iter().enumerate().collect();
+    for_each(config, blocks, |(b, mut block)| f(b * height, &mut block));
+}
+
+#[cfg(feature = "parallel")]
+fn for_each<I: Send>(config: Config, items: Vec<I>, f: impl Fn(I) + Sync + Send) {
+    use rayon::prelude::*;
+    install(config, || items.into_par_iter().for_each(f))
+}
+
+#[cfg(not(feature = "parallel"))]
+fn for_each<I>(_config: Config, items: Vec<I>, f: impl Fn(I)) {
+    items.into_iter().for_each(f)
+}
+
+#[cfg(feature = "parallel")]
+fn map<I: Send, R: Send>(config: Config, items: Vec<I>, f: impl Fn(I) -> R + Sync + Send) -> Vec<R> {
+    use rayon::prelude::*;
+    install(config, || items.into_par_iter().map(f).collect())
+}
+
+#[cfg(not(feature = "parallel"))]
+fn map<I, R>(_config: Config, items: Vec<I>, f: impl Fn(I) -> R) -> Vec<R> {
+    items.into_iter().map(f).collect()
+}
+
+/// Runs `op` on the pool `config` was split for: the calling context's, or a dedicated pool of
+/// `config.threads` threads, built on first use.
+///
+/// If the pool cannot be built, `op` runs on the calling context's pool instead.
+#[cfg(feature = "parallel")]
+fn install<R: Send>(config: Config, op: impl FnOnce() -> R + Send) -> R {
+    static POOL: Mutex<Option<Arc<rayon::ThreadPool>>> = Mutex::new(None);
+
+    if !config.dedicated {
+        return op();
+    }
+    let threads = config.threads;
+    let pool = {
+        let mut pool = POOL.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if pool.as_ref().is_none_or(|pool| pool.current_num_threads() != threads) {
+            *pool = match rayon::ThreadPoolBuilder::new().num_threads(threads).build() {
+                Ok(built) => Some(Arc::new(built)),
+                Err(error) => {
+                    log::warn!("cannot build a BLAS pool of {threads} threads: {error}");
+                    None
+                }
+            };
+        }
+        pool.clone()
+    };
+    match pool {
+        Some(pool) => pool.install(op),
+        None => op(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blas::fixtures::distinct_values;
+    use rstest::rstest;
+
+    fn gemm_with(config: Config, transa: Trans, transb: Trans, m: usize, n: usize, k: usize) -> Vec<f64> {
+        let (a_rows, a_cols) = if transa == Trans::NoTrans { (m, k) } else { (k, m) };
+        let (b_rows, b_cols) = if transb == Trans::NoTrans { (k, n) } else { (n, k) };
+        let (a, b) = (distinct_values(a_rows * a_cols, 1), distinct_values(b_rows * b_cols, 2));
+        let mut c = distinct_values(m * n, 3);
+        let a_f = FortranView::new(&a, a_rows as i32, a_cols as i32, a_rows as i32);
+        let b_f = FortranView::new(&b, b_rows as i32, b_cols as i32, b_rows as i32);
+        let mut c_f = FortranViewMut::new(&mut c, m as i32, n as i32, m as i32);
+        gemm_parallel(config, transa, transb, m, n, k, 0.5, &a_f, &b_f, &mut c_f);
+        drop(c_f);
+        c
+    }
+
+    #[rstest]
+    fn test_gemm_parallel_deterministic(
+        #[values(Trans::NoTrans, Trans::Trans)] transa: Trans,
+        #[values(Trans::NoTrans, Trans::Trans)] transb: Trans,
+        #[values((100, 75, 150), (64, 8, 2100))] shape: (usize, usize, usize),
+    ) {
+        let (m, n, k) = shape;
+        let serial = gemm_with(Config { threads: 1, dedicated: false, deterministic: true }, transa, transb, m, n, k);
+        for threads in 2..=5 {
+            let config = Config { threads, dedicated: false, deterministic: true };
+            assert_eq!(serial, gemm_with(config, transa, transb, m, n, k));
+        }
+    }
+
+    #[test]
+    fn test_gemm_parallel_split_k() {
+        let (m, n, k) = (64, 8, 2100);
+        let serial = gemm_with(Config { threads: 1, dedicated: false, deterministic: false }, Trans::Trans, Trans::NoTrans, m, n, k);
+        for threads in 2..=4 {
+            let split = gemm_with(Config { threads, dedicated: false, deterministic: false }, Trans::Trans, Trans::NoTrans, m, n, k);
+            for (s, p) in serial.iter().zip(&split) {
+                assert!((s - p).abs() <= 1e-12 * s.abs().max(1.));
+            }
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_install_runs_on_config_pool() {
+        // The pool comes from the configuration the work was split for, not from the setting.
+        let dedicated = Config { threads: 2, dedicated: true, deterministic: false };
+        assert_eq!(2, install(dedicated, rayon::current_num_threads));
+        let context = Config { threads: 7, dedicated: false, deterministic: false };
+        assert_eq!(rayon::current_num_threads(), install(context, rayon::current_num_threads));
+    }
+
+    #[test]
+    fn test_settings() {
+        assert!(num_threads() >= 1);
+        if cfg!(not(feature = "parallel")) {
+            assert_eq!(1, num_threads());
+        }
+        assert!(!is_deterministic());
+    }
+}
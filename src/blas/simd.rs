@@ -0,0 +1,383 @@
+use std::any::TypeId;
+use std::mem;
+use std::slice;
+use std::sync::OnceLock;
+
+use crate::scalar::{RealScalar, Scalar};
+
+/// Instruction set used by the vectorized BLAS kernels.
+///
+/// The level is detected once, on first use, from the running CPU. Only double precision
+/// has vector kernels; the other types always run the portable loops. Building with the
+/// `force-scalar` feature pins the level to [`SimdLevel::Scalar`], so results are the same
+/// on every machine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SimdLevel {
+    /// Portable loops.
+    Scalar,
+    /// 256-bit AVX2 with fused multiply-add.
+    Avx2Fma,
+    /// 512-bit AVX-512F.
+    Avx512,
+}
+
+/// Instruction set picked for the running CPU, see [`SimdLevel`].
+pub fn simd_level() -> SimdLevel {
+    static LEVEL: OnceLock<SimdLevel> = OnceLock::new();
+    *LEVEL.get_or_init(detect)
+}
+
+fn detect() -> SimdLevel {
+    if cfg!(feature = "force-scalar") { return SimdLevel::Scalar; }
+    #[cfg(target_arch = "x86_64")]
+    {
+        let fma = is_x86_feature_detected!("fma");
+        if is_x86_feature_detected!("avx512f") && fma { return SimdLevel::Avx512; }
+        if is_x86_feature_detected!("avx2") && fma { return SimdLevel::Avx2Fma; }
+    }
+    SimdLevel::Scalar
+}
+
+/// Rows and columns of the `C` tile computed by the AVX2 [`dgemm`](crate::blas::dgemm::dgemm) kernel.
+pub(crate) const AVX2_MR: usize = 8;
+pub(crate) const AVX2_NR: usize = 6;
+
+/// Rows and columns of the `C` tile computed by the AVX-512 [`dgemm`](crate::blas::dgemm::dgemm) kernel.
+pub(crate) const AVX512_MR: usize = 16;
+pub(crate) const AVX512_NR: usize = 8;
+
+pub(crate) fn is_f64<T: Scalar>() -> bool {
+    TypeId::of::<T>() == TypeId::of::<f64>()
+}
+
+fn as_f64<T: Scalar>(x: &[T]) -> Option<&[f64]> {
+    // SAFETY: `T` is `f64`.
+    is_f64::<T>().then(|| unsafe { &*(x as *const [T] as *const [f64]) })
+}
+
+fn as_f64_mut<T: Scalar>(x: &mut [T]) -> Option<&mut [f64]> {
+    // SAFETY: `T` is `f64`.
+    is_f64::<T>().then(|| unsafe { &mut *(x as *mut [T] as *mut [f64]) })
+}
+
+fn f64_value<T: Scalar>(x: T) -> Option<f64> {
+    as_f64(slice::from_ref(&x)).map(|x| x[0])
+}
+
+/// `x := da*x` with the vector kernels, or `false` when they do not apply and the
+/// portable loop has to run.
+pub(crate) fn scal<T: Scalar>(da: T, x: &mut [T]) -> bool {
+    let (Some(da), Some(x)) = (f64_value(da), as_f64_mut(x)) else { return false; };
+    match simd_level() {
+        // SAFETY: the running CPU supports the detected level.
+        #[cfg(target_arch = "x86_64")]
+        SimdLevel::Avx512 => { unsafe { x86::scal_avx512(da, x) }; true },
+        #[cfg(target_arch = "x86_64")]
+        SimdLevel::Avx2Fma => { unsafe { x86::scal_avx2(da, x) }; true },
+        _ => false,
+    }
+}
+
+/// Applies the plane rotation `(c, s)` to `x` and `y` with the vector kernels, or returns
+/// `false` when they do not apply.
+pub(crate) fn rot<T: RealScalar>(x: &mut [T], y: &mut [T], c: T, s: T) -> bool {
+    let (Some(x), Some(y), Some(c), Some(s)) = (as_f64_mut(x), as_f64_mut(y), f64_value(c), f64_value(s)) else {
+        return false;
+    };
+    match simd_level() {
+        // SAFETY: the running CPU supports the detected level.
+        #[cfg(target_arch = "x86_64")]
+        SimdLevel::Avx512 => { unsafe { x86::rot_avx512(x, y, c, s) }; true },
+        #[cfg(target_arch = "x86_64")]
+        SimdLevel::Avx2Fma => { unsafe { x86::rot_avx2(x, y, c, s) }; true },
+        _ => false,
+    }
+}
+
+/// `y := x` as a vectorized memory copy, or `false` at [`SimdLevel::Scalar`].
+pub(crate) fn copy<T: Scalar>(x: &[T], y: &mut [T]) -> bool {
+    if simd_level() == SimdLevel::Scalar { return false; }
+    y.copy_from_slice(x);
+    true
+}
+
+/// The small, medium and big sums of squares of Blue's algorithm, see
+/// [`nrm2`](crate::blas::dnrm2::nrm2), or `None` when the vector kernels do not apply.
+///
+/// Unlike the portable loop the small sum keeps accumulating after a big value is seen;
+/// it is only used when the big sum is zero.
+pub(crate) fn nrm2_sums<T: RealScalar>(x: &[T], tsml: T, tbig: T, ssml: T, sbig: T) -> Option<(T, T, T)> {
+    let x = as_f64(x)?;
+    let thresholds = [tsml, tbig, ssml, sbig].map(|t| t.to_f64().unwrap_or_default());
+    let (asml, amed, abig) = match simd_level() {
+        // SAFETY: the running CPU supports the detected level.
+        #[cfg(target_arch = "x86_64")]
+        SimdLevel::Avx512 => unsafe { x86::nrm2_avx512(x, thresholds) },
+        #[cfg(target_arch = "x86_64")]
+        SimdLevel::Avx2Fma => unsafe { x86::nrm2_avx2(x, thresholds) },
+        _ => return None,
+    };
+    Some((T::from_f64(asml), T::from_f64(amed), T::from_f64(abig)))
+}
+
+/// `a*b` for an `AVX2_MR` by `kb` sliver `a` and a `kb` by `AVX2_NR` sliver `b`, both packed.
+///
+/// Panics unless `T` is `f64` and the running CPU supports [`SimdLevel::Avx2Fma`].
+#[cfg(target_arch = "x86_64")]
+pub(crate) fn gemm_avx2<T: Scalar>(a: &[T], b: &[T]) -> [[T; AVX2_MR]; AVX2_NR] {
+    assert!(simd_level() >= SimdLevel::Avx2Fma);
+    let (Some(a), Some(b)) = (as_f64(a), as_f64(b)) else { panic!("the AVX2 kernel only supports f64") };
+    // SAFETY: the CPU supports AVX2 and FMA, and `T` is `f64`.
+    unsafe { mem::transmute_copy(&x86::gemm_avx2(a, b)) }
+}
+
+/// `a*b` for an `AVX512_MR` by `kb` sliver `a` and a `kb` by `AVX512_NR` sliver `b`, both packed.
+///
+/// Panics unless `T` is `f64` and the running CPU supports [`SimdLevel::Avx512`].
+#[cfg(target_arch = "x86_64")]
+pub(crate) fn gemm_avx512<T: Scalar>(a: &[T], b: &[T]) -> [[T; AVX512_MR]; AVX512_NR] {
+    assert!(simd_level() >= SimdLevel::Avx512);
+    let (Some(a), Some(b)) = (as_f64(a), as_f64(b)) else { panic!("the AVX-512 kernel only supports f64") };
+    // SAFETY: the CPU supports AVX-512F, and `T` is `f64`.
+    unsafe { mem::transmute_copy(&x86::gemm_avx512(a, b)) }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use std::arch::x86_64::*;
+
+    use super::{AVX2_MR, AVX2_NR, AVX512_MR, AVX512_NR};
+
+    #[target_feature(enable = "avx2,fma")]
+    pub(super) unsafe fn scal_avx2(da: f64, x: &mut [f64]) {
+        let va = _mm256_set1_pd(da);
+        let mut chunks = x.chunks_exact_mut(4);
+        for chunk in &mut chunks {
+            let p = chunk.as_mut_ptr();
+            _mm256_storeu_pd(p, _mm256_mul_pd(_mm256_loadu_pd(p), va));
+        }
+        for x in chunks.into_remainder() {
+            *x *= da;
+        }
+    }
+
+    #[target_feature(enable = "avx512f,fma")]
+    pub(super) unsafe fn scal_avx512(da: f64, x: &mut [f64]) {
+        let va = _mm512_set1_pd(da);
+        let mut chunks = x.chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            let p = chunk.as_mut_ptr();
+            _mm512_storeu_pd(p, _mm512_mul_pd(_mm512_loadu_pd(p), va));
+        }
+        for x in chunks.into_remainder() {
+            *x *= da;
+        }
+    }
+
+    #[target_feature(enable = "avx2,fma")]
+    pub(super) unsafe fn rot_avx2(x: &mut [f64], y: &mut [f64], c: f64, s: f64) {
+        let (vc, vs) = (_mm256_set1_pd(c), _mm256_set1_pd(s));
+        let (mut x_chunks, mut y_chunks) = (x.chunks_exact_mut(4), y.chunks_exact_mut(4));
+        for (x, y) in (&mut x_chunks).zip(&mut y_chunks) {
+            let (px, py) = (x.as_mut_ptr(), y.as_mut_ptr());
+            let (vx, vy) = (_mm256_loadu_pd(px), _mm256_loadu_pd(py));
+            _mm256_storeu_pd(px, _mm256_add_pd(_mm256_mul_pd(vc, vx), _mm256_mul_pd(vs, vy)));
+            _mm256_storeu_pd(py, _mm256_sub_pd(_mm256_mul_pd(vc, vy), _mm256_mul_pd(vs, vx)));
+        }
+        rot_remainder(x_chunks.into_remainder(), y_chunks.into_remainder(), c, s);
+    }
+
+    #[target_feature(enable = "avx512f,fma")]
+    pub(super) unsafe fn rot_avx512(x: &mut [f64], y: &mut [f64], c: f64, s: f64) {
+        let (vc, vs) = (_mm512_set1_pd(c), _mm512_set1_pd(s));
+        let (mut x_chunks, mut y_chunks) = (x.chunks_exact_mut(8), y.chunks_exact_mut(8));
+        for (x, y) in (&mut x_chunks).zip(&mut y_chunks) {
+            let (px, py) = (x.as_mut_ptr(), y.as_mut_ptr());
+            let (vx, vy) = (_mm512_loadu_pd(px), _mm512_loadu_pd(py));
+            _mm512_storeu_pd(px, _mm512_add_pd(_mm512_mul_pd(vc, vx), _mm512_mul_pd(vs, vy)));
+            _mm512_storeu_pd(py, _mm512_sub_pd(_mm512_mul_pd(vc, vy), _mm512_mul_pd(vs, vx)));
+        }
+        rot_remainder(x_chunks.into_remainder(), y_chunks.into_remainder(), c, s);
+    }
+
+    // Unfused, like the portable loop, so rotations round the same on every level.
+    fn rot_remainder(x: &mut [f64], y: &mut [f64], c: f64, s: f64) {
+        for (x, y) in x.iter_mut().zip(y) {
+            let dtemp = c * *x + s * *y;
+            *y = c * *y - s * *x;
+            *x = dtemp;
+        }
+    }
+
+    #[target_feature(enable = "avx2,fma")]
+    pub(super) unsafe fn nrm2_avx2(x: &[f64], thresholds: [f64; 4]) -> (f64, f64, f64) {
+        let [tsml, tbig, ssml, sbig] = thresholds;
+        let (vtsml, vtbig, vssml, vsbig) = (_mm256_set1_pd(tsml), _mm256_set1_pd(tbig), _mm256_set1_pd(ssml), _mm256_set1_pd(sbig));
+        let sign = _mm256_set1_pd(-0.);
+        let (mut asml, mut amed, mut abig) = (_mm256_setzero_pd(), _mm256_setzero_pd(), _mm256_setzero_pd());
+        let mut chunks = x.chunks_exact(4);
+        for chunk in &mut chunks {
+            let ax = _mm256_andnot_pd(sign, _mm256_loadu_pd(chunk.as_ptr()));
+            // NaN compares false to both thresholds and lands in the medium sum, as in the loop.
+            let big = _mm256_cmp_pd::<_CMP_GT_OQ>(ax, vtbig);
+            let sml = _mm256_cmp_pd::<_CMP_LT_OQ>(ax, vtsml);
+            let (xb, xs) = (_mm256_mul_pd(ax, vsbig), _mm256_mul_pd(ax, vssml));
+            abig = _mm256_add_pd(abig, _mm256_and_pd(big, _mm256_mul_pd(xb, xb)));
+            asml = _mm256_add_pd(asml, _mm256_and_pd(sml, _mm256_mul_pd(xs, xs)));
+            amed = _mm256_add_pd(amed, _mm256_andnot_pd(_mm256_or_pd(big, sml), _mm256_mul_pd(ax, ax)));
+        }
+        let sum = |v: __m256d| {
+            let mut lanes = [0.; 4];
+            _mm256_storeu_pd(lanes.as_mut_ptr(), v);
+            lanes.iter().sum::<f64>()
+        };
+        nrm2_remainder(chunks.remainder(), thresholds, (sum(asml), sum(amed), sum(abig)))
+    }
+
+    #[target_feature(enable = "avx512f,fma")]
+    pub(super) unsafe fn nrm2_avx512(x: &[f64], thresholds: [f64; 4]) -> (f64, f64, f64) {
+        let [tsml, tbig, ssml, sbig] = thresholds;
+        let (vtsml, vtbig, vssml, vsbig) = (_mm512_set1_pd(tsml), _mm512_set1_pd(tbig), _mm512_set1_pd(ssml), _mm512_set1_pd(sbig));
+        let (mut asml, mut amed, mut abig) = (_mm512_setzero_pd(), _mm512_setzero_pd(), _mm512_setzero_pd());
+        let mut chunks = x.chunks_exact(8);
+        for chunk in &mut chunks {
+            let ax = _mm512_abs_pd(_mm512_loadu_pd(chunk.as_ptr()));
+            let big = _mm512_cmp_pd_mask::<_CMP_GT_OQ>(ax, vtbig);
+            let sml = _mm512_cmp_pd_mask::<_CMP_LT_OQ>(ax, vtsml);
+            let (xb, xs) = (_mm512_mul_pd(ax, vsbig), _mm512_mul_pd(ax, vssml));
+            abig = _mm512_mask_add_pd(abig, big, abig, _mm512_mul_pd(xb, xb));
+            asml = _mm512_mask_add_pd(asml, sml, asml, _mm512_mul_pd(xs, xs));
+            amed = _mm512_mask_add_pd(amed, !(big | sml), amed, _mm512_mul_pd(ax, ax));
+        }
+        let sums = (_mm512_reduce_add_pd(asml), _mm512_reduce_add_pd(amed), _mm512_reduce_add_pd(abig));
+        nrm2_remainder(chunks.remainder(), thresholds, sums)
+    }
+
+    pub(super) fn nrm2_remainder(x: &[f64], thresholds: [f64; 4], sums: (f64, f64, f64)) -> (f64, f64, f64) {
+        let [tsml, tbig, ssml, sbig] = thresholds;
+        let (mut asml, mut amed, mut abig) = sums;
+        for ax in x.iter().map(|x| x.abs()) {
+            if ax > tbig {
+                abig += (ax * sbig).powi(2);
+            } else if ax < tsml {
+                asml += (ax * ssml).powi(2);
+            } else {
+                amed += ax.powi(2);
+            }
+        }
+        (asml, amed, abig)
+    }
+
+    #[target_feature(enable = "avx2,fma")]
+    pub(super) unsafe fn gemm_avx2(a: &[f64], b: &[f64]) -> [[f64; AVX2_MR]; AVX2_NR] {
+        // Two registers per column of the tile, twelve accumulators in all.
+        let mut c = [[_mm256_setzero_pd(); 2]; AVX2_NR];
+        for (a, b) in a.chunks_exact(AVX2_MR).zip(b.chunks_exact(AVX2_NR)) {
+            let (a0, a1) = (_mm256_loadu_pd(a.as_ptr()), _mm256_loadu_pd(a.as_ptr().add(4)));
+            for (c, b) in c.iter_mut().zip(b) {
+                let b = _mm256_broadcast_sd(b);
+                c[0] = _mm256_fmadd_pd(a0, b, c[0]);
+                c[1] = _mm256_fmadd_pd(a1, b, c[1]);
+            }
+        }
+        let mut ab = [[0.; AVX2_MR]; AVX2_NR];
+        for (ab, c) in ab.iter_mut().zip(&c) {
+            _mm256_storeu_pd(ab.as_mut_ptr(), c[0]);
+            _mm256_storeu_pd(ab.as_mut_ptr().add(4), c[1]);
+        }
+        ab
+    }
+
+    #[target_feature(enable = "avx512f,fma")]
+    pub(super) unsafe fn gemm_avx512(a: &[f64], b: &[f64]) -> [[f64; AVX512_MR]; AVX512_NR] {
+        // Two registers per column of the tile, sixteen accumulators in all.
+        let mut c = [[_mm512_setzero_pd(); 2]; AVX512_NR];
+        for (a, b) in a.chunks_exact(AVX512_MR).zip(b.chunks_exact(AVX512_NR)) {
+            let (a0, a1) = (_mm512_loadu_pd(a.as_ptr()), _mm512_loadu_pd(a.as_ptr().add(8)));
+            for (c, b) in c.iter_mut().zip(b) {
+                let b = _mm512_set1_pd(*b);
+                c[0] = _mm512_fmadd_pd(a0, b, c[0]);
+                c[1] = _mm512_fmadd_pd(a1, b, c[1]);
+            }
+        }
+        let mut ab = [[0.; AVX512_MR]; AVX512_NR];
+        for (ab, c) in ab.iter_mut().zip(&c) {
+            _mm512_storeu_pd(ab.as_mut_ptr(), c[0]);
+            _mm512_storeu_pd(ab.as_mut_ptr().add(8), c[1]);
+        }
+        ab
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(len: usize) -> Vec<f64> {
+        (0..len).map(|p| ((p * 7) % 17) as f64 / 3. - 2.5).collect()
+    }
+
+    #[test]
+    fn test_simd_level() {
+        if cfg!(feature = "force-scalar") {
+            assert_eq!(SimdLevel::Scalar, simd_level());
+        }
+        assert_eq!(simd_level(), simd_level());
+    }
+
+    #[test]
+    fn test_only_f64_is_vectorized() {
+        assert!(!scal(2f32, &mut [1f32; 9]));
+        assert!(!rot(&mut [1f32; 9], &mut [1f32; 9], 0.6, 0.8));
+        assert_eq!(None, nrm2_sums(&[1f32; 9], 0., 1., 1., 1.));
+        let vectorized = simd_level() > SimdLevel::Scalar;
+        assert_eq!(vectorized, scal(2., &mut [1.; 9]));
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn test_level_1_kernels() {
+        let levels = [SimdLevel::Avx2Fma, SimdLevel::Avx512];
+        for level in levels.into_iter().filter(|&level| level <= simd_level()) {
+            for n in [0, 1, 7, 8, 9, 33] {
+                let x = values(n);
+
+                let actual = &mut x.clone();
+                unsafe {
+                    match level {
+                        SimdLevel::Avx512 => x86::scal_avx512(-1.5, actual),
+                        _ => x86::scal_avx2(-1.5, actual),
+                    }
+                }
+                assert_eq!(x.iter().map(|x| -1.5 * x).collect::<Vec<_>>(), *actual);
+
+                let (rx, ry) = (&mut x.clone(), &mut values(n + 3)[3..].to_vec());
+                let (ex, ey) = (rx.clone(), ry.clone());
+                unsafe {
+                    match level {
+                        SimdLevel::Avx512 => x86::rot_avx512(rx, ry, 0.6, 0.8),
+                        _ => x86::rot_avx2(rx, ry, 0.6, 0.8),
+                    }
+                }
+                assert_eq!(ex.iter().zip(&ey).map(|(x, y)| 0.6 * x + 0.8 * y).collect::<Vec<_>>(), *rx);
+                assert_eq!(ex.iter().zip(&ey).map(|(x, y)| 0.6 * y - 0.8 * x).collect::<Vec<_>>(), *ry);
+
+                // 1e-300 and 1e300 fall in the small and big sums.
+                let mut x = x;
+                x.extend([1e-300, 1e300, f64::NAN].iter().take(n.min(3)));
+                let thresholds = [1e-150, 1e150, 1e150, 1e-150];
+                let (asml, amed, abig) = unsafe {
+                    match level {
+                        SimdLevel::Avx512 => x86::nrm2_avx512(&x, thresholds),
+                        _ => x86::nrm2_avx2(&x, thresholds),
+                    }
+                };
+                let expected = x86::nrm2_remainder(&x, thresholds, (0., 0., 0.));
+                assert!((expected.0 - asml).abs() <= 1e-15 * expected.0);
+                assert_eq!(expected.1.is_nan(), amed.is_nan());
+                assert!(amed.is_nan() || (expected.1 - amed).abs() <= 1e-13 * expected.1);
+                assert!((expected.2 - abig).abs() <= 1e-15 * expected.2);
+            }
+        }
+    }
+}
@@ -1,4 +1,10 @@
-use crate::array::{convert::ToFortranArray, FortranArray};
+use std::fmt::Debug;
+
+use crate::array::convert::{ToFortranArray, ToFortranArrayMut};
+use crate::error::Info;
+use crate::flags::Uplo;
+use crate::scalar::{Complex32, Complex64, Scalar};
+use crate::xerbla::illegal_argument;
 
 /// DLACPY
 ///
@@ -8,49 +14,136 @@ use crate::array::{convert::ToFortranArray, FortranArray};
 /// [http://www.netlib.org/lapack/explore-html/](http://www.netlib.org/lapack/explore-html/)
 ///
 /// Copies all or part of a two-dimensional matrix `A` to another matrix `B`.
+/// `uplo` selects the triangle to copy, `None` copies the whole matrix.
 ///
 /// # Arguments
 ///
 /// For arguments definitions, please refer to the original documentation.
 pub fn dlacpy<A, B>(
-    uplo: char,
+    uplo: Option<Uplo>,
     m: i32,
     n: i32,
     a: &A,
     lda: i32,
     b: &mut B,
     ldb: i32,
-) where
+) -> Result<(), Info> where
     A: ToFortranArray,
-    B: ToFortranArray + From<FortranArray>,
+    B: ToFortranArrayMut,
+{
+    lacpy(uplo, m, n, a, lda, b, ldb)
+}
+
+/// SLACPY
+///
+/// Single precision variant of [`dlacpy`].
+pub fn slacpy<A, B>(
+    uplo: Option<Uplo>,
+    m: i32,
+    n: i32,
+    a: &A,
+    lda: i32,
+    b: &mut B,
+    ldb: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<f32>,
+    B: ToFortranArrayMut<f32>,
+{
+    lacpy(uplo, m, n, a, lda, b, ldb)
+}
+
+/// CLACPY
+///
+/// Single precision complex variant of [`dlacpy`].
+pub fn clacpy<A, B>(
+    uplo: Option<Uplo>,
+    m: i32,
+    n: i32,
+    a: &A,
+    lda: i32,
+    b: &mut B,
+    ldb: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<Complex32>,
+    B: ToFortranArrayMut<Complex32>,
 {
-    let a_f = &a.to_fa_2d(lda);
-    let b_f = &mut b.to_fa_2d(ldb);
+    lacpy(uplo, m, n, a, lda, b, ldb)
+}
+
+/// ZLACPY
+///
+/// Double precision complex variant of [`dlacpy`].
+pub fn zlacpy<A, B>(
+    uplo: Option<Uplo>,
+    m: i32,
+    n: i32,
+    a: &A,
+    lda: i32,
+    b: &mut B,
+    ldb: i32,
+) -> Result<(), Info> where
+    A: ToFortranArray<Complex64>,
+    B: ToFortranArrayMut<Complex64>,
+{
+    lacpy(uplo, m, n, a, lda, b, ldb)
+}
+
+/// Shared implementation of [`slacpy`], [`dlacpy`], [`clacpy`] and [`zlacpy`].
+pub fn lacpy<T, A, B>(
+    uplo: Option<Uplo>,
+    m: i32,
+    n: i32,
+    a: &A,
+    lda: i32,
+    b: &mut B,
+    ldb: i32,
+) -> Result<(), Info> where
+    T: Scalar,
+    A: ToFortranArray<T>,
+    B: ToFortranArrayMut<T>,
+{
+    let illegal = |position, name, value: &dyn Debug| {
+        illegal_argument(format!("{}LACPY", T::PREFIX), position, name, value)
+    };
+
+    if m < 0 {
+        return illegal(2, "m", &m);
+    } else if n < 0 {
+        return illegal(3, "n", &n);
+    } else if lda < 1.max(m) {
+        return illegal(5, "lda", &lda);
+    } else if ldb < 1.max(m) {
+        return illegal(7, "ldb", &ldb);
+    }
+
+    let a_f = &a.fa_view_2d(lda);
+    let b_f = &mut b.fa_view_2d_mut(ldb);
 
     match uplo {
-        'U' => for j in 1..=n {
-            for i in j..=m {
+        Some(Uplo::Upper) => for j in 1..=n {
+            for i in 1..=j.min(m) {
                 b_f[(i, j)] = a_f[(i, j)];
             }
         },
-        'L' => for j in 1..=n {
-            for i in 1..=j {
+        Some(Uplo::Lower) => for j in 1..=n {
+            for i in j..=m {
                 b_f[(i, j)] = a_f[(i, j)];
             }
         },
-        _ => for j in 1..=n {
+        None => for j in 1..=n {
             for i in 1..=m {
                 b_f[(i, j)] = a_f[(i, j)];
             }
         }
     }
 
-    *b = B::from(b_f.clone());
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[test]
     fn test_dlacpy_upper() {
@@ -65,11 +158,11 @@ mod tests {
             vec![0.0, 0.0, 0.0],
         ];
 
-        dlacpy('U', 3, 3, &a, 3, &mut b, 3);
+        dlacpy(Some(Uplo::Upper), 3, 3, &a, 3, &mut b, 3).unwrap();
         assert_eq!(b, vec![
-            vec![1.0, 2.0, 3.0],
-            vec![0.0, 5.0, 6.0],
-            vec![0.0, 0.0, 9.0],
+            vec![1.0, 0.0, 0.0],
+            vec![4.0, 5.0, 0.0],
+            vec![7.0, 8.0, 9.0],
         ]);
     }
 
@@ -86,11 +179,11 @@ mod tests {
             vec![0.0, 0.0, 0.0],
         ];
 
-        dlacpy('L', 3, 3, &a, 3, &mut b, 3);
+        dlacpy(Some(Uplo::Lower), 3, 3, &a, 3, &mut b, 3).unwrap();
         assert_eq!(b, vec![
-            vec![1.0, 0.0, 0.0],
-            vec![4.0, 5.0, 0.0],
-            vec![7.0, 8.0, 9.0],
+            vec![1.0, 2.0, 3.0],
+            vec![0.0, 5.0, 6.0],
+            vec![0.0, 0.0, 9.0],
         ]);
     }
 
@@ -107,7 +200,29 @@ mod tests {
             vec![0.0, 0.0, 0.0],
         ];
 
-        dlacpy('A', 3, 3, &a, 3, &mut b, 3);
+        dlacpy(None, 3, 3, &a, 3, &mut b, 3).unwrap();
         assert_eq!(a, b);
     }
+
+    #[rstest]
+    #[case(-1, 3, 3, 3, 2, "m")]
+    #[case(3, -1, 3, 3, 3, "n")]
+    #[case(3, 3, 2, 3, 5, "lda")]
+    #[case(3, 3, 3, 2, 7, "ldb")]
+    fn test_dlacpy_illegal_argument(
+        #[case] m: i32,
+        #[case] n: i32,
+        #[case] lda: i32,
+        #[case] ldb: i32,
+        #[case] position: i32,
+        #[case] name: &str,
+    ) {
+        let a = vec![1.0; 9];
+        let mut b = vec![0.0; 9];
+        let Err(Info::IllegalArgument(err)) = dlacpy(None, m, n, &a, lda, &mut b, ldb) else {
+            panic!("expected an illegal argument");
+        };
+        assert_eq!(("DLACPY", position, name), (err.routine.as_str(), err.position, err.name));
+        assert_eq!(vec![0.0; 9], b);
+    }
 }
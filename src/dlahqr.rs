@@ -1,9 +1,15 @@
-use crate::array::{convert::ToFortranArray, FortranArray};
-use crate::blas::dcopy::dcopy;
-use crate::blas::drot::drot;
-use crate::dlamch::dlamch;
-use crate::dlanv2::dlanv2;
-use crate::dlarfg::dlarfg;
+use std::fmt::Debug;
+
+use crate::array::convert::ToFortranArrayMut;
+use crate::array::FortranArray;
+use crate::blas::backend::{Blas, BlasBackend};
+use crate::dlamch::lamch;
+use crate::dlanv2::lanv2;
+use crate::dlarfg::larfg;
+use crate::error::Info;
+use crate::nancheck::{ieee_nan_arithmetic, nancheck};
+use crate::scalar::RealScalar;
+use crate::xerbla::illegal_argument;
 
 /// DLAHQR
 ///
@@ -14,6 +20,11 @@ use crate::dlarfg::dlarfg;
 ///
 /// Computes the eigenvalues and Schur factorization of an upper Hessenberg matrix, using the double-shift/single-shift QR algorithm.
 ///
+/// Returns [`Info::ConvergenceFailure`] with `index = i` when the eigenvalues `i+1` to `ihi`
+/// have been computed but `ilo` to `i` failed to converge, the reference `INFO = i`.
+/// With [`check_finite`](crate::nancheck::check_finite) enabled, NaN or infinity in `h`, or in
+/// `z` when `wantz`, is reported as [`Info::NonFinite`] instead.
+///
 /// # Arguments
 ///
 /// For arguments definitions, please refer to the original documentation.
@@ -30,49 +41,120 @@ pub fn dlahqr<H, WR, WI, Z>(
     iloz: i32,
     ihiz: i32,
     z: &mut Z,
-    _ldz: i32,
-    info: &mut i32,
-) where
-    H: ToFortranArray + From<FortranArray>,
-    WR: ToFortranArray + From<FortranArray>,
-    WI: ToFortranArray + From<FortranArray>,
-    Z: ToFortranArray + From<FortranArray>,
+    ldz: i32,
+) -> Result<(), Info> where
+    H: ToFortranArrayMut,
+    WR: ToFortranArrayMut,
+    WI: ToFortranArrayMut,
+    Z: ToFortranArrayMut,
+{
+    lahqr(wantt, wantz, n, ilo, ihi, h, ldh, wr, wi, iloz, ihiz, z, ldz)
+}
+
+/// SLAHQR
+///
+/// Single precision variant of [`dlahqr`].
+pub fn slahqr<H, WR, WI, Z>(
+    wantt: bool,
+    wantz: bool,
+    n: i32,
+    ilo: i32,
+    ihi: i32,
+    h: &mut H,
+    ldh: i32,
+    wr: &mut WR,
+    wi: &mut WI,
+    iloz: i32,
+    ihiz: i32,
+    z: &mut Z,
+    ldz: i32,
+) -> Result<(), Info> where
+    H: ToFortranArrayMut<f32>,
+    WR: ToFortranArrayMut<f32>,
+    WI: ToFortranArrayMut<f32>,
+    Z: ToFortranArrayMut<f32>,
 {
-    let h_f = &mut h.to_fa();
-    let wr_f = &mut wr.to_fa();
-    let wi_f = &mut wi.to_fa();
-    let z_f = &mut z.to_fa();
-
-    *info = 0;
-    let (dat1, dat2) = (3. / 4., -0.4375);
-    let (mut cs, mut sn) = (0., 0.);
+    lahqr(wantt, wantz, n, ilo, ihi, h, ldh, wr, wi, iloz, ihiz, z, ldz)
+}
+
+/// Shared implementation of [`slahqr`] and [`dlahqr`].
+pub fn lahqr<T, H, WR, WI, Z>(
+    wantt: bool,
+    wantz: bool,
+    n: i32,
+    ilo: i32,
+    ihi: i32,
+    h: &mut H,
+    ldh: i32,
+    wr: &mut WR,
+    wi: &mut WI,
+    iloz: i32,
+    ihiz: i32,
+    z: &mut Z,
+    ldz: i32,
+) -> Result<(), Info> where
+    T: RealScalar,
+    H: ToFortranArrayMut<T>,
+    WR: ToFortranArrayMut<T>,
+    WI: ToFortranArrayMut<T>,
+    Z: ToFortranArrayMut<T>,
+{
+    let illegal = |position, name, value: &dyn Debug| {
+        illegal_argument(format!("{}LAHQR", T::PREFIX), position, name, value)
+    };
+
+    if n < 0 {
+        return illegal(3, "n", &n);
+    } else if ilo < 1 || ilo > 1.max(n) {
+        return illegal(4, "ilo", &ilo);
+    } else if ihi < ilo.min(n) || ihi > n {
+        return illegal(5, "ihi", &ihi);
+    } else if ldh < 1.max(n) {
+        return illegal(7, "ldh", &ldh);
+    } else if wantz && (iloz < 1 || iloz > ilo) {
+        return illegal(10, "iloz", &iloz);
+    } else if wantz && (ihiz < ihi || ihiz > n) {
+        return illegal(11, "ihiz", &ihiz);
+    } else if ldz < 1 || (wantz && ldz < 1.max(n)) {
+        return illegal(13, "ldz", &ldz);
+    }
+
+    let h_f = &mut h.fa_view_2d_mut(ldh);
+    let wr_f = &mut wr.fa_view_mut();
+    let wi_f = &mut wi.fa_view_mut();
+    let z_f = &mut z.fa_view_2d_mut(ldz);
+
+    nancheck(format!("{}LAHQR", T::PREFIX), 6, "h", n, n, &h_f.sub(1, 1, n, n))?;
+    if wantz {
+        nancheck(format!("{}LAHQR", T::PREFIX), 12, "z", n, n, &z_f.sub(1, 1, n, n))?;
+    }
+
+    let (dat1, dat2) = (T::from_f64(3. / 4.), T::from_f64(-0.4375));
+    let (mut cs, mut sn) = (T::zero(), T::zero());
     let kexsh = 10;
 
-    if n == 0 { return; }
+    if n == 0 { return Ok(()); }
 
     if ilo == ihi {
         wr_f[ilo] = h_f[(ilo, ilo)];
-        wi_f[ilo] = 0.;
-
-        *wr = WR::from(wr_f.clone());
-        *wi = WI::from(wi_f.clone());
-        return;
+        wi_f[ilo] = T::zero();
+        return Ok(());
     }
 
     for j in ilo..=ihi-3 {
-        h_f[(j + 2, j)] = 0.;
-        h_f[(j + 3, j)] = 0.;
+        h_f[(j + 2, j)] = T::zero();
+        h_f[(j + 3, j)] = T::zero();
     }
     if ilo <= (ihi - 2) {
-        h_f[(ihi, ihi-2)] = 0.;
+        h_f[(ihi, ihi-2)] = T::zero();
     }
 
     let nh = ihi - ilo + 1;
     let nz = ihiz - iloz + 1;
 
-    let safmin = dlamch('S');
-    let ulp = dlamch('P');
-    let smlnum = safmin * ((nh as f64) / ulp);
+    let safmin = lamch::<T>('S');
+    let ulp = lamch::<T>('P');
+    let smlnum = safmin * (T::from_f64(nh as f64) / ulp);
 
     let (mut i1, mut i2) = (0, 0);
     if wantt {
@@ -81,12 +163,13 @@ pub fn dlahqr<H, WR, WI, Z>(
     }
 
     let itmax = 30 * nh.max(10);
+    let ieee = ieee_nan_arithmetic();
     let mut kdefl = 0;
     let mut i = ihi;
 
-    let mut v = vec![0.; 3].to_fa();
+    let mut v = FortranArray::vector(&[T::zero(); 3]);
     let mut m = 0;
-    let mut t1 = 0.;
+    let mut t1 = T::zero();
     let mut nr;
     let mut its = 0;
     let mut l;
@@ -94,21 +177,20 @@ pub fn dlahqr<H, WR, WI, Z>(
 
     loop { // 'loop_20:
         l = ilo;
-        if i < ilo { // GO TO 160
-            *h = H::from(h_f.clone());
-            *wr = WR::from(wr_f.clone());
-            *wi = WI::from(wi_f.clone());
-            *z = Z::from(z_f.clone());
-            return;
-        }
+        if i < ilo { return Ok(()); } // GO TO 160
 
         'loop_140: for curr_its in 0..=itmax {
             its = curr_its;
+            // A NaN in the active block spreads to H(I,I) and never deflates; with IEEE
+            // arithmetic stop at once instead of running out of iterations.
+            if ieee && h_f[(i, i)].is_nan() {
+                return Err(Info::ConvergenceFailure { index: i });
+            }
             for curr_k in (l + 1..=i).rev() { // 'loop_30:
                 k = curr_k;
                 if h_f[(curr_k, curr_k - 1)].abs() <= smlnum { break; }
                 let mut tst = h_f[(curr_k - 1, curr_k - 1)].abs() + h_f[(curr_k, curr_k)].abs();
-                if tst == 0. {
+                if tst == T::zero() {
                     if (curr_k - 2) >= ilo { tst += h_f[(curr_k - 1, curr_k - 2)].abs(); }
                     if (curr_k + 1) <= ihi { tst += h_f[(curr_k + 1, curr_k)].abs(); }
                 }
@@ -124,7 +206,7 @@ pub fn dlahqr<H, WR, WI, Z>(
             }
 
             l = k;
-            if l > ilo { h_f[(l, l - 1)] = 0.; }
+            if l > ilo { h_f[(l, l - 1)] = T::zero(); }
 
             // go to 150
             if l >= i - 1 { break 'loop_140; }
@@ -134,10 +216,8 @@ pub fn dlahqr<H, WR, WI, Z>(
             let mut h12;
             let mut h21;
             let mut h22;
-            let mut i1 = 0;
-            let mut i2 = 0;
-
-            if wantt {
+            // Without the full Schur form only the active block is updated (IF( .NOT.WANTT )).
+            if !wantt {
                 i1 = l;
                 i2 = i;
             }
@@ -168,21 +248,21 @@ pub fn dlahqr<H, WR, WI, Z>(
             let s = h11.abs() + h12.abs() + h21.abs() + h22.abs();
             let (mut rt1r, rt1i, mut rt2r, rt2i);
 
-            if s == 0. {
-                rt1r = 0.;
-                rt1i = 0.;
-                rt2r = 0.;
-                rt2i = 0.;
+            if s == T::zero() {
+                rt1r = T::zero();
+                rt1i = T::zero();
+                rt2r = T::zero();
+                rt2i = T::zero();
             } else {
                 h11 /= s;
                 h21 /= s;
                 h12 /= s;
                 h22 /= s;
-                let tr = (h11 + h22) / 2.;
+                let tr = (h11 + h22) / T::from_f64(2.);
                 let det = (h11 - tr) * (h22 - tr) - h12 * h21;
                 let rtdisc = det.abs().sqrt();
 
-                if det >= 0. {
+                if det >= T::zero() {
                     // complex conjugate shifts
                     rt1r = tr * s;
                     rt2r = rt1r;
@@ -199,8 +279,8 @@ pub fn dlahqr<H, WR, WI, Z>(
                         rt2r *= s;
                         rt1r = rt2r;
                     }
-                    rt1i = 0.;
-                    rt2i = 0.;
+                    rt1i = T::zero();
+                    rt2i = T::zero();
                 }
             }
 
@@ -218,9 +298,9 @@ pub fn dlahqr<H, WR, WI, Z>(
                 v[2] = h21s * (h_f[(curr_m, curr_m)] + h_f[(curr_m + 1, curr_m + 1)] - rt1r - rt2r);
                 v[3] = h21s * h_f[(curr_m + 2, curr_m + 1)];
                 s = v[1].abs() + v[2].abs() + v[3].abs();
-                v[1] = v[1]/s;
-                v[2] = v[2]/s;
-                v[3] = v[3]/s;
+                v[1] /= s;
+                v[2] /= s;
+                v[3] /= s;
 
                 if curr_m == l {
                     // go to 60
@@ -246,24 +326,21 @@ pub fn dlahqr<H, WR, WI, Z>(
 
                 nr = 3.min(i-k+1);
 
-                if k > m { dcopy(nr, &h_f[(k, k - 1)..].to_vec(), 1, &mut v, 1); }
-                let (v_1, v_2) = (
-                    &mut v[1].clone(),
-                    &mut v[2..].to_vec().to_fa());
-                dlarfg(nr, v_1, v_2, 1, &mut t1);
-                v = v_2.clone();
-                v.insert(1, v_1.clone());
+                if k > m { Blas::copy(nr, &h_f.slice_from((k, k - 1)), 1, &mut v, 1); }
+                let mut alpha = v[1];
+                larfg(nr, &mut alpha, &mut v.view_mut().slice_from_mut((2, 1)), 1, &mut t1);
+                v[1] = alpha;
 
                 if k > m {
                     h_f[(k, k - 1)] = v[1];
-                    h_f[(k + 1, k - 1)] = 0.;
+                    h_f[(k + 1, k - 1)] = T::zero();
                     if k < i - 1 {
-                        h_f[(k + 2, k - 1)] = 0.;
+                        h_f[(k + 2, k - 1)] = T::zero();
                     }
                 } else if m > l {
                     // Use the following instead of h_f[(k, k - 1)] = -h_f[(k, k - 1)], to
                     // avoid a bug when v[1] and v[2] underflow. ====
-                    h_f[(k, k - 1)] *= 1. - t1;
+                    h_f[(k, k - 1)] *= T::one() - t1;
                 }
 
                 let v2 = v[2];
@@ -277,26 +354,26 @@ pub fn dlahqr<H, WR, WI, Z>(
                     // Apply G from the left to transform the rows of the matrix in columns K to I2.
                     for j in k..=i2 { // 'loop_70:
                         sum = h_f[(k, j)] + v2 * h_f[(k + 1, j)] + v3 * h_f[(k + 2, j)];
-                        h_f[(k, j)] = h_f[(k, j)] - sum * t1;
-                        h_f[(k + 1, j)] = h_f[(k + 1, j)] - sum * t2;
-                        h_f[(k + 2, j)] = h_f[(k + 2, j)] - sum * t3;
+                        h_f[(k, j)] -= sum * t1;
+                        h_f[(k + 1, j)] -= sum * t2;
+                        h_f[(k + 2, j)] -= sum * t3;
                     }
 
                     // Apply G from the right to transform the columns of the matrix in rows I1 to min(K+3,I).
-                    for j in i1..=i.min(k + 4) { // 'loop_80:
+                    for j in i1..=i.min(k + 3) { // 'loop_80:
                         sum = h_f[(j, k)] + v2 * h_f[(j, k + 1)] + v3 * h_f[(j, k + 2)];
-                        h_f[(j, k)] = h_f[(j, k)] - sum * t1;
-                        h_f[(j, k + 1)] = h_f[(j, k + 1)] - sum * t2;
-                        h_f[(j, k + 2)] = h_f[(j, k + 2)] - sum * t3;
+                        h_f[(j, k)] -= sum * t1;
+                        h_f[(j, k + 1)] -= sum * t2;
+                        h_f[(j, k + 2)] -= sum * t3;
                     }
 
                     if wantz {
                         // Accumulate transformations in the matrix Z
                         for j in iloz..=ihiz { // 'loop_90:
                             sum = z_f[(j, k)] + v2 * z_f[(j, k + 1)] + v3 * z_f[(j, k + 2)];
-                            z_f[(j, k)] = z_f[(j, k)] - sum * t1;
-                            z_f[(j, k + 1)] = z_f[(j, k + 1)] - sum * t2;
-                            z_f[(j, k + 2)] = z_f[(j, k + 2)] - sum * t3;
+                            z_f[(j, k)] -= sum * t1;
+                            z_f[(j, k + 1)] -= sum * t2;
+                            z_f[(j, k + 2)] -= sum * t3;
                         }
                     }
 
@@ -304,23 +381,23 @@ pub fn dlahqr<H, WR, WI, Z>(
                     // Apply G from the left to transform the rows of the matrix in columns K to i2
                     for j in k..=i2 { // 'loop_100:
                         sum = h_f[(k, j)] + v2 * h_f[(k + 1, j)];
-                        h_f[(k, j)] = h_f[(k, j)] - sum * t1;
-                        h_f[(k + 1, j)] = h_f[(k + 1, j)] - sum * t2;
+                        h_f[(k, j)] -= sum * t1;
+                        h_f[(k + 1, j)] -= sum * t2;
                     }
 
                     // Apply G from the right to transform the columns of the matrix in rows I1 to min(K+3,I).
                     for j in i1..=i { // 'loop_110:
                         sum = h_f[(j, k)] + v2 * h_f[(j, k + 1)];
-                        h_f[(j, k)] = h_f[(j, k)] - sum * t1;
-                        h_f[(j, k + 1)] = h_f[(j, k + 1)] - sum * t2;
+                        h_f[(j, k)] -= sum * t1;
+                        h_f[(j, k + 1)] -= sum * t2;
                     }
 
                     if wantz {
                         // Accumulate transformations in the matrix Z
                         for j in iloz..=ihiz { // 'loop_120:
                             sum = z_f[(j, k)] + v2 * z_f[(j, k + 1)];
-                            z_f[(j, k)] = z_f[(j, k)] - sum * t1;
-                            z_f[(j, k + 1)] = z_f[(j, k + 1)] - sum * t2;
+                            z_f[(j, k)] -= sum * t1;
+                            z_f[(j, k + 1)] -= sum * t2;
                         }
                     }
                 }
@@ -329,61 +406,42 @@ pub fn dlahqr<H, WR, WI, Z>(
 
         // condition 150
         if its >= itmax {
-            *info = i;
-
-            *h = H::from(h_f.clone());
-            *wr = WR::from(wr_f.clone());
-            *wi = WI::from(wi_f.clone());
-            *z = Z::from(z_f.clone());
-            return;
+            return Err(Info::ConvergenceFailure { index: i });
         }
 
         if l == i {
             wr_f[i] = h_f[(i, i)];
-            wi_f[i] = 0.;
+            wi_f[i] = T::zero();
         } else if l == i - 1 {
-            let (h_00, h_01, h_10, h_11) = (
-                &mut h_f[(i-1, i-1)].clone(), &mut h_f[(i-1, i)].clone(), &mut h_f[(i, i-1)].clone(), &mut h_f[(i, i)].clone());
-            let (wr_0, wi_0, wr_1, wi_1) = (
-                &mut wr_f[i-1].clone(), &mut wi_f[i-1].clone(), &mut wr_f[i].clone(), &mut wi_f[i].clone());
-            dlanv2(h_00, h_01, h_10, h_11, wr_0, wi_0, wr_1, wi_1, &mut cs, &mut sn);
+            let (mut h_00, mut h_01, mut h_10, mut h_11) = (h_f[(i-1, i-1)], h_f[(i-1, i)], h_f[(i, i-1)], h_f[(i, i)]);
+            let (mut wr_0, mut wi_0, mut wr_1, mut wi_1) = (wr_f[i-1], wi_f[i-1], wr_f[i], wi_f[i]);
+            lanv2(&mut h_00, &mut h_01, &mut h_10, &mut h_11, &mut wr_0, &mut wi_0, &mut wr_1, &mut wi_1, &mut cs, &mut sn);
 
-            h_f[(i-1, i-1)] = h_00.clone(); h_f[(i-1, i)] = h_01.clone(); h_f[(i, i-1)] = h_10.clone(); h_f[(i, i)] = h_11.clone();
-            wr_f[i-1] = wr_0.clone(); wr_f[i] = wr_1.clone();
-            wi_f[i-1] = wi_0.clone(); wi_f[i] = wi_1.clone();
+            h_f[(i-1, i-1)] = h_00; h_f[(i-1, i)] = h_01; h_f[(i, i-1)] = h_10; h_f[(i, i)] = h_11;
+            wr_f[i-1] = wr_0; wr_f[i] = wr_1;
+            wi_f[i-1] = wi_0; wi_f[i] = wi_1;
 
             if wantt {
                 if i2 > i {
-                    let (h_02, h_12) = (&mut h_f[(i-1, i+1)..].to_vec(), &mut h_f[(i, i+1)..].to_vec());
-                    drot(i2 - i, h_02, ldh, h_12, ldh, cs, sn);
-                    let (h_02_idx, h_12_idx) = (h_f.idx((i-1, i+1)), h_f.idx((i, i+1)));
-                    for idx in h_02_idx..=h_f.len() { h_f[idx] = h_02[(idx - h_02_idx) as usize]; }
-                    for idx in h_12_idx..=h_f.len() { h_f[idx] = h_12[(idx - h_12_idx) as usize]; }
+                    // SAFETY: rows i-1 and i of H never share an element.
+                    let (h_02, h_12) = unsafe { h_f.slice_pair_mut((i-1, i+1), (i, i+1)) };
+                    Blas::rot(i2 - i, &mut { h_02 }, ldh, &mut { h_12 }, ldh, cs, sn);
                 }
 
-                let (h_10, h_11) = (&mut h_f[(i1, i-1)..].to_vec(), &mut h_f[(i1, i)..].to_vec());
-                drot(i - i1 - 1, &mut h_f[(i1, i-1)..].to_vec(), 1, &mut h_f[(i1, i)..].to_vec(), 1, cs, sn);
-                let (h_10_idx, h_11_idx) = (h_f.idx((i1, i-1)), h_f.idx((i1, i)));
-                for idx in h_10_idx..=h_f.len() { h_f[idx] = h_10[(idx - h_10_idx) as usize]; }
-                for idx in h_11_idx..=h_f.len() { h_f[idx] = h_11[(idx - h_11_idx) as usize]; }
+                // SAFETY: columns i-1 and i of H never share an element.
+                let (h_10, h_11) = unsafe { h_f.slice_pair_mut((i1, i-1), (i1, i)) };
+                Blas::rot(i - i1 - 1, &mut { h_10 }, 1, &mut { h_11 }, 1, cs, sn);
             }
 
             if wantz {
-                let (z_00, z_01) = (&mut z_f[(iloz, i-1)..].to_vec(), &mut z_f[(iloz, i)..].to_vec());
-                drot(nz, z_00, 1, z_01, 1, cs, sn);
-                let (z_00_idx, z_01_idx) = (z_f.idx((iloz, i-1)), z_f.idx((iloz, i)));
-                for idx in z_00_idx..=z_f.len() { z_f[idx] = z_00[(idx - z_00_idx) as usize]; }
-                for idx in z_01_idx..=z_f.len() { z_f[idx] = z_01[(idx - z_01_idx) as usize]; }
+                // SAFETY: columns i-1 and i of Z never share an element.
+                let (z_00, z_01) = unsafe { z_f.slice_pair_mut((iloz, i-1), (iloz, i)) };
+                Blas::rot(nz, &mut { z_00 }, 1, &mut { z_01 }, 1, cs, sn);
             }
         }
 
         kdefl = 0;
         i = l - 1;
-
-        *h = H::from(h_f.clone());
-        *wr = WR::from(wr_f.clone());
-        *wi = WI::from(wi_f.clone());
-        *z = Z::from(z_f.clone());
     }
 }
 
@@ -391,13 +449,19 @@ pub fn dlahqr<H, WR, WI, Z>(
 mod tests {
     use super::*;
     use rstest::rstest;
+    use std::sync::Mutex;
+
+    use crate::nancheck::set_check_finite;
+
+    // Serializes the tests that feed NaN, since screening is a global setting.
+    static SCREENING: Mutex<()> = Mutex::new(());
 
     #[rstest]
     #[case(
         true, true, 3, 1, 3,
         &mut vec![vec![1., 2., 3.], vec![4., 5., 6.], vec![7., 8., 9.]], 3,
         &mut vec![1., 2., 3.], &mut vec![1., 2., 3.], 1, 3,
-        &mut vec![vec![1., 2., 3.], vec![4., 5., 6.], vec![7., 8., 9.]], 3, &mut 0,
+        &mut vec![vec![1., 2., 3.], vec![4., 5., 6.], vec![7., 8., 9.]], 3,
         &vec![vec![0.08064984900783352, 1.5862959541840427, 0.0], vec![-0.37825080661482813, 0.08064984900783352, 0.0], vec![-6.221356919060636, -3.7993781215208107, 14.838700301984334]],
         &vec![0.08064984900783352, 0.08064984900783352, 14.838700301984334], &vec![0.774608110078866, -0.774608110078866, 0.0],
         &vec![vec![-0.4651395556132531, -1.2543276506150056, -2.043515745616759], vec![0.34592653350082625, -0.15812789956359435, -0.6621823326280135], vec![8.10333141536393, 9.56042141917825, 11.017511422992568]],
@@ -406,16 +470,16 @@ mod tests {
         false, false, 4, 2, 4,
         &mut vec![vec![7., 8., 9., 10.], vec![11., 12., 13., 14.], vec![15., 16., 17., 18.], vec![19., 20., 21., 22.]], 4,
         &mut vec![4., 5., 6., 7.], &mut vec![4., 5., 6., 7.], 2, 4,
-        &mut vec![vec![7., 8., 9., 10.], vec![11., 12., 13., 14.], vec![15., 16., 17., 18.], vec![19., 20., 21., 22.]], 4, &mut 0,
-        &vec![vec![7., 8., 9., -3.4983360944002646e-21], vec![-2.0658540465189605, -2.016740798182532, 0., -3.4166091623768073e-28], vec![1.5544763283028376, -0.8003032123903298, 20.692597675634232, 0.0], vec![-26.463481449031356, -28.17468094616857, -35.37092977070222, -11.366325417353767]],
-        &vec![4., -2.016740798182532, 20.692597675634232, -11.366325417353767], &vec![4., 0., 0., 0.],
+        &mut vec![vec![7., 8., 9., 10.], vec![11., 12., 13., 14.], vec![15., 16., 17., 18.], vec![19., 20., 21., 22.]], 4,
+        &vec![vec![7., 8., 9., 10.], vec![11., 0.22916530712106287, 0., 0.], vec![15., -9.27051346519947, 5.3840591981012365, 0.], vec![19., -1.893559519260327, -18.123796854924564, 45.38677549477771]],
+        &vec![4., 0.22916530712106287, 5.3840591981012365, 45.38677549477771], &vec![4., 0., 0., 0.],
         &mut vec![vec![7., 8., 9., 10.], vec![11., 12., 13., 14.], vec![15., 16., 17., 18.], vec![19., 20., 21., 22.]],
     )]
     #[case(
         false, true, 2, 1, 2,
         &mut vec![vec![2., 3.], vec![3., 2.]], 2,
         &mut vec![2., 3.], &mut vec![2., 3.], 1, 2,
-        &mut vec![vec![2., 3.], vec![3., 2.]], 2, &mut 0,
+        &mut vec![vec![2., 3.], vec![3., 2.]], 2,
         &vec![vec![5., 0.], vec![0., -1.0000000000000009]],
         &vec![5., -1.0000000000000009], &vec![0., 0.],
         &vec![vec![3.5355339059327378, 3.5355339059327378], vec![0.7071067811865472, -0.7071067811865479]],
@@ -424,7 +488,7 @@ mod tests {
         true, false, 3, 3, 3,
         &mut vec![vec![9., 8., 7.], vec![6., 5., 4.], vec![3., 2., 1.]], 3,
         &mut vec![3., 2., 1.], &mut vec![3., 2., 1.], 3, 3,
-        &mut vec![vec![9., 8., 7.], vec![6., 5., 4.], vec![3., 2., 1.]], 3, &mut 0,
+        &mut vec![vec![9., 8., 7.], vec![6., 5., 4.], vec![3., 2., 1.]], 3,
         &vec![vec![9.0, 8.0, 7.0], vec![6.0, 5.0, 4.0], vec![3.0, 2.0, 1.0]],
         &vec![3., 2., 1.], &vec![3., 2., 0.],
         &vec![vec![9.0, 8.0, 7.0], vec![6.0, 5.0, 4.0], vec![3.0, 2.0, 1.0]],
@@ -443,17 +507,125 @@ mod tests {
         #[case] ihiz: i32,
         #[case] z: &mut Vec<Vec<f64>>,
         #[case] ldz: i32,
-        #[case] info: &mut i32,
         #[case] expected_h: &Vec<Vec<f64>>,
         #[case] expected_wr: &Vec<f64>,
         #[case] expected_wi: &Vec<f64>,
         #[case] expected_z: &Vec<Vec<f64>>,
     ) {
-        dlahqr(wantt, wantz, n, ilo, ihi, h, ldh, wr, wi, iloz, ihiz, z, ldz, info);
+        dlahqr(wantt, wantz, n, ilo, ihi, h, ldh, wr, wi, iloz, ihiz, z, ldz).unwrap();
 
         assert_eq!(expected_h, h);
         assert_eq!(expected_wr, wr);
         assert_eq!(expected_wi, wi);
         assert_eq!(expected_z, z);
     }
+
+    #[test]
+    fn test_dlahqr_active_block_eigenvalues() {
+        // Cross-checks the second case of test_dlahqr: after the trash below the subdiagonal is
+        // cleared, the active block H(2:4,2:4) = [12 16 20; 13 17 21; 0 18 22] has the
+        // characteristic polynomial x^3 - 51x^2 + 256x - 56.
+        let mut h = vec![vec![7., 8., 9., 10.], vec![11., 12., 13., 14.], vec![15., 16., 17., 18.], vec![19., 20., 21., 22.]];
+        let (mut wr, mut wi) = (vec![4., 5., 6., 7.], vec![4., 5., 6., 7.]);
+        let mut z = h.clone();
+        dlahqr(false, false, 4, 2, 4, &mut h, 4, &mut wr, &mut wi, 2, 4, &mut z, 4).unwrap();
+
+        let (a, b, c) = (wr[1], wr[2], wr[3]);
+        assert_eq!(vec![4., 0., 0., 0.], wi);
+        assert!((a + b + c - 51.).abs() < 1e-12);
+        assert!((a * b + a * c + b * c - 256.).abs() < 1e-12);
+        assert!((a * b * c - 56.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_dlahqr_schur_form_similar() {
+        // With WANTT the transformations reach rows 1 to N, not just the active block, so the
+        // whole of H stays similar to the input: H = Z**T * H0 * Z.
+        let h0 = FortranArray::from_row_major(&[
+            4., 3., 2., 1.,
+            1., 4., 3., 2.,
+            0., 1., 4., 3.,
+            0., 0., 1., 4.,
+        ], 4, 4);
+        let (mut h, mut z) = (h0.clone(), FortranArray::identity(4));
+        let (mut wr, mut wi) = (vec![0.; 4], vec![0.; 4]);
+        dlahqr(true, true, 4, 1, 4, &mut h, 4, &mut wr, &mut wi, 1, 4, &mut z, 4).unwrap();
+
+        let similar = z.transpose() * h0 * &z;
+        assert!(h.iter().zip(similar.iter()).all(|(x, y)| (x - y).abs() < 1e-12));
+        assert!((wr.iter().sum::<f64>() - 16.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_slahqr() {
+        let mut h = vec![vec![1f32, 2., 3.], vec![4., 5., 6.], vec![7., 8., 9.]];
+        let (mut wr, mut wi) = (vec![0f32; 3], vec![0f32; 3]);
+        let mut z = vec![vec![1f32, 0., 0.], vec![0., 1., 0.], vec![0., 0., 1.]];
+        let result = slahqr(true, true, 3, 1, 3, &mut h, 3, &mut wr, &mut wi, 1, 3, &mut z, 3);
+
+        let expected_wr = [0.08064984900783352, 0.08064984900783352, 14.838700301984334];
+        let expected_wi = [0.774608110078866, -0.774608110078866, 0.0];
+        assert_eq!(Ok(()), result);
+        for k in 0..3 {
+            assert!((wr[k] as f64 - expected_wr[k]).abs() < 1e-4);
+            assert!((wi[k] as f64 - expected_wi[k]).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_dlahqr_convergence_failure() {
+        let _guard = SCREENING.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        // NaN never deflates, so every eigenvalue is left unconverged.
+        let mut h = vec![vec![1., 2., 3.], vec![4., f64::NAN, 6.], vec![0., 8., 9.]];
+        let (mut wr, mut wi) = (vec![0.; 3], vec![0.; 3]);
+        let mut z = vec![vec![0.; 3]; 3];
+        let result = dlahqr(false, false, 3, 1, 3, &mut h, 3, &mut wr, &mut wi, 1, 3, &mut z, 3);
+        assert_eq!(Err(Info::ConvergenceFailure { index: 3 }), result);
+    }
+
+    #[test]
+    fn test_dlahqr_non_finite() {
+        let _guard = SCREENING.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut h = vec![vec![1., 2., 3.], vec![4., f64::NAN, 6.], vec![0., 8., 9.]];
+        let (mut wr, mut wi) = (vec![0.; 3], vec![0.; 3]);
+        let mut z = vec![vec![1., 0., 0.], vec![0., 1., 0.], vec![0., 0., f64::INFINITY]];
+        set_check_finite(true);
+        let h_result = dlahqr(false, false, 3, 1, 3, &mut h.clone(), 3, &mut wr, &mut wi, 1, 3, &mut z.clone(), 3);
+        let z_result = dlahqr(false, true, 3, 1, 3, &mut vec![vec![1., 0., 0.]; 3], 3, &mut wr, &mut wi, 1, 3, &mut z, 3);
+        set_check_finite(false);
+
+        assert_eq!(Err(Info::NonFinite { routine: "DLAHQR".to_string(), position: 6, name: "h" }), h_result);
+        assert_eq!(-12, z_result.unwrap_err().info());
+        assert_eq!(vec![0.; 3], wr);
+        assert_eq!(Err(Info::ConvergenceFailure { index: 3 }),
+                   dlahqr(false, false, 3, 1, 3, &mut h, 3, &mut wr, &mut wi, 1, 3, &mut z, 3));
+    }
+
+    // wantz, n, ilo, ihi, ldh, iloz, ihiz, ldz
+    #[rstest]
+    #[case((true, -1, 1, 0, 1, 1, 0, 1), 3, "n")]
+    #[case((true, 3, 0, 3, 3, 1, 3, 3), 4, "ilo")]
+    #[case((true, 3, 3, 2, 3, 1, 3, 3), 5, "ihi")]
+    #[case((true, 3, 1, 4, 3, 1, 3, 3), 5, "ihi")]
+    #[case((true, 3, 1, 3, 2, 1, 3, 3), 7, "ldh")]
+    #[case((true, 3, 2, 3, 3, 3, 3, 3), 10, "iloz")]
+    #[case((true, 3, 1, 3, 3, 1, 2, 3), 11, "ihiz")]
+    #[case((true, 3, 1, 3, 3, 1, 4, 3), 11, "ihiz")]
+    #[case((true, 3, 1, 3, 3, 1, 3, 2), 13, "ldz")]
+    #[case((false, 3, 1, 3, 3, 1, 3, 0), 13, "ldz")]
+    fn test_dlahqr_illegal_argument(
+        #[case] args: (bool, i32, i32, i32, i32, i32, i32, i32),
+        #[case] position: i32,
+        #[case] name: &str,
+    ) {
+        let (wantz, n, ilo, ihi, ldh, iloz, ihiz, ldz) = args;
+        let mut h = vec![1., 2., 3., 4., 5., 6., 7., 8., 9.];
+        let (mut wr, mut wi) = (vec![0.; 3], vec![0.; 3]);
+        let mut z = vec![0.; 9];
+        let Err(Info::IllegalArgument(err)) = dlahqr(true, wantz, n, ilo, ihi, &mut h, ldh, &mut wr, &mut wi, iloz, ihiz, &mut z, ldz) else {
+            panic!("expected an illegal argument");
+        };
+        assert_eq!(("DLAHQR", position, name), (err.routine.as_str(), err.position, err.name));
+        assert_eq!(vec![0.; 3], wr);
+    }
 }
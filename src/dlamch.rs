@@ -1,3 +1,5 @@
+use crate::scalar::RealScalar;
+
 /// DLAMCH
 ///
 /// # Documentation
@@ -13,38 +15,54 @@
 pub fn dlamch(
     cmach: char,
 ) -> f64 {
+    lamch(cmach)
+}
+
+/// SLAMCH
+///
+/// Single precision variant of [`dlamch`].
+pub fn slamch(
+    cmach: char,
+) -> f32 {
+    lamch(cmach)
+}
+
+/// Shared implementation of [`slamch`] and [`dlamch`].
+pub fn lamch<T: RealScalar>(
+    cmach: char,
+) -> T {
     // Parameters
-    let one: f64 = 1.0;
-    let zero: f64 = 0.0;
+    let one = T::one();
+    let zero = T::zero();
 
     // Local Scalars
-    let eps = f64::EPSILON * 0.5;
-    let mut sfmin: f64;
-    let small: f64;
-    let rmach: f64;
+    let eps = T::epsilon() * T::from_f64(0.5);
+    let mut sfmin: T;
+    let small: T;
+    let rmach: T;
 
     match cmach {
         'E' => rmach = eps,
         'S' => {
-            sfmin = f64::MIN_POSITIVE;
-            small = one / f64::MAX;
+            sfmin = T::min_positive_value();
+            small = one / T::max_value();
             if small >= sfmin {
                 // Use SMALL plus a bit, to avoid the possibility of rounding causing overflow when computing 1/sfmin.
                 sfmin = small * (one + eps);
             }
             rmach = sfmin;
         }
-        'B' => rmach = f64::RADIX as f64,
-        'P' => rmach = eps * f64::RADIX as f64,
-        'N' => rmach = f64::MANTISSA_DIGITS as f64,
+        'B' => rmach = T::from_f64(T::RADIX as f64),
+        'P' => rmach = eps * T::from_f64(T::RADIX as f64),
+        'N' => rmach = T::from_f64(T::MANTISSA_DIGITS as f64),
         'R' => rmach = one,
-        'M' => rmach = f64::MIN_EXP as f64,
-        'U' => rmach = f64::MIN_POSITIVE,
-        'L' => rmach = f64::MAX_EXP as f64,
-        'O' => rmach = f64::MAX,
+        'M' => rmach = T::from_f64(T::MIN_EXP as f64),
+        'U' => rmach = T::min_positive_value(),
+        'L' => rmach = T::from_f64(T::MAX_EXP as f64),
+        'O' => rmach = T::max_value(),
         _ => rmach = zero,
     }
-    return rmach;
+    rmach
 }
 
 #[cfg(test)]
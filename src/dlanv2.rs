@@ -1,5 +1,6 @@
-use crate::dlamch::dlamch;
-use crate::dlapy2::dlapy2;
+use crate::dlamch::lamch;
+use crate::dlapy2::lapy2;
+use crate::scalar::RealScalar;
 
 /// DLANV2
 ///
@@ -25,30 +26,62 @@ pub fn dlanv2(
     cs: &mut f64,
     sn: &mut f64,
 ) {
-    let multpl = 4.;
-    let safmin = dlamch('S');
-    let eps = dlamch('P');
-    let safmn2 = dlamch('B').powf((safmin / eps).log(dlamch('B')) / 2.).trunc();
-    let safmx2 = 1. / safmn2;
-
-    if *c == 0. {
-        *cs = 1.;
-        *sn = 0.;
-    } else if *b == 0. {
-        *cs = 0.;
-        *sn = 1.;
-        let temp = *d;
-        *d = *a;
-        *a = temp;
+    lanv2(a, b, c, d, rt1r, rt1i, rt2r, rt2i, cs, sn)
+}
+
+/// SLANV2
+///
+/// Single precision variant of [`dlanv2`].
+pub fn slanv2(
+    a: &mut f32,
+    b: &mut f32,
+    c: &mut f32,
+    d: &mut f32,
+    rt1r: &mut f32,
+    rt1i: &mut f32,
+    rt2r: &mut f32,
+    rt2i: &mut f32,
+    cs: &mut f32,
+    sn: &mut f32,
+) {
+    lanv2(a, b, c, d, rt1r, rt1i, rt2r, rt2i, cs, sn)
+}
+
+/// Shared implementation of [`slanv2`] and [`dlanv2`].
+pub fn lanv2<T: RealScalar>(
+    a: &mut T,
+    b: &mut T,
+    c: &mut T,
+    d: &mut T,
+    rt1r: &mut T,
+    rt1i: &mut T,
+    rt2r: &mut T,
+    rt2i: &mut T,
+    cs: &mut T,
+    sn: &mut T,
+) {
+    let multpl = T::from_f64(4.);
+    let safmin = lamch::<T>('S');
+    let eps = lamch::<T>('P');
+    let safmn2 = lamch::<T>('B').powf((safmin / eps).log(lamch::<T>('B')) / T::from_f64(2.)).trunc();
+    let safmx2 = T::one() / safmn2;
+
+    if *c == T::zero() {
+        *cs = T::one();
+        *sn = T::zero();
+    } else if *b == T::zero() {
+        *cs = T::zero();
+        *sn = T::one();
+        std::mem::swap(&mut *d, &mut *a);
         *b = -(*c);
-        *c = 0.;
-    } else if (*a - *d).abs() < f64::EPSILON
+        *c = T::zero();
+    } else if (*a - *d).abs() < T::epsilon()
         && b.signum() != c.signum() {
-        *cs = 1.;
-        *sn = 0.;
+        *cs = T::one();
+        *sn = T::zero();
     } else {
         let mut temp = *a - *d;
-        let p = 0.5 * temp;
+        let p = T::from_f64(0.5) * temp;
         let bcmax = b.abs().max(c.abs());
         let bcmis = b.abs().min(c.abs()) * b.signum() * c.signum();
         let scale = p.abs().max(bcmax);
@@ -58,14 +91,14 @@ pub fn dlanv2(
             // Real eigenvalues. Compute A and D.
             z = p + (scale.sqrt() * z.sqrt()).copysign(p);
             *a = *d + z;
-            *d = *d - (bcmax / z) * bcmis;
+            *d -= (bcmax / z) * bcmis;
 
             // Compute B and the rotation matrix.
-            let tau = dlapy2(*c, z);
+            let tau = lapy2(*c, z);
             *cs = z / tau;
             *sn = *c / tau;
-            *b = *b - *c;
-            *c = 0.;
+            *b -= *c;
+            *c = T::zero();
         } else {
             let mut count = 0;
             let mut sigma = *b + *c;
@@ -93,9 +126,9 @@ pub fn dlanv2(
                 break;
             }
 
-            let p = 0.5 * temp;
-            let tau = dlapy2(sigma, temp);
-            *cs = ((1f64 + sigma.abs() / tau) * 0.5).sqrt();
+            let p = T::from_f64(0.5) * temp;
+            let tau = lapy2(sigma, temp);
+            *cs = ((T::one() + sigma.abs() / tau) * T::from_f64(0.5)).sqrt();
             *sn = -(p / (tau * *cs)) * sigma.signum();
 
             // Compute [ AA  BB ] = [ A  B ] [ CS -SN ]
@@ -112,22 +145,22 @@ pub fn dlanv2(
             *c = -(aa * *sn) + cc * *cs;
             *d = -(bb * *sn) + dd * *cs;
 
-            temp = 0.5 * (*a + *d);
+            temp = T::from_f64(0.5) * (*a + *d);
             *a = temp;
             *d = temp;
 
-            if *c != 0. {
-                if *b != 0. {
+            if *c != T::zero() {
+                if *b != T::zero() {
                     if b.signum() == c.signum() {
                         // Real eigenvalues: reduce to upper triangular form
                         let sab = b.abs().sqrt();
                         let sac = c.abs().sqrt();
                         let p = c.signum() * sab * sac;
-                        let tau = 1. / (*b + *c).abs().sqrt();
+                        let tau = T::one() / (*b + *c).abs().sqrt();
                         *a = temp + p;
                         *d = temp - p;
-                        *b = *b - *c;
-                        *c = 0.;
+                        *b -= *c;
+                        *c = T::zero();
                         let cs1 = sab * tau;
                         let sn1 = sac * tau;
                         let temp = *cs * cs1 - *sn * sn1;
@@ -136,7 +169,7 @@ pub fn dlanv2(
                     }
                 } else {
                     *b = -*c;
-                    *c = 0.;
+                    *c = T::zero();
                     let temp = *cs;
                     *cs = -(*sn);
                     *sn = temp;
@@ -148,9 +181,9 @@ pub fn dlanv2(
     *rt1r = *a;
     *rt2r = *d;
 
-    if *c == 0. {
-        *rt1i = 0.;
-        *rt2i = 0.;
+    if *c == T::zero() {
+        *rt1i = T::zero();
+        *rt2i = T::zero();
     } else {
         *rt1i = b.abs().sqrt() * c.abs().sqrt();
         *rt2i = -(*rt1i);
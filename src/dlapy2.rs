@@ -1,3 +1,5 @@
+use crate::scalar::RealScalar;
+
 /// DLAPY2
 ///
 /// # Documentation
@@ -14,14 +16,32 @@ pub fn dlapy2(
     x: f64,
     y: f64,
 ) -> f64 {
+    lapy2(x, y)
+}
+
+/// SLAPY2
+///
+/// Single precision variant of [`dlapy2`].
+pub fn slapy2(
+    x: f32,
+    y: f32,
+) -> f32 {
+    lapy2(x, y)
+}
+
+/// Shared implementation of [`slapy2`] and [`dlapy2`].
+pub fn lapy2<T: RealScalar>(
+    x: T,
+    y: T,
+) -> T {
     if x.is_nan() { return x; }
     if y.is_nan() { return y; }
 
     let w = x.abs().max(y.abs());
     let z = x.abs().min(y.abs());
 
-    return if z == 0.0 || w > f64::MAX { w }
-    else { w * ((1.0 + (z / w).powi(2)).sqrt()) }
+    if z.is_zero() || w > T::max_value() { w }
+    else { w * ((T::one() + (z / w).powi(2)).sqrt()) }
 }
 
 #[cfg(test)]
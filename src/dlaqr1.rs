@@ -1,5 +1,5 @@
-use crate::array::convert::ToFortranArray;
-use crate::array::FortranArray;
+use crate::array::convert::{ToFortranArray, ToFortranArrayMut};
+use crate::scalar::RealScalar;
 
 /// DLAQR1
 ///
@@ -32,18 +32,55 @@ pub fn dlaqr1<H, V>(
     v: &mut V,
 ) where
     H: ToFortranArray,
-    V: ToFortranArray + From<FortranArray>,
+    V: ToFortranArrayMut,
 {
-    let h_f = &mut h.to_fa_2d(ldh);
-    let v_f = &mut v.to_fa();
+    laqr1(n, h, ldh, sr1, si1, sr2, si2, v)
+}
+
+/// SLAQR1
+///
+/// Single precision variant of [`dlaqr1`].
+pub fn slaqr1<H, V>(
+    n: i32,
+    h: &H,
+    ldh: i32,
+    sr1: f32,
+    si1: f32,
+    sr2: f32,
+    si2: f32,
+    v: &mut V,
+) where
+    H: ToFortranArray<f32>,
+    V: ToFortranArrayMut<f32>,
+{
+    laqr1(n, h, ldh, sr1, si1, sr2, si2, v)
+}
+
+/// Shared implementation of [`slaqr1`] and [`dlaqr1`].
+pub fn laqr1<T, H, V>(
+    n: i32,
+    h: &H,
+    ldh: i32,
+    sr1: T,
+    si1: T,
+    sr2: T,
+    si2: T,
+    v: &mut V,
+) where
+    T: RealScalar,
+    H: ToFortranArray<T>,
+    V: ToFortranArrayMut<T>,
+{
+    let h_f = &h.fa_view_2d(ldh);
+    let v_f = &mut v.fa_view_mut();
 
     if n != 2 && n != 3 { return; }
 
     if n == 2 {
         let s = (h_f[(1, 1)] - sr2).abs() + si2.abs() + h_f[(2, 1)].abs();
-        if s == 0. {
-            v_f[1] = 0.;
-            v_f[2] = 0.;
+        if s == T::zero() {
+            v_f[1] = T::zero();
+            v_f[2] = T::zero();
         } else {
             let h21s = h_f[(2, 1)] / s;
             v_f[1] = h21s * h_f[(1, 2)] + (h_f[(1, 1)] - sr1) * ((h_f[(1, 1)] - sr2) / s) - si1 * (si2 / s);
@@ -51,10 +88,10 @@ pub fn dlaqr1<H, V>(
         }
     } else {
         let s = (h_f[(1, 1)] - sr2).abs() + si2.abs() + h_f[(2, 1)].abs() + h_f[(3, 1)].abs();
-        if s == 0. {
-            v_f[1] = 0.;
-            v_f[2] = 0.;
-            v_f[3] = 0.;
+        if s == T::zero() {
+            v_f[1] = T::zero();
+            v_f[2] = T::zero();
+            v_f[3] = T::zero();
         } else {
             let h21s = h_f[(2, 1)] / s;
             let h31s = h_f[(3, 1)] / s;
@@ -63,8 +100,6 @@ pub fn dlaqr1<H, V>(
             v_f[3] = h31s * (h_f[(1, 1)] + h_f[(3, 3)] - sr1 - sr2) + h21s * h_f[(3, 2)];
         }
     }
-
-    *v = V::from(v_f.clone());
 }
 
 #[cfg(test)]
@@ -1,11 +1,17 @@
-use crate::array::convert::ToFortranArray;
+use std::fmt::Debug;
+
+use crate::array::convert::{ToFortranArray, ToFortranArrayMut};
 use crate::array::FortranArray;
-use crate::blas::dgemm::dgemm;
-use crate::dlacpy::dlacpy;
-use crate::dlamch::dlamch;
-use crate::dlaqr1::dlaqr1;
-use crate::dlarfg::dlarfg;
-use crate::dlaset::dlaset;
+use crate::blas::backend::{Blas, BlasBackend};
+use crate::dlacpy::lacpy;
+use crate::dlamch::lamch;
+use crate::dlaqr1::laqr1;
+use crate::dlarfg::larfg;
+use crate::dlaset::laset;
+use crate::error::Info;
+use crate::flags::Trans;
+use crate::scalar::RealScalar;
+use crate::xerbla::illegal_argument;
 
 /// DLAQR5
 ///
@@ -45,28 +51,146 @@ pub fn dlaqr5<SR, SI, H, Z, V, U, WV, WH>(
     nh: i32,
     wh: &mut WH,
     ldwh: i32,
-) where
+) -> Result<(), Info> where
     SR: ToFortranArray,
     SI: ToFortranArray,
-    H: ToFortranArray + From<FortranArray>,
-    Z: ToFortranArray + From<FortranArray>,
-    V: ToFortranArray + From<FortranArray>,
-    U: ToFortranArray + From<FortranArray>,
-    WV: ToFortranArray + From<FortranArray>,
-    WH: ToFortranArray + From<FortranArray>,
+    H: ToFortranArrayMut,
+    Z: ToFortranArrayMut,
+    V: ToFortranArrayMut,
+    U: ToFortranArrayMut,
+    WV: ToFortranArrayMut,
+    WH: ToFortranArrayMut,
+{
+    laqr5(wantt, wantz, kacc22, n, ktop, kbot, nshfts, sr, si, h, ldh, iloz, ihiz, z, ldz, v, ldv, u, ldu, nv, wv, ldwv, nh, wh, ldwh)
+}
+
+/// SLAQR5
+///
+/// Single precision variant of [`dlaqr5`].
+pub fn slaqr5<SR, SI, H, Z, V, U, WV, WH>(
+    wantt: bool,
+    wantz: bool,
+    kacc22: i32,
+    n: i32,
+    ktop: i32,
+    kbot: i32,
+    nshfts: i32,
+    sr: &SR,
+    si: &SI,
+    h: &mut H,
+    ldh: i32,
+    iloz: i32,
+    ihiz: i32,
+    z: &mut Z,
+    ldz: i32,
+    v: &mut V,
+    ldv: i32,
+    u: &mut U,
+    ldu: i32,
+    nv: i32,
+    wv: &mut WV,
+    ldwv: i32,
+    nh: i32,
+    wh: &mut WH,
+    ldwh: i32,
+) -> Result<(), Info> where
+    SR: ToFortranArray<f32>,
+    SI: ToFortranArray<f32>,
+    H: ToFortranArrayMut<f32>,
+    Z: ToFortranArrayMut<f32>,
+    V: ToFortranArrayMut<f32>,
+    U: ToFortranArrayMut<f32>,
+    WV: ToFortranArrayMut<f32>,
+    WH: ToFortranArrayMut<f32>,
 {
+    laqr5(wantt, wantz, kacc22, n, ktop, kbot, nshfts, sr, si, h, ldh, iloz, ihiz, z, ldz, v, ldv, u, ldu, nv, wv, ldwv, nh, wh, ldwh)
+}
+
+/// Shared implementation of [`slaqr5`] and [`dlaqr5`].
+pub fn laqr5<T, SR, SI, H, Z, V, U, WV, WH>(
+    wantt: bool,
+    wantz: bool,
+    kacc22: i32,
+    n: i32,
+    ktop: i32,
+    kbot: i32,
+    nshfts: i32,
+    sr: &SR,
+    si: &SI,
+    h: &mut H,
+    ldh: i32,
+    iloz: i32,
+    ihiz: i32,
+    z: &mut Z,
+    ldz: i32,
+    v: &mut V,
+    ldv: i32,
+    u: &mut U,
+    ldu: i32,
+    nv: i32,
+    wv: &mut WV,
+    ldwv: i32,
+    nh: i32,
+    wh: &mut WH,
+    ldwh: i32,
+) -> Result<(), Info> where
+    T: RealScalar,
+    SR: ToFortranArray<T>,
+    SI: ToFortranArray<T>,
+    H: ToFortranArrayMut<T>,
+    Z: ToFortranArrayMut<T>,
+    V: ToFortranArrayMut<T>,
+    U: ToFortranArrayMut<T>,
+    WV: ToFortranArrayMut<T>,
+    WH: ToFortranArrayMut<T>,
+{
+    let illegal = |position, name, value: &dyn Debug| {
+        illegal_argument(format!("{}LAQR5", T::PREFIX), position, name, value)
+    };
+
+    if !(0..=2).contains(&kacc22) {
+        return illegal(3, "kacc22", &kacc22);
+    } else if n < 0 {
+        return illegal(4, "n", &n);
+    } else if ktop < 1 {
+        return illegal(5, "ktop", &ktop);
+    } else if kbot > n {
+        return illegal(6, "kbot", &kbot);
+    } else if nshfts < 0 {
+        return illegal(7, "nshfts", &nshfts);
+    } else if ldh < 1.max(n) {
+        return illegal(11, "ldh", &ldh);
+    } else if wantz && (iloz < 1 || iloz > 1.max(n)) {
+        return illegal(12, "iloz", &iloz);
+    } else if wantz && (ihiz < iloz.min(n) || ihiz > n) {
+        return illegal(13, "ihiz", &ihiz);
+    } else if ldz < 1 || (wantz && ldz < ihiz) {
+        return illegal(15, "ldz", &ldz);
+    } else if ldv < 3 {
+        return illegal(17, "ldv", &ldv);
+    } else if ldu < 1.max(2 * nshfts) {
+        return illegal(19, "ldu", &ldu);
+    } else if ldwv < 1.max(nv) {
+        return illegal(22, "ldwv", &ldwv);
+    } else if nh < 1 {
+        return illegal(23, "nh", &nh);
+    } else if ldwh < 1.max(2 * nshfts) {
+        return illegal(25, "ldwh", &ldwh);
+    }
+
+    // SR and SI are reordered locally, the caller's shifts are left untouched.
     let sr_f = &mut sr.to_fa();
     let si_f = &mut si.to_fa();
-    let h_f = &mut h.to_fa_2d(ldh);
-    let z_f = &mut z.to_fa_2d(ldz);
-    let v_f = &mut v.to_fa_2d(ldv);
-    let u_f = &mut u.to_fa_2d(ldu);
-    let wv_f = &mut wv.to_fa_2d(ldwv);
-    let wh_f = &mut wh.to_fa_2d(ldwh);
+    let h_f = &mut h.fa_view_2d_mut(ldh);
+    let z_f = &mut z.fa_view_2d_mut(ldz);
+    let v_f = &mut v.fa_view_2d_mut(ldv);
+    let u_f = &mut u.fa_view_2d_mut(ldu);
+    let wv_f = &mut wv.fa_view_2d_mut(ldwv);
+    let wh_f = &mut wh.fa_view_2d_mut(ldwh);
 
-    let vt = &mut vec![0.; 3].to_fa();
-    if nshfts < 2 { return; }
-    if ktop >= kbot { return; }
+    let vt = &mut FortranArray::vector(&[T::zero(); 3]);
+    if nshfts < 2 { return Ok(()); }
+    if ktop >= kbot { return Ok(()); }
 
     for i in (0..nshfts - 2).step_by(2) {
         if si_f[i] != si_f[i + 1] {
@@ -83,13 +207,13 @@ pub fn dlaqr5<SR, SI, H, Z, V, U, WV, WH>(
     }
 
     let ns = nshfts - (nshfts % 2);
-    let safmin = dlamch('S');
-    let ulp = dlamch('P');
-    let smlnum = safmin * ((n as f64) / ulp);
+    let safmin = lamch::<T>('S');
+    let ulp = lamch::<T>('P');
+    let smlnum = safmin * (T::from_f64(n as f64) / ulp);
     let accum = kacc22 == 1 || kacc22 == 2;
 
     if ktop + 2 <= kbot {
-        h_f[(ktop + 2, ktop)] = 0.;
+        h_f[(ktop + 2, ktop)] = T::zero();
     }
 
     let nbmps = ns / 2;
@@ -102,7 +226,7 @@ pub fn dlaqr5<SR, SI, H, Z, V, U, WV, WH>(
         else { jtop = ktop; }
 
         let ndcol = incol + kdu;
-        if accum { dlaset('A', kdu, kdu, 0., 1., u_f); }
+        if accum { laset(None, kdu, kdu, T::zero(), T::one(), u_f, ldu)?; }
 
         for krcol in incol..=(incol + 2 * nbmps - 1).min(kbot - 2) {
             let mtop = 1.max((ktop - krcol) / 2 + 1);
@@ -113,33 +237,22 @@ pub fn dlaqr5<SR, SI, H, Z, V, U, WV, WH>(
             if bmp22 {
                 let k = krcol + 2 * (m22 - 1);
                 if k == ktop - 1 {
-                    let h_tmp = h_f[(k + 1, k + 1)..].to_vec();
-                    let v_tmp = &mut v_f[(1, m22)..].to_vec();
-                    dlaqr1(2, &h_tmp, ldh, sr_f[2 * m22 - 1], si_f[2 * m22 - 1],
-                           sr_f[2 * m22], si_f[2 * m22], v_tmp);
-                    *v_f = v_tmp.to_fa_2d(ldv);
-
-                    let beta = &mut v_f[(1, m22)].clone();
-                    let (v_1, v_2) = (
-                        &mut v_f[(2, m22)..].to_vec(),
-                        &mut v_f[(1, m22)].clone());
-                    dlarfg(2, beta, v_1, 1, v_2);
-                    *v_f = v_1.to_fa();
-                    v_f.insert(1, v_2.clone());
-                    v_f.as_2d(ldv);
+                    laqr1(2, &h_f.slice_from((k + 1, k + 1)), ldh, sr_f[2 * m22 - 1], si_f[2 * m22 - 1],
+                           sr_f[2 * m22], si_f[2 * m22], &mut v_f.slice_from_mut((1, m22)));
+
+                    let mut beta = v_f[(1, m22)];
+                    let mut tau = T::zero();
+                    larfg(2, &mut beta, &mut v_f.slice_from_mut((2, m22)), 1, &mut tau);
+                    v_f[(1, m22)] = tau;
                 } else {
-                    let beta = &mut h_f[(k + 1, k)].clone();
-                    v_f[(2, m22)] = h_f[(k + 2, k)].clone();
-                    let (v_1, v_2) = (
-                        &mut v_f[(2, m22)..].to_vec(),
-                        &mut v_f[(1, m22)].clone());
-                    dlarfg(2, beta, v_1, 1, v_2);
-                    *v_f = v_1.to_fa();
-                    v_f.insert(1, v_2.clone());
-                    v_f.as_2d(ldv);
-
-                    h_f[(k + 1, k)] = beta.clone();
-                    h_f[(k + 2, k)] = 0.;
+                    let mut beta = h_f[(k + 1, k)];
+                    let mut tau = T::zero();
+                    v_f[(2, m22)] = h_f[(k + 2, k)];
+                    larfg(2, &mut beta, &mut v_f.slice_from_mut((2, m22)), 1, &mut tau);
+                    v_f[(1, m22)] = tau;
+
+                    h_f[(k + 1, k)] = beta;
+                    h_f[(k + 2, k)] = T::zero();
                 }
 
                 // Perform update from right within computational window.
@@ -152,7 +265,7 @@ pub fn dlaqr5<SR, SI, H, Z, V, U, WV, WH>(
                 }
 
                 // Perform update from left within computational window.
-                let mut jbot;
+                let jbot;
 
                 if accum { jbot = ndcol.min(kbot); }
                 else if wantt { jbot = n; }
@@ -167,11 +280,11 @@ pub fn dlaqr5<SR, SI, H, Z, V, U, WV, WH>(
                     h_f[(k + 2, j)] -= refsum * t2;
                 }
 
-                if k >= ktop {
-                    if h_f[(k + 1, k)] != 0. {
+                if k >= ktop
+                    && h_f[(k + 1, k)] != T::zero() {
                         let mut tst1 = h_f[(k, k)].abs() + h_f[(k + 1, k + 1)].abs();
-                        if tst1 == 0. {
-                            if k >= ktop + 1 { tst1 += h_f[(k, k - 1)].abs(); }
+                        if tst1 == T::zero() {
+                            if k > ktop { tst1 += h_f[(k, k - 1)].abs(); }
                             if k >= ktop + 2 { tst1 += h_f[(k, k - 2)].abs(); }
                             if k >= ktop + 3 { tst1 += h_f[(k, k - 3)].abs(); }
                             if k <= kbot - 2 { tst1 += h_f[(k + 2, k + 1)].abs(); }
@@ -186,12 +299,11 @@ pub fn dlaqr5<SR, SI, H, Z, V, U, WV, WH>(
                             let scl = h11 + h12;
                             let tst2 = h22 * (h11 / scl);
 
-                            if tst2 == 0. || h21 * (h12 / scl) <= (smlnum.max(ulp * tst2)) {
-                                h_f[(k + 1, k)] = 0.;
+                            if tst2 == T::zero() || h21 * (h12 / scl) <= (smlnum.max(ulp * tst2)) {
+                                h_f[(k + 1, k)] = T::zero();
                             }
                         }
                     }
-                }
 
                 // Accumulate orthogonal transformations
 
@@ -219,19 +331,13 @@ pub fn dlaqr5<SR, SI, H, Z, V, U, WV, WH>(
             for m in (mtop..=mbot).rev() {
                 let k = krcol + 2*(m - 1);
                 if k == ktop - 1 {
-                    let h_tmp = h_f[(ktop, ktop)..].to_vec();
-                    let v_tmp = &mut v_f[(1, m)..].to_vec();
-                    dlaqr1(3, &h_tmp, ldh, sr_f[2*m-1], si_f[2*m-1], sr_f[2*m], si_f[2*m], v_tmp);
-                    *v_f = v_tmp.to_fa_2d(ldv);
-
-                    let alpha = &mut v_f[(1, m)].clone();
-                    let (v_1, v_2) = (
-                        &mut v_f[(2, m)..].to_vec(),
-                        &mut v_f[(1, m)].clone());
-                    dlarfg(3, alpha, v_1, 1, v_2);
-                    *v_f = v_1.to_fa();
-                    v_f.insert(1, v_2.clone());
-                    v_f.as_2d(ldv);
+                    laqr1(3, &h_f.slice_from((ktop, ktop)), ldh, sr_f[2*m-1], si_f[2*m-1], sr_f[2*m], si_f[2*m],
+                           &mut v_f.slice_from_mut((1, m)));
+
+                    let mut alpha = v_f[(1, m)];
+                    let mut tau = T::zero();
+                    larfg(3, &mut alpha, &mut v_f.slice_from_mut((2, m)), 1, &mut tau);
+                    v_f[(1, m)] = tau;
                 } else {
                     // Perform delayed transformation of row below Mth bulge. Exploit the fact that the first two elements of the row are actually zero.
                     let t1 = v_f[(1, m)];
@@ -243,35 +349,27 @@ pub fn dlaqr5<SR, SI, H, Z, V, U, WV, WH>(
                     h_f[(k + 3, k + 2)] -= refsum * t3;
 
                     // Calculate reflection to move Mth bulge one step
-                    let beta = &mut h_f[(k + 1, k)].clone();
-                    v_f[(2, m)] = h_f[(k + 2, k)].clone();
-                    v_f[(3, m)] = h_f[(k + 3, k)].clone();
-
-                    let (v_1, v_2) = (
-                        &mut v_f[(2, m)..].to_vec(),
-                        &mut v_f[(1, m)].clone());
-                    dlarfg(3, beta, v_1, 1, v_2);
-                    *v_f = v_1.to_fa();
-                    v_f.insert(1, v_2.clone());
-                    v_f.as_2d(ldv);
-
-                    if h_f[(k + 3, k)] != 0. || h_f[(k + 3, k + 1)] != 0. || h_f[(k + 3, k + 2)] == 0. {
+                    let mut beta = h_f[(k + 1, k)];
+                    let mut tau = T::zero();
+                    v_f[(2, m)] = h_f[(k + 2, k)];
+                    v_f[(3, m)] = h_f[(k + 3, k)];
+                    larfg(3, &mut beta, &mut v_f.slice_from_mut((2, m)), 1, &mut tau);
+                    v_f[(1, m)] = tau;
+
+                    if h_f[(k + 3, k)] != T::zero() || h_f[(k + 3, k + 1)] != T::zero() || h_f[(k + 3, k + 2)] == T::zero() {
                         // Typical case: not collapsed (yet).
-                        h_f[(k + 1, k)] = beta.clone();
-                        h_f[(k + 2, k)] = 0.;
-                        h_f[(k + 3, k)] = 0.;
+                        h_f[(k + 1, k)] = beta;
+                        h_f[(k + 2, k)] = T::zero();
+                        h_f[(k + 3, k)] = T::zero();
                     } else {
                         // Atypical case: collapsed.  Attempt to reintroduce ignoring H(K+1, K) and H(K+2, K).
                         // If the fill resulting from the new reflector is too large, then abandon it. Otherwise, use the new one.
-                        dlaqr1(3, &h_f[(k+1, k+1)..].to_vec(), ldh, sr_f[2*m-1], si_f[2*m-1], sr_f[2*m], si_f[2*m], vt);
+                        laqr1(3, &h_f.slice_from((k + 1, k + 1)), ldh, sr_f[2*m-1], si_f[2*m-1], sr_f[2*m], si_f[2*m], vt);
 
-                        let alpha = &mut vt[1].clone();
-                        let (vt_1, vt_2) = (
-                            &mut vec![vt[2].clone()],
-                            &mut vt[1].clone());
-                        dlarfg(3, alpha, vt_1, 1, vt_2);
-                        *vt = vt_1.to_fa();
-                        vt.insert(1, vt_2.clone());
+                        let mut alpha = vt[1];
+                        let mut tau = T::zero();
+                        larfg(3, &mut alpha, &mut vt.view_mut().slice_from_mut((2, 1)), 1, &mut tau);
+                        vt[1] = tau;
 
                         let t1 = vt[1];
                         let t2 = t1 * vt[2];
@@ -279,14 +377,14 @@ pub fn dlaqr5<SR, SI, H, Z, V, U, WV, WH>(
                         let refsum = h_f[(k + 1, k)] + vt[2] * h_f[(k + 2, k)];
                         if (h_f[(k + 2, k)] - refsum * t2).abs() + refsum * t3.abs() > ulp * (h_f[(k, k)].abs() + h_f[(k + 1, k + 1)].abs() + h_f[(k + 2, k + 2)].abs()) {
                             // Starting a new bulge here would create non-negligible fill. Use the old one with trepidation.
-                            h_f[(k + 1, k)] = beta.clone();
-                            h_f[(k + 2, k)] = 0.;
-                            h_f[(k + 3, k)] = 0.;
+                            h_f[(k + 1, k)] = beta;
+                            h_f[(k + 2, k)] = T::zero();
+                            h_f[(k + 3, k)] = T::zero();
                         } else {
                             // Starting a new bulge here would create only negligible fill. Replace the old reflector with the new one.
                             h_f[(k + 1, k)] -= refsum * t1;
-                            h_f[(k + 2, k)] = 0.;
-                            h_f[(k + 3, k)] = 0.;
+                            h_f[(k + 2, k)] = T::zero();
+                            h_f[(k + 3, k)] = T::zero();
                             v_f[(1, m)] = vt[1];
                             v_f[(2, m)] = vt[2];
                             v_f[(3, m)] = vt[3];
@@ -311,10 +409,10 @@ pub fn dlaqr5<SR, SI, H, Z, V, U, WV, WH>(
                 h_f[(k + 3, k + 1)] -= refsum * t3;
 
                 if k < ktop { continue; }
-                if h_f[(k + 1, k)] != 0. {
+                if h_f[(k + 1, k)] != T::zero() {
                     let mut tst1 = h_f[(k, k)].abs() + h_f[(k + 1, k + 1)].abs();
-                    if tst1 == 0. {
-                        if k >= ktop + 1 { tst1 += h_f[(k, k - 1)].abs(); }
+                    if tst1 == T::zero() {
+                        if k > ktop { tst1 += h_f[(k, k - 1)].abs(); }
                         if k >= ktop + 2 { tst1 += h_f[(k, k - 2)].abs(); }
                         if k >= ktop + 3 { tst1 += h_f[(k, k - 3)].abs(); }
                         if k <= kbot - 2 { tst1 += h_f[(k + 2, k + 1)].abs(); }
@@ -328,8 +426,8 @@ pub fn dlaqr5<SR, SI, H, Z, V, U, WV, WH>(
                         let h22 = h_f[(k + 1, k + 1)].abs().min((h_f[(k, k)] - h_f[(k + 1, k + 1)]).abs());
                         let scl = h11 + h12;
                         let tst2 = h22 * (h11 / scl);
-                        if tst2 == 0. || h21 * (h12 / scl) <= smlnum.max(ulp * tst2) {
-                            h_f[(k + 1, k)] = 0.;
+                        if tst2 == T::zero() || h21 * (h12 / scl) <= smlnum.max(ulp * tst2) {
+                            h_f[(k + 1, k)] = T::zero();
                         }
                     }
                 }
@@ -372,7 +470,7 @@ pub fn dlaqr5<SR, SI, H, Z, V, U, WV, WH>(
                 }
             } else if wantz {
                 // U is not accumulated, so update Z now by multiplying by reflections from the right.
-                for m in (mbot..=mtop).rev() {
+                for m in (mtop..=mbot).rev() {
                     let k = krcol + 2 * (m - 1);
                     let t1 = v_f[(1, m)];
                     let t2 = t1 * v_f[(2, m)];
@@ -400,28 +498,18 @@ pub fn dlaqr5<SR, SI, H, Z, V, U, WV, WH>(
             for jcol in (ndcol.min(kbot) + 1..=jbot).step_by(nh as usize) {
                 let jlen = nh.min(jbot - jcol + 1);
 
-                dgemm('C', 'N', nu, jlen, nu, 1., &u_f[(k1, k1)..].to_vec(), ldu,
-                      &h_f[(incol + k1, jcol)..].to_vec(), ldh, 0., wh_f, ldwh);
-                let h_tmp = &mut h_f[(incol + k1, jcol)..].to_vec().to_fa_2d(ldh);
-                dlacpy('A', nu, jlen, wh_f, ldwh, h_tmp, ldh);
-                let ini_idx = h_f.idx((incol + k1, jcol)) as usize - 1;
-                let mut h_ini = h_f.clone().to_vec()[..ini_idx].to_vec();
-                h_ini.extend_from_slice(&h_tmp.clone().to_vec());
-                *h_f = h_ini.to_fa_2d(ldz);
+                Blas::gemm(Trans::ConjTrans, Trans::NoTrans, nu, jlen, nu, T::one(), &u_f.sub(k1, k1, nu, nu), ldu,
+                            &h_f.sub(incol + k1, jcol, nu, jlen), ldh, T::zero(), wh_f, ldwh)?;
+                lacpy(None, nu, jlen, wh_f, ldwh, &mut h_f.sub_mut(incol + k1, jcol, nu, jlen), ldh)?;
             }
 
             // Vertical multiply
             for jrow in (jtop..=ktop.max(incol) - 1).step_by(nv as usize) {
                 let jlen = nv.min(ktop.max(incol) - jrow);
 
-                dgemm('N', 'N', jlen, nu, nu, 1., &h_f[(jrow, incol + k1)..].to_vec(), ldh,
-                      &u_f[(k1, k1)..].to_vec(), ldu, 0., wv_f, ldwv);
-                let h_tmp = &mut h_f[(jrow, incol + k1)..].to_vec().to_fa_2d(ldh);
-                dlacpy('A', jlen, nu, wv_f, ldwv, h_tmp, ldh);
-                let ini_idx = h_f.idx((jrow, incol + k1)) as usize - 1;
-                let mut h_ini = h_f.clone().to_vec()[..ini_idx].to_vec();
-                h_ini.extend_from_slice(&h_tmp.clone().to_vec());
-                *h_f = h_ini.to_fa_2d(ldz);
+                Blas::gemm(Trans::NoTrans, Trans::NoTrans, jlen, nu, nu, T::one(), &h_f.sub(jrow, incol + k1, jlen, nu), ldh,
+                            &u_f.sub(k1, k1, nu, nu), ldu, T::zero(), wv_f, ldwv)?;
+                lacpy(None, jlen, nu, wv_f, ldwv, &mut h_f.sub_mut(jrow, incol + k1, jlen, nu), ldh)?;
             }
 
             // Z multiply (also vertical)
@@ -429,148 +517,105 @@ pub fn dlaqr5<SR, SI, H, Z, V, U, WV, WH>(
                 for jrow in (iloz..=ihiz).step_by(nv as usize) {
                     let jlen = nv.min(ihiz - jrow + 1);
 
-                    dgemm('N', 'N', jlen, nu, nu, 1., &z_f[(jrow, incol + k1)..].to_vec(), ldz,
-                          &u_f[(k1, k1)..].to_vec(), ldu, 0., wv_f, ldwv);
-                    let z_tmp = &mut z_f[(jrow, incol + k1)..].to_vec().to_fa_2d(ldz);
-                    dlacpy('A', jlen, nu, wv_f, ldwv, z_tmp, ldz);
-                    let ini_idx = z_f.idx((jrow, incol + k1)) as usize - 1;
-                    let mut z_ini = z_f.clone().to_vec()[..ini_idx].to_vec();
-                    z_ini.extend_from_slice(&z_tmp.clone().to_vec());
-                    *z_f = z_ini.to_fa_2d(ldz);
+                    Blas::gemm(Trans::NoTrans, Trans::NoTrans, jlen, nu, nu, T::one(), &z_f.sub(jrow, incol + k1, jlen, nu), ldz,
+                                &u_f.sub(k1, k1, nu, nu), ldu, T::zero(), wv_f, ldwv)?;
+                    lacpy(None, jlen, nu, wv_f, ldwv, &mut z_f.sub_mut(jrow, incol + k1, jlen, nu), ldz)?;
                 }
             }
         }
     }
 
-    *h = H::from(h_f.clone());
-    *z = Z::from(z_f.clone());
-    *v = V::from(v_f.clone());
-    *u = U::from(u_f.clone());
-    *wv = WV::from(wv_f.clone());
-    *wh = WH::from(wh_f.clone());
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
+
+    fn hessenberg() -> FortranArray {
+        FortranArray::from_row_major(&[
+            4., 3., 2., 1.,
+            1., 4., 3., 2.,
+            0., 1., 4., 3.,
+            0., 0., 1., 4.,
+        ], 4, 4)
+    }
 
-    #[test]
-    fn test_dlaqr5_1() {
-        let sr = &mut vec![1., 2., 3.];
-        let si = &mut vec![0., 0., 0.];
-        let h = &mut vec![vec![2., 2., 2.], vec![1., 1., 1.], vec![3., 3., 3.]];
-        let z = &mut vec![vec![3., 3., 3.], vec![2., 2., 2.], vec![4., 4., 4.]];
-        let v = &mut vec![vec![2., 2., 2.], vec![1., 1., 1.], vec![3., 3., 3.]];
-        let u = &mut vec![vec![3., 3., 3.], vec![2., 2., 2.], vec![4., 4., 4.]];
-        let wv = &mut vec![vec![0., 0., 0.], vec![0., 0., 0.], vec![0., 0., 0.]];
-        let wh = &mut vec![vec![0., 0., 0.], vec![0., 0., 0.], vec![0., 0., 0.]];
-
-        dlaqr5(true, true, 1, 4, 1, 3, 2, sr, si, h, 3,
-               1, 3, z, 3, v, 3, u, 3, 3, wv, 5, 3, wh, 3);
-
-        let expected_sr = vec![1., 2., 3.];
-        assert_eq!(expected_sr, sr.clone());
-        let expected_si = vec![0., 0., 0.];
-        assert_eq!(expected_si, si.clone());
-
-        let expected_h = vec![
-            vec![3.9999999999999973, 3.6742346141747659, 0.],
-            vec![0.81649658092772626, 0.81481481481481510, 5.2378280087892359E-002],
-            vec![-2.3094010767585029, -0.65472850109865499, 1.1851851851851853],
-        ];
-        assert_eq!(expected_h, h.clone());
-
-        let expected_z = vec![
-            vec![-4.949747468305832, -4.949747468305832, -4.949747468305832],
-            vec![-1.7320508075688774, -1.7320508075688774, -1.7320508075688774],
-            vec![5.230171827006869, 5.230171827006869, 5.230171827006869],
-        ];
-        assert_eq!(expected_z, z.clone());
-
-        let expected_v = vec![
-            vec![1.9622504486493764, -0.13870070824202946, 0.4142135623730951],
-            vec![1., 1., 1.],
-            vec![3., 3., 3.],
-        ];
-        assert_eq!(expected_v, v.clone());
-
-        let expected_u = vec![
-            vec![-0.7071067811865472, 0.0, -0.7071067811865475],
-            vec![-0.19245008972987515, -0.9622504486493764, 0.19245008972987515],
-            vec![0.6547285010986551, 0.27216552697590857, 0.6804138174397717],
-        ];
-        assert_eq!(expected_u, u.clone());
-
-        let expected_wv = vec![
-            vec![-4.949747468305832, -4.949747468305832, -4.949747468305832],
-            vec![-1.7320508075688774, -1.7320508075688774, -1.7320508075688774],
-            vec![5.230171827006869, 5.230171827006869, 5.230171827006869],
-        ];
-        assert_eq!(expected_wv, wv.clone());
-
-        let expected_wh = vec![
-            vec![-3.1467296279827165e-308, -2.1410783186265535e-308, 3.576378669627478e-308],
-            vec![0.; 3],
-            vec![0.; 3],
-        ];
-        assert_eq!(expected_wh, wh.clone());
+    fn sweep(kacc22: i32) -> (FortranArray, FortranArray) {
+        let (sr, si) = (vec![1., 2.], vec![0., 0.]);
+        let (mut h, mut z) = (hessenberg(), FortranArray::identity(4));
+        let (mut v, mut u) = (vec![0.; 3], vec![0.; 16]);
+        let (mut wv, mut wh) = (vec![0.; 16], vec![0.; 16]);
+        dlaqr5(true, true, kacc22, 4, 1, 4, 2, &sr, &si, &mut h, 4,
+               1, 4, &mut z, 4, &mut v, 3, &mut u, 4, 4, &mut wv, 4, 4, &mut wh, 4).unwrap();
+        (h, z)
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(1)]
+    #[case(2)]
+    fn test_dlaqr5(
+        #[case] kacc22: i32,
+    ) {
+        let (h, z) = sweep(kacc22);
+
+        // The sweep is the orthogonal similarity H = Z**T * H0 * Z, and every way of
+        // applying the reflections gives the same result.
+        let similar = z.transpose() * hessenberg() * &z;
+        assert!(h.iter().zip(similar.iter()).all(|(x, y)| (x - y).abs() < 1e-12));
+        let (h0, z0) = sweep(0);
+        assert!(h.iter().zip(h0.iter()).all(|(x, y)| (x - y).abs() < 1e-12));
+        assert!(z.iter().zip(z0.iter()).all(|(x, y)| (x - y).abs() < 1e-12));
+        assert_ne!(hessenberg(), h);
+    }
+
+    // kacc22, n, ktop, kbot, nshfts, ldh, iloz, ihiz, ldz, ldv, ldu, nv, ldwv, nh, ldwh
+    #[rstest]
+    #[case([3, 4, 1, 4, 2, 4, 1, 4, 4, 3, 4, 4, 4, 4, 4], 3, "kacc22")]
+    #[case([1, -1, 1, 4, 2, 4, 1, 4, 4, 3, 4, 4, 4, 4, 4], 4, "n")]
+    #[case([1, 4, 0, 4, 2, 4, 1, 4, 4, 3, 4, 4, 4, 4, 4], 5, "ktop")]
+    #[case([1, 4, 1, 5, 2, 4, 1, 4, 4, 3, 4, 4, 4, 4, 4], 6, "kbot")]
+    #[case([1, 4, 1, 4, -2, 4, 1, 4, 4, 3, 4, 4, 4, 4, 4], 7, "nshfts")]
+    #[case([1, 4, 1, 3, 2, 3, 1, 3, 3, 3, 3, 3, 5, 3, 3], 11, "ldh")]
+    #[case([1, 4, 1, 4, 2, 4, 0, 4, 4, 3, 4, 4, 4, 4, 4], 12, "iloz")]
+    #[case([1, 4, 1, 4, 2, 4, 1, 5, 4, 3, 4, 4, 4, 4, 4], 13, "ihiz")]
+    #[case([1, 4, 1, 4, 2, 4, 1, 4, 3, 3, 4, 4, 4, 4, 4], 15, "ldz")]
+    #[case([1, 4, 1, 4, 2, 4, 1, 4, 4, 2, 4, 4, 4, 4, 4], 17, "ldv")]
+    #[case([1, 3, 2, 3, 2, 3, 1, 3, 3, 3, 3, 3, 3, 3, 3], 19, "ldu")]
+    #[case([1, 4, 1, 4, 2, 4, 1, 4, 4, 3, 4, 4, 3, 4, 4], 22, "ldwv")]
+    #[case([1, 4, 1, 4, 2, 4, 1, 4, 4, 3, 4, 4, 4, 0, 4], 23, "nh")]
+    #[case([1, 4, 1, 4, 2, 4, 1, 4, 4, 3, 4, 4, 4, 4, 3], 25, "ldwh")]
+    fn test_dlaqr5_illegal_argument(
+        #[case] args: [i32; 15],
+        #[case] position: i32,
+        #[case] name: &str,
+    ) {
+        let [kacc22, n, ktop, kbot, nshfts, ldh, iloz, ihiz, ldz, ldv, ldu, nv, ldwv, nh, ldwh] = args;
+        let (sr, si) = (vec![1., 2.], vec![0., 0.]);
+        let (mut h, mut z) = (hessenberg(), FortranArray::identity(4));
+        let (mut v, mut u) = (vec![0.; 3], vec![0.; 16]);
+        let (mut wv, mut wh) = (vec![0.; 16], vec![0.; 16]);
+        let Err(Info::IllegalArgument(err)) = dlaqr5(true, true, kacc22, n, ktop, kbot, nshfts, &sr, &si, &mut h, ldh,
+                                                     iloz, ihiz, &mut z, ldz, &mut v, ldv, &mut u, ldu, nv, &mut wv, ldwv, nh, &mut wh, ldwh) else {
+            panic!("expected an illegal argument");
+        };
+        assert_eq!(("DLAQR5", position, name), (err.routine.as_str(), err.position, err.name));
+        assert_eq!(hessenberg(), h);
     }
 
     #[test]
-    fn test_dlaqr5_2() {
-        let sr = &mut vec![1., 2., 3.];
-        let si = &mut vec![0., 0., 0.];
-        let h = &mut vec![vec![2., 2., 2.], vec![1., 1., 1.], vec![3., 3., 3.]];
-        let z = &mut vec![vec![3., 3., 3.], vec![2., 2., 2.], vec![4., 4., 4.]];
-        let v = &mut vec![vec![2., 2., 2.], vec![1., 1., 1.], vec![3., 3., 3.]];
-        let u = &mut vec![vec![3., 3., 3.], vec![2., 2., 2.], vec![4., 4., 4.]];
-        let wv = &mut vec![vec![0., 0., 0.], vec![0., 0., 0.], vec![0., 0., 0.]];
-        let wh = &mut vec![vec![0., 0., 0.], vec![0., 0., 0.], vec![0., 0., 0.]];
-
-        dlaqr5(true, true, 1, 3, 2, 3, 2, sr, si, h, 3,
-               1, 3, z, 3, v, 3, u, 3, 3, wv, 3, 3, wh, 3);
-
-        let expected_sr = vec![1., 2., 3.];
-        assert_eq!(expected_sr, sr.clone());
-        let expected_si = vec![0., 0., 0.];
-        assert_eq!(expected_si, si.clone());
-
-        let expected_h = vec![
-            vec![2., 2., 2.],
-            vec![-1.8973665961010278, 2.4000000000000012, -1.2000000000000002],
-            vec![3.1460498941515413, -3.2000000000000006, 1.6000000000000001],
-        ];
-        assert_eq!(expected_h, h.clone());
-
-        let expected_z = vec![
-            vec![3., 3., 3.],
-            vec![-3.1622776601683800, -3.1622776601683800, -3.1622776601683800],
-            vec![4.3947331922020556, 4.3947331922020556, 4.3947331922020556],
-        ];
-        assert_eq!(expected_z, z.clone());
-
-        let expected_v = vec![
-            vec![1.9486832980505140, 0.16227766016837933, 2.],
-            vec![1., 1., 1.],
-            vec![3., 3., 3.],
-        ];
-        assert_eq!(expected_v, v.clone());
-
-        let expected_u = vec![
-            vec![-0.94868329805051399, -0.31622776601683794, 0.],
-            vec![0.30000000000000010, 0.94868329805051377, 0.],
-            vec![0.10000000000000001, 0., 1.],
-        ];
-        assert_eq!(expected_u, u.clone());
-
-        let expected_wv = vec![
-            vec![-3.1622776601683800, -3.1622776601683800, -3.1622776601683800],
-            vec![ 4.3947331922020556, 4.3947331922020556, 4.3947331922020556],
-            vec![0., 0., 0.],
-        ];
-        assert_eq!(expected_wv, wv.clone());
-
-        let expected_wh = vec![vec![0.; 3]; 3];
-        assert_eq!(expected_wh, wh.clone());
+    fn test_slaqr5() {
+        let (sr, si) = (vec![1f32, 2.], vec![0f32, 0.]);
+        let mut h = FortranArray::<f32>::from_row_major(&[4., 3., 2., 1., 1., 4., 3., 2., 0., 1., 4., 3., 0., 0., 1., 4.], 4, 4);
+        let mut z = FortranArray::<f32>::identity(4);
+        let (mut v, mut u) = (vec![0f32; 3], vec![0f32; 16]);
+        let (mut wv, mut wh) = (vec![0f32; 16], vec![0f32; 16]);
+        slaqr5(true, true, 1, 4, 1, 4, 2, &sr, &si, &mut h, 4,
+               1, 4, &mut z, 4, &mut v, 3, &mut u, 4, 4, &mut wv, 4, 4, &mut wh, 4).unwrap();
+
+        let (h0, _) = sweep(0);
+        assert!(h.iter().zip(h0.iter()).all(|(x, y)| (*x as f64 - y).abs() < 1e-4));
     }
-}
\ No newline at end of file
+}
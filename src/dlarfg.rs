@@ -1,8 +1,8 @@
-use crate::array::{convert::ToFortranArray, FortranArray};
-use crate::blas::dnrm2::dnrm2;
-use crate::blas::dscal::dscal;
-use crate::dlamch::dlamch;
-use crate::dlapy2::dlapy2;
+use crate::array::convert::ToFortranArrayMut;
+use crate::blas::backend::{Blas, BlasBackend};
+use crate::dlamch::lamch;
+use crate::dlapy2::lapy2;
+use crate::scalar::RealScalar;
 
 /// DLARFG
 ///
@@ -37,26 +37,55 @@ pub fn dlarfg<X>(
     incx: i32,
     tau: &mut f64,
 ) where
-    X: ToFortranArray + From<FortranArray>,
+    X: ToFortranArrayMut,
+{
+    larfg(n, alpha, x, incx, tau)
+}
+
+/// SLARFG
+///
+/// Single precision variant of [`dlarfg`].
+pub fn slarfg<X>(
+    n: i32,
+    alpha: &mut f32,
+    x: &mut X,
+    incx: i32,
+    tau: &mut f32,
+) where
+    X: ToFortranArrayMut<f32>,
+{
+    larfg(n, alpha, x, incx, tau)
+}
+
+/// Shared implementation of [`slarfg`] and [`dlarfg`].
+pub fn larfg<T, X>(
+    n: i32,
+    alpha: &mut T,
+    x: &mut X,
+    incx: i32,
+    tau: &mut T,
+) where
+    T: RealScalar,
+    X: ToFortranArrayMut<T>,
 {
     if n <= 1 {
-        *tau = 0.;
+        *tau = T::zero();
         return;
     }
 
-    let mut xnorm = dnrm2(n - 1, x, incx);
-    if xnorm == 0. {
-        *tau = 0.;
+    let mut xnorm = Blas::nrm2(n - 1, x, incx);
+    if xnorm == T::zero() {
+        *tau = T::zero();
     } else {
-        let mut beta = -alpha.signum() * dlapy2(*alpha, xnorm);
-        let safmin = dlamch('S') / dlamch('E');
+        let mut beta = -alpha.signum() * lapy2(*alpha, xnorm);
+        let safmin = lamch::<T>('S') / lamch::<T>('E');
         let mut knt = 0;
 
         if beta.abs() < safmin {
-            let rsafmn = 1. / safmin;
+            let rsafmn = T::one() / safmin;
             loop {
                 knt += 1;
-                dscal(n - 1, rsafmn, x, incx);
+                Blas::scal(n - 1, rsafmn, x, incx);
                 beta *= rsafmn;
                 *alpha *= rsafmn;
                 if beta.abs() > safmin || knt > 20 {
@@ -64,12 +93,12 @@ pub fn dlarfg<X>(
                 }
             }
 
-            xnorm = dnrm2(n - 1, x, incx);
-            beta = -alpha.signum() * dlapy2(*alpha, xnorm);
+            xnorm = Blas::nrm2(n - 1, x, incx);
+            beta = -alpha.signum() * lapy2(*alpha, xnorm);
         }
 
         *tau = (beta - *alpha) / beta;
-        dscal(n - 1, 1. / (*alpha - beta), x, incx);
+        Blas::scal(n - 1, T::one() / (*alpha - beta), x, incx);
         for _ in 0..knt { beta *= safmin; }
         *alpha = beta;
     }
@@ -88,8 +117,8 @@ mod tests {
     #[case(3, 2.1, vec![2.6, 7.8, 3.2], 2, 1.4538485592273604)]
     #[case(4, 3.4, vec![2.9, 1.0, 4.1, 5.2], 1, 1.5531563983709862)]
     #[case(5, 4.5, vec![3.3, 4.4, 5.5, 6.6, 7.7], 1, 1.403607734816732)]
-    #[case(6, 6.8, vec![7.1, 8.2, 9.3, 1.4, 2.5, 3.6], 2, 1.4941176766392699)]
-    #[case(7, 8.7, vec![4.8, 2.9, 7.1, 8.2, 9.3, 1.4, 2.5], 4, 1.6392567355572378)]
+    #[case(4, 6.8, vec![7.1, 8.2, 9.3, 1.4, 2.5, 3.6], 2, 1.4941176766392699)]
+    #[case(3, 8.7, vec![4.8, 2.9, 7.1, 8.2, 9.3, 1.4, 2.5], 4, 1.6392567355572378)]
     fn test_dlarfg(
         #[case] n: i32,
         #[case] mut alpha: f64,
@@ -102,4 +131,13 @@ mod tests {
         dlarfg(n, &mut alpha, &mut x, incx, &mut tau);
         assert_eq!(tau, expected_tau);
     }
+
+    #[test]
+    #[cfg_attr(feature = "debug-bounds", should_panic(expected = "out of bounds"))]
+    fn test_dlarfg_past_end() {
+        // n = 6 with incx = 2 needs nine elements of x.
+        let (mut alpha, mut tau) = (6.8, 0.);
+        dlarfg(6, &mut alpha, &mut vec![7.1, 8.2, 9.3, 1.4, 2.5, 3.6], 2, &mut tau);
+        assert!((tau - 1.4941176766392699).abs() < 1e-15);
+    }
 }
@@ -1,4 +1,10 @@
-use crate::array::{convert::ToFortranArray, FortranArray};
+use std::fmt::Debug;
+
+use crate::array::convert::ToFortranArrayMut;
+use crate::error::Info;
+use crate::flags::Uplo;
+use crate::scalar::{Complex32, Complex64, Scalar};
+use crate::xerbla::illegal_argument;
 
 /// DLASET
 ///
@@ -8,34 +14,115 @@ use crate::array::{convert::ToFortranArray, FortranArray};
 /// [http://www.netlib.org/lapack/explore-html/](http://www.netlib.org/lapack/explore-html/)
 ///
 /// Initializes the off-diagonal elements and the diagonal elements of a matrix to given values.
+/// `uplo` selects the off-diagonal triangle to set, `None` sets both.
 ///
 /// # Arguments
 ///
 /// For arguments definitions, please refer to the original documentation.
 pub fn dlaset<A>(
-    uplo: char,
+    uplo: Option<Uplo>,
     m: i32,
     n: i32,
     alpha: f64,
     beta: f64,
     a: &mut A,
-) where
-    A: ToFortranArray + From<FortranArray>,
+    lda: i32,
+) -> Result<(), Info> where
+    A: ToFortranArrayMut,
+{
+    laset(uplo, m, n, alpha, beta, a, lda)
+}
+
+/// SLASET
+///
+/// Single precision variant of [`dlaset`].
+pub fn slaset<A>(
+    uplo: Option<Uplo>,
+    m: i32,
+    n: i32,
+    alpha: f32,
+    beta: f32,
+    a: &mut A,
+    lda: i32,
+) -> Result<(), Info> where
+    A: ToFortranArrayMut<f32>,
 {
-    let a_f = &mut a.to_fa();
+    laset(uplo, m, n, alpha, beta, a, lda)
+}
+
+/// CLASET
+///
+/// Single precision complex variant of [`dlaset`].
+pub fn claset<A>(
+    uplo: Option<Uplo>,
+    m: i32,
+    n: i32,
+    alpha: Complex32,
+    beta: Complex32,
+    a: &mut A,
+    lda: i32,
+) -> Result<(), Info> where
+    A: ToFortranArrayMut<Complex32>,
+{
+    laset(uplo, m, n, alpha, beta, a, lda)
+}
+
+/// ZLASET
+///
+/// Double precision complex variant of [`dlaset`].
+pub fn zlaset<A>(
+    uplo: Option<Uplo>,
+    m: i32,
+    n: i32,
+    alpha: Complex64,
+    beta: Complex64,
+    a: &mut A,
+    lda: i32,
+) -> Result<(), Info> where
+    A: ToFortranArrayMut<Complex64>,
+{
+    laset(uplo, m, n, alpha, beta, a, lda)
+}
+
+/// Shared implementation of [`slaset`], [`dlaset`], [`claset`] and [`zlaset`].
+pub fn laset<T, A>(
+    uplo: Option<Uplo>,
+    m: i32,
+    n: i32,
+    alpha: T,
+    beta: T,
+    a: &mut A,
+    lda: i32,
+) -> Result<(), Info> where
+    T: Scalar,
+    A: ToFortranArrayMut<T>,
+{
+    let illegal = |position, name, value: &dyn Debug| {
+        illegal_argument(format!("{}LASET", T::PREFIX), position, name, value)
+    };
+
+    if m < 0 {
+        return illegal(2, "m", &m);
+    } else if n < 0 {
+        return illegal(3, "n", &n);
+    } else if lda < 1.max(m) {
+        return illegal(7, "lda", &lda);
+    }
+
+    let a_f = &mut a.fa_view_2d_mut(lda);
 
     match uplo {
-        'U' => for j in 1..=n {
-            for i in j..=m {
+        Some(Uplo::Upper) => for j in 2..=n {
+            for i in 1..=(j - 1).min(m) {
                 a_f[(i, j)] = alpha;
             }
         }
-        'L' => for j in 1..=n {
-            for i in 1..=j {
+        Some(Uplo::Lower) => for j in 1..=m.min(n) {
+            for i in j + 1..=m {
                 a_f[(i, j)] = alpha;
             }
         }
-        _ => for j in 1..=n {
+        None => for j in 1..=n {
             for i in 1..=m {
                 a_f[(i, j)] = alpha;
             }
@@ -46,12 +133,13 @@ pub fn dlaset<A>(
         a_f[(i, i)] = beta;
     }
 
-    *a = A::from(a_f.clone());
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[test]
     fn test_dlaset_upper() {
@@ -60,11 +148,11 @@ mod tests {
             vec![0.0, 0.0, 0.0],
             vec![0.0, 0.0, 0.0],
         ];
-        dlaset('U', 3, 3, 1.0, 2.0, &mut matrix);
+        dlaset(Some(Uplo::Upper), 3, 3, 1.0, 2.0, &mut matrix, 3).unwrap();
         assert_eq!(matrix, vec![
-            vec![2.0, 1.0, 1.0],
-            vec![0.0, 2.0, 1.0],
-            vec![0.0, 0.0, 2.0]
+            vec![2.0, 0.0, 0.0],
+            vec![1.0, 2.0, 0.0],
+            vec![1.0, 1.0, 2.0]
         ]);
     }
 
@@ -75,11 +163,11 @@ mod tests {
             vec![0.0, 0.0, 0.0],
             vec![0.0, 0.0, 0.0],
         ];
-        dlaset('L', 3, 3, 1.0, 2.0, &mut matrix);
+        dlaset(Some(Uplo::Lower), 3, 3, 1.0, 2.0, &mut matrix, 3).unwrap();
         assert_eq!(matrix, vec![
-            vec![2.0, 0.0, 0.0],
-            vec![1.0, 2.0, 0.0],
-            vec![1.0, 1.0, 2.0]
+            vec![2.0, 1.0, 1.0],
+            vec![0.0, 2.0, 1.0],
+            vec![0.0, 0.0, 2.0]
         ]);
     }
 
@@ -90,11 +178,30 @@ mod tests {
             vec![0.0, 0.0, 0.0],
             vec![0.0, 0.0, 0.0],
         ];
-        dlaset('A', 3, 3, 1.0, 2.0, &mut matrix);
+        dlaset(None, 3, 3, 1.0, 2.0, &mut matrix, 3).unwrap();
         assert_eq!(matrix, vec![
             vec![2.0, 1.0, 1.0],
             vec![1.0, 2.0, 1.0],
             vec![1.0, 1.0, 2.0]
         ]);
     }
+
+    #[rstest]
+    #[case(-1, 3, 3, 2, "m")]
+    #[case(3, -1, 3, 3, "n")]
+    #[case(3, 3, 2, 7, "lda")]
+    fn test_dlaset_illegal_argument(
+        #[case] m: i32,
+        #[case] n: i32,
+        #[case] lda: i32,
+        #[case] position: i32,
+        #[case] name: &str,
+    ) {
+        let mut matrix = vec![0.0; 9];
+        let Err(Info::IllegalArgument(err)) = dlaset(None, m, n, 1.0, 2.0, &mut matrix, lda) else {
+            panic!("expected an illegal argument");
+        };
+        assert_eq!(("DLASET", position, name), (err.routine.as_str(), err.position, err.name));
+        assert_eq!(vec![0.0; 9], matrix);
+    }
 }
@@ -0,0 +1,119 @@
+use std::error::Error;
+use std::fmt;
+
+/// An argument passed to a routine had an illegal value.
+///
+/// This is the condition reference LAPACK reports through XERBLA, with `INFO = -position`.
+/// Routines return it as [`Info::IllegalArgument`] instead of stopping the process; see
+/// [`OrExit`](crate::xerbla::OrExit) for the reference behavior.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LapackError {
+    /// Routine name, e.g. `"DGEMM"`.
+    pub routine: String,
+    /// 1-based position of the argument in the Fortran argument list.
+    pub position: i32,
+    /// Argument name, e.g. `"lda"`.
+    pub name: &'static str,
+    /// The offending value, formatted with `Debug`.
+    pub value: String,
+}
+
+impl LapackError {
+    pub fn new(routine: String, position: i32, name: &'static str, value: &dyn fmt::Debug) -> Self {
+        LapackError { routine, position, name, value: format!("{value:?}") }
+    }
+
+    /// The `INFO` value reference LAPACK would return, `-position`.
+    pub fn info(&self) -> i32 {
+        -self.position
+    }
+}
+
+impl fmt::Display for LapackError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "on entry to {} parameter number {} ({}) had an illegal value: {}",
+               self.routine, self.position, self.name, self.value)
+    }
+}
+
+impl Error for LapackError {}
+
+/// Reason a routine did not complete, the nonzero `INFO` values of reference LAPACK.
+///
+/// Every public routine that can fail returns `Result<(), Info>`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Info {
+    /// `INFO < 0`: an argument had an illegal value.
+    IllegalArgument(LapackError),
+    /// `INFO > 0` from iterative routines: iteration stopped before convergence, e.g. DLAHQR
+    /// failed to compute the eigenvalues `ILO` to `index`.
+    ConvergenceFailure { index: i32 },
+    /// `INFO > 0` from factorizations and solvers: the `index`-th diagonal element is exactly
+    /// zero, so the matrix is singular.
+    Singular { index: i32 },
+    /// An input contained NaN or infinity while [`check_finite`](crate::nancheck::check_finite)
+    /// was enabled; `INFO = -position` as in LAPACKE.
+    NonFinite { routine: String, position: i32, name: &'static str },
+}
+
+impl Info {
+    /// The `INFO` value reference LAPACK would return.
+    pub fn info(&self) -> i32 {
+        match self {
+            Info::IllegalArgument(err) => err.info(),
+            Info::ConvergenceFailure { index } | Info::Singular { index } => *index,
+            Info::NonFinite { position, .. } => -position,
+        }
+    }
+}
+
+impl From<LapackError> for Info {
+    fn from(err: LapackError) -> Self {
+        Info::IllegalArgument(err)
+    }
+}
+
+impl fmt::Display for Info {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Info::IllegalArgument(err) => err.fmt(f),
+            Info::ConvergenceFailure { index } => write!(f, "failed to converge at index {}", index),
+            Info::Singular { index } => write!(f, "matrix is singular, diagonal element {} is zero", index),
+            Info::NonFinite { routine, position, name } =>
+                write!(f, "on entry to {} parameter number {} ({}) contains NaN or infinity", routine, position, name),
+        }
+    }
+}
+
+impl Error for Info {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Info::IllegalArgument(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let error = LapackError::new("DGEMM".to_string(), 8, "lda", &1);
+        assert_eq!(-8, error.info());
+        assert_eq!("on entry to DGEMM parameter number 8 (lda) had an illegal value: 1", error.to_string());
+        assert_eq!("'X'", LapackError::new("DTRMM".to_string(), 1, "side", &'X').value);
+    }
+
+    #[test]
+    fn test_info() {
+        let error = Info::from(LapackError::new("DGEMM".to_string(), 8, "lda", &1));
+        assert_eq!(-8, error.info());
+        assert_eq!("on entry to DGEMM parameter number 8 (lda) had an illegal value: 1", error.to_string());
+        assert_eq!(3, Info::ConvergenceFailure { index: 3 }.info());
+        assert_eq!("matrix is singular, diagonal element 2 is zero", Info::Singular { index: 2 }.to_string());
+        let error = Info::NonFinite { routine: "DLAHQR".to_string(), position: 6, name: "h" };
+        assert_eq!((-6, "on entry to DLAHQR parameter number 6 (h) contains NaN or infinity".to_string()), (error.info(), error.to_string()));
+    }
+}
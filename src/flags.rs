@@ -0,0 +1,112 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::blas::lsame::lsame;
+
+/// A character flag did not name any variant of the flag type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FlagError {
+    /// Name of the flag type, e.g. `"Trans"`.
+    pub flag: &'static str,
+    pub value: char,
+}
+
+impl fmt::Display for FlagError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?} is not a valid {} flag", self.value, self.flag)
+    }
+}
+
+impl Error for FlagError {}
+
+/// Operation applied to a matrix argument, the `TRANS` flags.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Trans {
+    /// `op( A ) = A`, `'N'`.
+    NoTrans,
+    /// `op( A ) = A**T`, `'T'`.
+    Trans,
+    /// `op( A ) = A**H`, `'C'`; the same as [`Trans::Trans`] for real types.
+    ConjTrans,
+}
+
+/// Triangle of a matrix that is referenced, the `UPLO` flags.
+///
+/// Routines that can also work on the whole matrix, like `dlacpy` and `dlaset`, take an
+/// `Option<Uplo>` with `None` for the full matrix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Uplo {
+    /// `'U'`.
+    Upper,
+    /// `'L'`.
+    Lower,
+}
+
+/// Side a matrix is applied from, the `SIDE` flags.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    /// `op( A )*B`, `'L'`.
+    Left,
+    /// `B*op( A )`, `'R'`.
+    Right,
+}
+
+/// Whether a triangular matrix has an implicit unit diagonal, the `DIAG` flags.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Diag {
+    /// `'N'`.
+    NonUnit,
+    /// The diagonal is assumed to be ones and is not referenced, `'U'`.
+    Unit,
+}
+
+// Fortran-style conversions, case-insensitive like `lsame`.
+macro_rules! impl_char_conversions {
+    ($flag:ident { $($variant:ident => $c:literal),* $(,)? }) => {
+        impl TryFrom<char> for $flag {
+            type Error = FlagError;
+
+            fn try_from(value: char) -> Result<Self, FlagError> {
+                $(if lsame(value, $c) { return Ok($flag::$variant); })*
+                Err(FlagError { flag: stringify!($flag), value })
+            }
+        }
+
+        impl From<$flag> for char {
+            fn from(flag: $flag) -> char {
+                match flag { $($flag::$variant => $c,)* }
+            }
+        }
+    };
+}
+
+impl_char_conversions!(Trans { NoTrans => 'N', Trans => 'T', ConjTrans => 'C' });
+impl_char_conversions!(Uplo { Upper => 'U', Lower => 'L' });
+impl_char_conversions!(Side { Left => 'L', Right => 'R' });
+impl_char_conversions!(Diag { NonUnit => 'N', Unit => 'U' });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case('N', Ok(Trans::NoTrans))]
+    #[case('t', Ok(Trans::Trans))]
+    #[case('C', Ok(Trans::ConjTrans))]
+    #[case('X', Err(FlagError { flag: "Trans", value: 'X' }))]
+    fn test_trans(
+        #[case] value: char,
+        #[case] expected: Result<Trans, FlagError>,
+    ) {
+        assert_eq!(expected, Trans::try_from(value));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        assert_eq!(Ok(Uplo::Lower), Uplo::try_from(char::from(Uplo::Lower)));
+        assert_eq!(Ok(Side::Right), Side::try_from(char::from(Side::Right)));
+        assert_eq!(Ok(Diag::Unit), Diag::try_from('u'));
+        assert_eq!("'A' is not a valid Uplo flag", Uplo::try_from('A').unwrap_err().to_string());
+    }
+}
@@ -12,7 +12,7 @@ use crate::iparmq::iparmq;
 /// # Arguments
 ///
 /// For arguments definitions, please refer to the original documentation.
-pub(crate) fn ilaenv(
+pub fn ilaenv(
     ispec: i32,
     name: &str,
     _opts: &str,
@@ -24,18 +24,18 @@ pub(crate) fn ilaenv(
     match ispec {
         1..=3 => {
             let mut subnam = name.to_string();
-            let ic = subnam.chars().nth(0).unwrap() as u32;
+            let ic = subnam.chars().next().unwrap() as u32;
             let iz = 'Z' as u32;
 
             if iz == 90 || iz == 122 {
                 // ASCII character set
-                if ic >= 97 && ic <= 122 {
+                if (97..=122).contains(&ic) {
                     let new_char = char::from_u32(ic - 32).unwrap();
                     subnam.replace_range(0..1, &new_char.to_string());
                     for i in 1..6 {
                         let ic = subnam.chars().nth(i).unwrap() as u32;
 
-                        if ic >= 97 && ic <= 122 {
+                        if (97..=122).contains(&ic) {
                             let new_char = char::from_u32(ic - 32).unwrap();
                             subnam.replace_range(i..=i, &new_char.to_string());
                         }
@@ -62,12 +62,12 @@ pub(crate) fn ilaenv(
                 }
             } else if iz == 218 || iz == 250 {
                 // Prime machines: ASCII + 128
-                if ic >= 225 && ic <= 250 {
+                if (225..=250).contains(&ic) {
                     let new_char = char::from_u32(ic - 32).unwrap();
                     subnam.replace_range(0..1, &new_char.to_string());
                     for i in 1..6 {
                         let ic = subnam.chars().nth(i).unwrap() as u32;
-                        if ic >= 225 && ic <= 250 {
+                        if (225..=250).contains(&ic) {
                             let new_char = char::from_u32(ic - 32).unwrap();
                             subnam.replace_range(i..=i, &new_char.to_string());
                         }
@@ -75,7 +75,7 @@ pub(crate) fn ilaenv(
                 }
             }
 
-            let c1 = subnam.chars().nth(0).unwrap();
+            let c1 = subnam.chars().next().unwrap();
             let sname = c1 == 'S' || c1 == 'D';
             let cname = c1 == 'C' || c1 == 'Z';
 
@@ -125,10 +125,9 @@ pub(crate) fn ilaenv(
                     _ if c2 == "GE" && subnam.chars().skip(3).take(4).collect::<String>() == "QP3RK" => 128,
                     ("SY", "TRD") | ("HE", "TRD") if sname || cname => 32,
                     ("GG", _) if c3 == "HD3" => 128,
-                    _ if (c2 == "OR" && sname) || (c2 == "UN" && cname) =>
-                        if c3.starts_with('G')
-                            && ["QR", "RQ", "LQ", "QL", "HR", "TR", "BR"].contains(&c4.as_str()) { 128 }
-                        else { 0 },
+                    _ if ((c2 == "OR" && sname) || (c2 == "UN" && cname))
+                        && c3.starts_with('G')
+                        && ["QR", "RQ", "LQ", "QL", "HR", "TR", "BR"].contains(&c4.as_str()) => 128,
                     _ => 0,
                 },
                 _ => { -1 },
@@ -179,7 +178,7 @@ mod tests {
     #[case(7, "CGGHRD", 2, 3, 0, 0, 1)]
     #[case(8, "CGGHRD", 2, 3, 0, 0, 50)]
     #[case(9, "CGGHRD", 2, 3, 0, 0, 25)]
-    #[case(10, "CGGHRD", 0, 0, 0, 0, 0)]
+    #[case(10, "CGGHRD", 0, 0, 0, 0, 1)]
     #[case(11, "CGGHRD", 0, 0, 0, 0, 1)]
     #[case(12, "CGGHRD", 2, 3, 4, 5, 75)]
     #[case(13, "CGGHRD", 2, 3, 4, 5, 2)]
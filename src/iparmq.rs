@@ -6,7 +6,7 @@
 /// # Arguments
 ///
 /// For arguments definitions, please refer to the original documentation.
-pub(crate) fn iparmq(
+pub fn iparmq(
     ispec: i32,
     name: &str,
     ilo: i32,
@@ -55,11 +55,11 @@ pub(crate) fn iparmq(
             let iz = 'Z' as u32;
             if iz == 90 || iz == 122 {
                 // ASCII character set
-                if ic >= 97 && ic <= 122 {
+                if (97..=122).contains(&ic) {
                     let mut chars: Vec<char> = subnam.chars().collect();
                     for i in 0..chars.len().min(6) {
                         let mut ic = chars[i] as u32;
-                        if ic >= 97 && ic <= 122 {
+                        if (97..=122).contains(&ic) {
                             ic -= 32;
                             chars[i] = std::char::from_u32(ic).unwrap_or_default();
                         }
@@ -71,9 +71,9 @@ pub(crate) fn iparmq(
                 let mut chars: Vec<char> = subnam.chars().collect();
                 for i in 0..chars.len().min(6) {
                     let mut ic = chars[i] as u32;
-                    if (ic >= 129 && ic <= 137) ||
-                        (ic >= 145 && ic <= 153) ||
-                        (ic >= 162 && ic <= 169) {
+                    if (129..=137).contains(&ic) ||
+                        (145..=153).contains(&ic) ||
+                        (162..=169).contains(&ic) {
                         ic += 64;
                         chars[i] = std::char::from_u32(ic).unwrap_or_default();
                     }
@@ -84,7 +84,7 @@ pub(crate) fn iparmq(
                 let mut chars: Vec<char> = subnam.chars().collect();
                 for i in 0..chars.len().min(6) {
                     let mut ic = chars[i] as u32;
-                    if ic >= 225 && ic <= 250 {
+                    if (225..=250).contains(&ic) {
                         ic -= 32;
                         chars[i] = std::char::from_u32(ic).unwrap_or_default();
                     }
@@ -102,7 +102,7 @@ pub(crate) fn iparmq(
                 if ns >= kacmin { return 1 }
                 else if ns >= k22min { return 2 }
             }
-            return result
+            result
         },
         _ if ispec == icost => rcost,
         _ => -1,
@@ -1,3 +1,6 @@
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::excessive_precision)]
+
 pub mod array;
 pub mod blas;
 
@@ -10,7 +13,11 @@ pub mod dlaqr1;
 pub mod dlaqr5;
 pub mod dlarfg;
 pub mod dlaset;
+pub mod error;
+pub mod flags;
 pub mod ieeeck;
 pub mod ilaenv;
 pub mod iparmq;
+pub mod nancheck;
+pub mod scalar;
 pub mod xerbla;
@@ -0,0 +1,80 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+
+use num_traits::Float;
+
+use crate::array::view::FortranView;
+use crate::error::Info;
+use crate::ieeeck::ieeeck;
+use crate::scalar::Scalar;
+
+static CHECK_FINITE: AtomicBool = AtomicBool::new(false);
+
+/// Whether drivers scan their inputs for NaN and infinity, see [`set_check_finite`].
+pub fn check_finite() -> bool {
+    CHECK_FINITE.load(Ordering::Relaxed)
+}
+
+/// Enables or disables, for every thread, the scan of driver inputs for NaN and infinity.
+///
+/// When enabled, a driver given a non-finite element returns [`Info::NonFinite`] before doing
+/// any work, like the `nancheck` of LAPACKE. It is disabled by default.
+pub fn set_check_finite(enabled: bool) {
+    CHECK_FINITE.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether NaN arithmetic follows IEEE 754, `ILAENV( 10, ... )`.
+///
+/// When it does, NaN propagates through the arithmetic without trapping, and drivers can rely
+/// on it to detect a poisoned computation instead of screening every input.
+pub fn ieee_nan_arithmetic() -> bool {
+    static IEEE: OnceLock<bool> = OnceLock::new();
+    *IEEE.get_or_init(|| ieeeck(1, 0.0, 1.0) == 1)
+}
+
+/// Returns [`Info::NonFinite`] for argument `position` when screening is enabled and one of
+/// the first `m` rows and `n` columns of `a` is NaN or infinite.
+pub(crate) fn nancheck<T: Scalar>(
+    routine: String,
+    position: i32,
+    name: &'static str,
+    m: i32,
+    n: i32,
+    a: &FortranView<T>,
+) -> Result<(), Info> {
+    if !check_finite() {
+        return Ok(());
+    }
+    for j in 1..=n {
+        for i in 1..=m {
+            if !is_finite(a[(i, j)]) {
+                return Err(Info::NonFinite { routine, position, name });
+            }
+        }
+    }
+    Ok(())
+}
+
+fn is_finite<T: Scalar>(x: T) -> bool {
+    Float::is_finite(x.re()) && Float::is_finite(x.im())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ieee_nan_arithmetic() {
+        assert!(ieee_nan_arithmetic());
+    }
+
+    #[test]
+    fn test_is_finite() {
+        use crate::scalar::Complex64;
+
+        assert!(is_finite(1.0));
+        assert!(!is_finite(f64::NAN));
+        assert!(!is_finite(f32::NEG_INFINITY));
+        assert!(!is_finite(Complex64::new(1.0, f64::INFINITY)));
+    }
+}
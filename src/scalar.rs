@@ -0,0 +1,162 @@
+use std::fmt::{Debug, Display, LowerExp};
+use std::ops::Neg;
+
+use num_traits::{Float, NumAssign};
+
+pub use num_complex::{Complex32, Complex64};
+
+/// Element type of [`FortranArray`](crate::array::FortranArray) and the routines.
+///
+/// Implemented for `f32`, `f64`, [`Complex32`] and [`Complex64`], the element types of
+/// the S, D, C and Z routines. Arithmetic (`+`, `*`, `zero()`, `one()`, ...) comes
+/// from `num_traits`.
+pub trait Scalar:
+    NumAssign + Neg<Output = Self> + Copy + PartialEq + Debug + Display + LowerExp + Send + Sync + 'static
+{
+    /// Real type of the same precision.
+    type Real: RealScalar;
+
+    /// Complex type of the same precision.
+    type Complex: Scalar<Real = Self::Real>;
+
+    const IS_COMPLEX: bool;
+
+    /// Letter prefixing the routine names of this type (`'S'`, `'D'`, `'C'` or `'Z'`).
+    const PREFIX: char;
+
+    fn from_real(re: Self::Real) -> Self;
+
+    fn re(self) -> Self::Real;
+
+    fn im(self) -> Self::Real;
+
+    /// Complex conjugate; the identity for real types.
+    fn conj(self) -> Self;
+
+    /// Absolute value, `sqrt(re**2 + im**2)` for complex types.
+    fn modulus(self) -> Self::Real;
+
+    /// `|re| + |im|`, the cheap norm LAPACK uses for complex scaling tests.
+    fn abs1(self) -> Self::Real {
+        self.re().abs() + self.im().abs()
+    }
+
+    /// Value written to when indexing out of range (NaN).
+    fn nan() -> Self;
+
+    /// Value read when indexing out of range (`MIN_POSITIVE`).
+    fn fallback() -> &'static Self;
+}
+
+/// Real element types, `f32` and `f64`.
+pub trait RealScalar: Scalar<Real = Self> + Float {
+    const RADIX: u32;
+    const MANTISSA_DIGITS: u32;
+    const MIN_EXP: i32;
+    const MAX_EXP: i32;
+
+    /// Converts a constant; literals in the routines are written as `f64`.
+    fn from_f64(value: f64) -> Self;
+}
+
+/// Conjugates `x` when `conj` is set, as the `'C'` (conjugate transpose) options do.
+#[inline]
+pub(crate) fn conj_if<T: Scalar>(conj: bool, x: T) -> T {
+    if conj { x.conj() } else { x }
+}
+
+macro_rules! impl_real {
+    ($t:ident, $complex:ident, $prefix:literal) => {
+        impl Scalar for $t {
+            type Real = $t;
+            type Complex = $complex;
+            const IS_COMPLEX: bool = false;
+            const PREFIX: char = $prefix;
+
+            fn from_real(re: $t) -> Self { re }
+
+            fn re(self) -> $t { self }
+
+            fn im(self) -> $t { 0. }
+
+            fn conj(self) -> Self { self }
+
+            fn modulus(self) -> $t { self.abs() }
+
+            fn abs1(self) -> $t { self.abs() }
+
+            fn nan() -> Self { $t::NAN }
+
+            fn fallback() -> &'static Self { &$t::MIN_POSITIVE }
+        }
+
+        impl RealScalar for $t {
+            const RADIX: u32 = $t::RADIX;
+            const MANTISSA_DIGITS: u32 = $t::MANTISSA_DIGITS;
+            const MIN_EXP: i32 = $t::MIN_EXP;
+            const MAX_EXP: i32 = $t::MAX_EXP;
+
+            fn from_f64(value: f64) -> Self { value as $t }
+        }
+    };
+}
+
+macro_rules! impl_complex {
+    ($t:ident, $real:ident, $prefix:literal) => {
+        impl Scalar for $t {
+            type Real = $real;
+            type Complex = $t;
+            const IS_COMPLEX: bool = true;
+            const PREFIX: char = $prefix;
+
+            fn from_real(re: $real) -> Self { $t::new(re, 0.) }
+
+            fn re(self) -> $real { self.re }
+
+            fn im(self) -> $real { self.im }
+
+            fn conj(self) -> Self { $t::new(self.re, -self.im) }
+
+            fn modulus(self) -> $real { self.norm() }
+
+            fn nan() -> Self { $t::new($real::NAN, $real::NAN) }
+
+            fn fallback() -> &'static Self {
+                const FALLBACK: $t = $t::new($real::MIN_POSITIVE, 0.);
+                &FALLBACK
+            }
+        }
+    };
+}
+
+impl_real!(f32, Complex32, 'S');
+impl_real!(f64, Complex64, 'D');
+impl_complex!(Complex32, f32, 'C');
+impl_complex!(Complex64, f64, 'Z');
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Complex64::new(3., -4.), 5., 7., Complex64::new(3., 4.))]
+    #[case(Complex64::new(-1., 0.), 1., 1., Complex64::new(-1., 0.))]
+    fn test_complex(
+        #[case] z: Complex64,
+        #[case] modulus: f64,
+        #[case] abs1: f64,
+        #[case] conj: Complex64,
+    ) {
+        assert_eq!(modulus, z.modulus());
+        assert_eq!(abs1, z.abs1());
+        assert_eq!(conj, Scalar::conj(z));
+    }
+
+    #[test]
+    fn test_prefix() {
+        assert_eq!(['S', 'D', 'C', 'Z'], [f32::PREFIX, f64::PREFIX, Complex32::PREFIX, Complex64::PREFIX]);
+        assert_eq!([false, false, true, true], [f32::IS_COMPLEX, f64::IS_COMPLEX, Complex32::IS_COMPLEX, Complex64::IS_COMPLEX]);
+        assert_eq!('Z', <<f64 as Scalar>::Complex as Scalar>::PREFIX);
+    }
+}
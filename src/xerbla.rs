@@ -1,4 +1,16 @@
+use std::fmt::Debug;
 use std::process;
+use std::sync::{Arc, RwLock};
+
+use crate::error::{Info, LapackError};
+
+/// Handler called by [`xerbla`], see [`set_xerbla_handler`].
+pub type XerblaHandler = Box<dyn Fn(&str, i32) + Send + Sync>;
+
+// Shared so `xerbla` can call it without holding the lock.
+type SharedHandler = Arc<dyn Fn(&str, i32) + Send + Sync>;
+
+static HANDLER: RwLock<Option<SharedHandler>> = RwLock::new(None);
 
 /// XERBLA
 ///
@@ -11,10 +23,11 @@ use std::process;
 ///
 /// XERBLA is an error handler for the LAPACK routines.
 /// It is called by an LAPACK routine if an input parameter has an
-/// invalid value. A message is printed and execution stops.
+/// invalid value, before the routine returns the matching [`Info::IllegalArgument`].
 ///
-/// Installers may consider modifying the STOP statement in order to
-/// call system-specific exception-handling facilities.
+/// Installers may replace it with [`set_xerbla_handler`]; by default it does nothing.
+/// [`panic_handler`], [`log_handler`] and [`exit_handler`] (the reference behavior) are
+/// provided.
 ///
 /// # Arguments
 ///
@@ -23,6 +36,137 @@ pub fn xerbla(
     srname: &str,
     info: i32,
 ) {
-    println!("** On entry to {} parameter number {} had an illegal value", srname, info);
+    // The lock is released before the call, so a handler may replace itself or take its time.
+    let handler = HANDLER.read().unwrap_or_else(|poisoned| poisoned.into_inner()).clone();
+    if let Some(handler) = handler {
+        handler(srname, info);
+    }
+}
+
+/// Installs `handler` for every thread; it is called with the routine name and the
+/// position of the illegal argument.
+pub fn set_xerbla_handler(handler: XerblaHandler) {
+    *HANDLER.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(Arc::from(handler));
+}
+
+/// Restores the default handler, which does nothing.
+pub fn reset_xerbla_handler() {
+    *HANDLER.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = None;
+}
+
+fn message(srname: &str, info: i32) -> String {
+    format!("** On entry to {} parameter number {} had an illegal value", srname, info)
+}
+
+/// Panics with the reference XERBLA message.
+pub fn panic_handler(srname: &str, info: i32) {
+    panic!("{}", message(srname, info));
+}
+
+/// Logs the reference XERBLA message at error level through the `log` crate.
+pub fn log_handler(srname: &str, info: i32) {
+    log::error!("{}", message(srname, info));
+}
+
+/// Reference behavior: prints the message and stops the process.
+pub fn exit_handler(srname: &str, info: i32) {
+    println!("{}", message(srname, info));
     process::exit(1);
 }
+
+/// Reports an illegal argument through [`xerbla`] and returns it as an error.
+pub(crate) fn illegal_argument<T>(
+    routine: String,
+    position: i32,
+    name: &'static str,
+    value: &dyn Debug,
+) -> Result<T, Info> {
+    xerbla(&routine, position);
+    Err(Info::IllegalArgument(LapackError::new(routine, position, name, value)))
+}
+
+/// Opt-in compatibility shim for the reference error handling.
+///
+/// Routines return [`Info::IllegalArgument`] for illegal arguments; `or_exit` turns that
+/// back into the reference behavior of printing the XERBLA message and stopping the
+/// process, and any other outcome into the nonnegative reference `INFO`.
+pub trait OrExit {
+    fn or_exit(self) -> i32;
+}
+
+impl OrExit for Result<(), Info> {
+    fn or_exit(self) -> i32 {
+        match self {
+            Ok(()) => 0,
+            Err(Info::IllegalArgument(err)) => {
+                exit_handler(&err.routine, err.position);
+                unreachable!()
+            }
+            Err(info) => info.info(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::sync::Mutex;
+
+    use crate::blas::dgemm::dgemm;
+    use crate::flags::Trans;
+
+    thread_local! {
+        static CALLS: RefCell<Vec<(String, i32)>> = const { RefCell::new(vec![]) };
+    }
+
+    // Serializes the tests that install a handler, since it is a global setting.
+    static INSTALLING: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn test_set_xerbla_handler() {
+        let _guard = INSTALLING.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        // Other tests may hit illegal arguments meanwhile; they only record on their own thread.
+        set_xerbla_handler(Box::new(|srname, info| {
+            CALLS.with(|calls| calls.borrow_mut().push((srname.to_string(), info)));
+        }));
+        let result = dgemm(Trans::NoTrans, Trans::NoTrans, 2, 2, 2, 1., &vec![1.; 4], 1, &vec![1.; 4], 2, 0., &mut vec![0.; 4], 2);
+        reset_xerbla_handler();
+
+        assert_eq!(-8, result.unwrap_err().info());
+        assert_eq!(vec![("DGEMM".to_string(), 8)], CALLS.with(|calls| calls.borrow().clone()));
+    }
+
+    fn reinstalling_handler(srname: &str, info: i32) {
+        CALLS.with(|calls| calls.borrow_mut().push((srname.to_string(), info)));
+        set_xerbla_handler(Box::new(reinstalling_handler));
+    }
+
+    #[test]
+    fn test_handler_installs_handler() {
+        let _guard = INSTALLING.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        set_xerbla_handler(Box::new(reinstalling_handler));
+        let result = dgemm(Trans::NoTrans, Trans::NoTrans, 2, 2, 2, 1., &vec![1.; 4], 1, &vec![1.; 4], 2, 0., &mut vec![0.; 4], 2);
+        reset_xerbla_handler();
+
+        assert_eq!(-8, result.unwrap_err().info());
+        assert_eq!(vec![("DGEMM".to_string(), 8)], CALLS.with(|calls| calls.borrow().clone()));
+    }
+
+    #[test]
+    #[should_panic(expected = "** On entry to DGEMM parameter number 8 had an illegal value")]
+    fn test_panic_handler() {
+        panic_handler("DGEMM", 8);
+    }
+
+    #[test]
+    fn test_log_handler() {
+        log_handler("DGEMM", 8);
+    }
+
+    #[test]
+    fn test_or_exit() {
+        assert_eq!(0, Ok(()).or_exit());
+        assert_eq!(3, Err(Info::ConvergenceFailure { index: 3 }).or_exit());
+    }
+}